@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use std::{error, fmt};
+
+use crate::RpcErrorCode;
+
+/// The error type for errors produced by the async client.
+#[derive(Debug)]
+pub enum Error {
+    /// The transport failed to send the request or to read the response.
+    Transport(Box<dyn error::Error + Send + Sync>),
+    /// Serializing the request or deserializing the response failed.
+    Json(serde_json::error::Error),
+    /// The daemon returned a JSON-RPC error object.
+    Rpc(RpcError),
+    /// The `id` of the response did not match the `id` of the request.
+    NonceMismatch,
+}
+
+impl Error {
+    /// Returns the error code if the daemon returned a JSON-RPC error.
+    pub fn rpc_error_code(&self) -> Option<RpcErrorCode> {
+        match *self {
+            Error::Rpc(ref e) => Some(e.error_code()),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::error::Error> for Error {
+    fn from(e: serde_json::error::Error) -> Error { Error::Json(e) }
+}
+
+impl From<RpcError> for Error {
+    fn from(e: RpcError) -> Error { Error::Rpc(e) }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Error::*;
+
+        match *self {
+            Transport(ref e) => write!(f, "transport error: {}", e),
+            Json(ref e) => write!(f, "JSON error: {}", e),
+            Rpc(ref e) => write!(f, "JSON-RPC error: {}", e),
+            NonceMismatch => write!(f, "nonce of response did not match nonce of request"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use Error::*;
+
+        match *self {
+            Transport(ref e) => Some(&**e),
+            Json(ref e) => Some(e),
+            Rpc(ref e) => Some(e),
+            NonceMismatch => None,
+        }
+    }
+}
+
+/// A JSON-RPC error object returned by the daemon.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RpcError {
+    /// The integer error code.
+    pub code: i32,
+    /// A short description of the error.
+    pub message: String,
+    /// Additional information about the error, if any.
+    pub data: Option<serde_json::Value>,
+}
+
+impl RpcError {
+    /// Returns the typed error code.
+    pub fn error_code(&self) -> RpcErrorCode { RpcErrorCode::from_i32(self.code) }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (code: {})", self.message, self.code)
+    }
+}
+
+impl error::Error for RpcError {}
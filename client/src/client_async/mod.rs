@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Async JSON-RPC clients for Bitcoin Core.
+//!
+//! The async client does not depend on any particular HTTP client or async runtime. Instead the
+//! user provides an [`AsyncTransport`] (e.g. implemented using `reqwest`) that posts the serialized
+//! JSON-RPC request to the daemon and returns the response body.
+//!
+//! # Methods
+//!
+//! Per method wrappers are added to a `Client` by the `impl_async_client_*` macros, one macro per
+//! section of the API docs (e.g. `impl_async_client_v17__blockchain!`). These cover the methods
+//! that pass their arguments straight through to Core, the resulting types are the version
+//! specific types from [`crate::types`] in scope where the macro is invoked. Any other method can
+//! be called using `Client::call`. Use [`crate::impl_async_client_methods!`] to add further wrappers.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::future::Future;
+//! use std::pin::Pin;
+//!
+//! use corepc_client::client_async::{AsyncTransport, Error, Result};
+//! use corepc_client::types::v28::GetBlockchainInfo;
+//!
+//! mod client {
+//!     use corepc_client::types::v28::*;
+//!
+//!     corepc_client::define_jsonrpc_async_client!("v28");
+//!     corepc_client::impl_async_client_v17__blockchain!();
+//! }
+//!
+//! /// A transport that would, for example, `POST` the body using `reqwest`.
+//! struct Transport;
+//!
+//! impl AsyncTransport for Transport {
+//!     fn post<'a>(
+//!         &'a self,
+//!         _body: Vec<u8>,
+//!     ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>> {
+//!         Box::pin(async { Err(Error::Transport("not implemented".into())) })
+//!     }
+//! }
+//!
+//! async fn blockchain_info() -> Result<GetBlockchainInfo> {
+//!     let client = client::Client::with_transport(Transport);
+//!     client.get_blockchain_info().await
+//! }
+//! ```
+
+mod error;
+mod v17;
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub use crate::client_async::error::{Error, RpcError};
+
+/// Crate-specific Result type.
+///
+/// Shorthand for `std::result::Result` with our crate-specific [`Error`] type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An async transport used to send JSON-RPC requests to the daemon.
+pub trait AsyncTransport: Send + Sync {
+    /// Sends `body`, a serialized JSON-RPC request, to the daemon and returns the response body.
+    ///
+    /// Bitcoin Core responds to a failed RPC call with a non-2xx HTTP status code and a JSON-RPC
+    /// error in the body, implementations should return the body in this case.
+    fn post<'a>(
+        &'a self,
+        body: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>>;
+}
+
+/// Defines an async JSON-RPC `Client` that sends requests using an [`AsyncTransport`].
+#[macro_export]
+macro_rules! define_jsonrpc_async_client {
+    ($version:literal) => {
+        use std::fmt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use $crate::client_async::{AsyncTransport, Result};
+
+        /// Client implements an async JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
+        pub struct Client {
+            transport: Box<dyn AsyncTransport>,
+            nonce: AtomicUsize,
+        }
+
+        impl fmt::Debug for Client {
+            fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                write!(f, "corepc_client::client_async::{}::Client", $version)
+            }
+        }
+
+        impl Client {
+            /// Creates a client that sends requests to a bitcoind JSON-RPC server using `transport`.
+            pub fn with_transport<T: AsyncTransport + 'static>(transport: T) -> Self {
+                Self { transport: Box::new(transport), nonce: AtomicUsize::new(1) }
+            }
+
+            /// Call an RPC `method` with given `args` list.
+            pub async fn call<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                let id = self.nonce.fetch_add(1, Ordering::Relaxed);
+                let body = $crate::client_async::build_request(id, method, args)?;
+                let resp = self.transport.post(body).await;
+                $crate::client_async::parse_response(id, method, resp)
+            }
+        }
+    };
+}
+
+/// Implements async methods that pass their arguments straight through to `Client::call`.
+///
+/// Each method is written `name(arg: Type, ...) -> ReturnType = "rpcmethod";` and sends its
+/// arguments, serialized to JSON, in the order given.
+#[macro_export]
+macro_rules! impl_async_client_methods {
+    ($($(#[$attr:meta])* $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty = $method:literal;)*) => {
+        impl Client {
+            $(
+                $(#[$attr])*
+                pub async fn $name(&self, $($arg: $ty),*) -> $crate::client_async::Result<$ret> {
+                    let args = [$($crate::client_async::into_json($arg)?),*];
+                    self.call($method, &args).await
+                }
+            )*
+        }
+    };
+}
+
+/// Shorthand for converting a variable into a `serde_json::Value`.
+#[doc(hidden)]
+pub fn into_json<T>(val: T) -> Result<serde_json::Value>
+where
+    T: serde::ser::Serialize,
+{
+    Ok(serde_json::to_value(val)?)
+}
+
+/// A JSON-RPC request.
+#[derive(serde::Serialize)]
+struct Request<'a> {
+    jsonrpc: &'static str,
+    id: usize,
+    method: &'a str,
+    params: &'a [serde_json::Value],
+}
+
+/// A JSON-RPC response.
+#[derive(serde::Deserialize)]
+struct Response {
+    result: Option<serde_json::Value>,
+    error: Option<RpcError>,
+    id: serde_json::Value,
+}
+
+/// Serializes a JSON-RPC request, used by `define_jsonrpc_async_client!`.
+#[doc(hidden)]
+pub fn build_request(id: usize, method: &str, args: &[serde_json::Value]) -> Result<Vec<u8>> {
+    if log::log_enabled!(log::Level::Debug) {
+        log::debug!(target: "corepc", "request: {} {}", method, serde_json::Value::from(args));
+    }
+    let req = Request { jsonrpc: "2.0", id, method, params: args };
+    Ok(serde_json::to_vec(&req)?)
+}
+
+/// Deserializes the result of a JSON-RPC response, used by `define_jsonrpc_async_client!`.
+#[doc(hidden)]
+pub fn parse_response<T: for<'a> serde::de::Deserialize<'a>>(
+    id: usize,
+    method: &str,
+    body: Result<Vec<u8>>,
+) -> Result<T> {
+    let resp = body.and_then(|body| Ok(serde_json::from_slice::<Response>(&body)?));
+    log_response(method, &resp);
+
+    let resp = resp?;
+    if resp.id != id {
+        return Err(Error::NonceMismatch);
+    }
+    if let Some(e) = resp.error {
+        return Err(Error::Rpc(e));
+    }
+    // Methods that return nothing have a `null` result.
+    Ok(serde_json::from_value(resp.result.unwrap_or(serde_json::Value::Null))?)
+}
+
+/// Helper to log an RPC response.
+fn log_response(method: &str, resp: &Result<Response>) {
+    use log::Level::{Debug, Trace};
+
+    match resp {
+        Err(ref e) =>
+            if log::log_enabled!(Debug) {
+                log::debug!(target: "corepc", "error: {}: {:?}", method, e);
+            },
+        Ok(ref resp) =>
+            if let Some(ref e) = resp.error {
+                if log::log_enabled!(Debug) {
+                    log::debug!(target: "corepc", "response error for {}: {:?}", method, e);
+                }
+            } else if log::log_enabled!(Trace) {
+                let result = resp.result.as_ref().unwrap_or(&serde_json::Value::Null);
+                log::trace!(target: "corepc", "response for {}: {}", method, result);
+            },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    mod client {
+        #![allow(dead_code)] // Not every method is called by the tests.
+
+        use crate::types::v17::*;
+
+        crate::define_jsonrpc_async_client!("v17");
+        crate::impl_async_client_v17__blockchain!();
+        crate::impl_async_client_v17__control!();
+        crate::impl_async_client_v17__mining!();
+        crate::impl_async_client_v17__network!();
+    }
+
+    /// Records each request and responds with `result`, or an RPC error if `result` is `None`.
+    struct MockTransport {
+        result: Option<Value>,
+        requests: Arc<Mutex<Vec<Value>>>,
+    }
+
+    impl AsyncTransport for MockTransport {
+        fn post<'a>(
+            &'a self,
+            body: Vec<u8>,
+        ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>> {
+            Box::pin(async move {
+                let request: Value = serde_json::from_slice(&body)?;
+                let id = request["id"].clone();
+                self.requests.lock().unwrap().push(request);
+                let response = match self.result {
+                    Some(ref result) => json!({ "result": result, "error": null, "id": id }),
+                    None => json!({
+                        "result": null,
+                        "error": { "code": -8, "message": "Block height out of range" },
+                        "id": id,
+                    }),
+                };
+                Ok(serde_json::to_vec(&response)?)
+            })
+        }
+    }
+
+    /// Polls `future` to completion, the mock transport never returns `Poll::Pending`.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn async_method_sends_request_and_parses_result() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let transport = MockTransport { result: Some(json!(101)), requests: requests.clone() };
+        let client = client::Client::with_transport(transport);
+
+        let count = block_on(client.get_block_count()).unwrap();
+        assert_eq!(count.0, 101);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["method"], "getblockcount");
+        assert_eq!(requests[0]["params"], json!([]));
+    }
+
+    #[test]
+    fn async_method_sends_arguments_and_returns_rpc_error() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let transport = MockTransport { result: None, requests: requests.clone() };
+        let client = client::Client::with_transport(transport);
+
+        let err = block_on(client.get_block_hash(1_000)).unwrap_err();
+        assert_eq!(err.rpc_error_code(), Some(crate::RpcErrorCode::InvalidParameter));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0]["method"], "getblockhash");
+        assert_eq!(requests[0]["params"], json!([1_000]));
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found in the API docs of Bitcoin Core `v0.17` whose arguments and
+//! result are unchanged in later versions.
+//!
+//! All macros require `Client` and the version specific types to be in scope.
+//!
+//! See or use the `define_jsonrpc_async_client!` macro to define a `Client`.
+
+/// Implements async methods found under the `== Blockchain ==` section.
+#[macro_export]
+macro_rules! impl_async_client_v17__blockchain {
+    () => {
+        $crate::impl_async_client_methods! {
+            get_best_block_hash() -> GetBestBlockHash = "getbestblockhash";
+            get_blockchain_info() -> GetBlockchainInfo = "getblockchaininfo";
+            get_block_count() -> GetBlockCount = "getblockcount";
+            get_block_hash(height: u64) -> GetBlockHash = "getblockhash";
+            get_chain_tips() -> GetChainTips = "getchaintips";
+            get_difficulty() -> GetDifficulty = "getdifficulty";
+            get_mempool_info() -> GetMempoolInfo = "getmempoolinfo";
+            get_raw_mempool() -> GetRawMempool = "getrawmempool";
+            get_tx_out_set_info() -> GetTxOutSetInfo = "gettxoutsetinfo";
+        }
+    };
+}
+
+/// Implements async methods found under the `== Control ==` section.
+#[macro_export]
+macro_rules! impl_async_client_v17__control {
+    () => {
+        $crate::impl_async_client_methods! {
+            get_memory_info() -> GetMemoryInfoStats = "getmemoryinfo";
+            /// Returns the node uptime in seconds.
+            uptime_secs() -> u64 = "uptime";
+        }
+    };
+}
+
+/// Implements async methods found under the `== Mining ==` section.
+#[macro_export]
+macro_rules! impl_async_client_v17__mining {
+    () => {
+        $crate::impl_async_client_methods! {
+            get_mining_info() -> GetMiningInfo = "getmininginfo";
+        }
+    };
+}
+
+/// Implements async methods found under the `== Network ==` section.
+#[macro_export]
+macro_rules! impl_async_client_v17__network {
+    () => {
+        $crate::impl_async_client_methods! {
+            get_connection_count() -> GetConnectionCount = "getconnectioncount";
+            get_net_totals() -> GetNetTotals = "getnettotals";
+            get_network_info() -> GetNetworkInfo = "getnetworkinfo";
+            get_peer_info() -> GetPeerInfo = "getpeerinfo";
+        }
+    };
+}
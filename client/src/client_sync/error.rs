@@ -4,6 +4,8 @@ use std::{error, fmt, io};
 
 use bitcoin::hex;
 
+use crate::RpcErrorCode;
+
 /// The error type for errors produced in this library.
 #[derive(Debug)]
 pub enum Error {
@@ -22,6 +24,23 @@ pub enum Error {
     ServerVersion(UnexpectedServerVersionError),
     /// Missing user/password.
     MissingUserPassword,
+    /// The daemon did not return a response for a request in a batch.
+    MissingBatchResponse,
+    /// An argument was rejected before making the RPC call.
+    InvalidArgument(String),
+    /// Timed out waiting for the node to reach the expected state.
+    Timeout(std::time::Duration),
+}
+
+impl Error {
+    /// Returns the error code if the daemon returned a JSON-RPC error.
+    pub fn rpc_error_code(&self) -> Option<RpcErrorCode> {
+        match *self {
+            Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e)) =>
+                Some(RpcErrorCode::from_i32(e.code)),
+            _ => None,
+        }
+    }
 }
 
 impl From<jsonrpc::error::Error> for Error {
@@ -64,6 +83,9 @@ impl fmt::Display for Error {
             Returned(ref s) => write!(f, "the daemon returned an error string: {}", s),
             ServerVersion(ref e) => write!(f, "server version: {}", e),
             MissingUserPassword => write!(f, "missing user and/or password"),
+            MissingBatchResponse => write!(f, "missing response for a request in a batch"),
+            InvalidArgument(ref s) => write!(f, "invalid argument: {}", s),
+            Timeout(ref d) => write!(f, "timed out after {:?}", d),
         }
     }
 }
@@ -80,7 +102,8 @@ impl error::Error for Error {
             BitcoinSerialization(ref e) => Some(e),
             Io(ref e) => Some(e),
             ServerVersion(ref e) => Some(e),
-            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword => None,
+            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword
+            | MissingBatchResponse | InvalidArgument(_) | Timeout(_) => None,
         }
     }
 }
@@ -59,20 +59,24 @@ impl Auth {
 macro_rules! define_jsonrpc_minreq_client {
     ($version:literal) => {
         use std::fmt;
+        use std::sync::RwLock;
 
-        use $crate::client_sync::{log_response, Auth, Result};
+        use $crate::client_sync::{is_unauthorized, log_response, minreq_client, Auth, Result};
         use $crate::client_sync::error::Error;
 
         /// Client implements a JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
         pub struct Client {
-            inner: jsonrpc::client::Client,
+            inner: RwLock<jsonrpc::client::Client>,
+            /// The URL and cookie file path, if authenticating using a cookie file.
+            cookie_file: Option<(String, std::path::PathBuf)>,
         }
 
         impl fmt::Debug for Client {
             fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                let inner = self.inner.read().map_err(|_| fmt::Error)?;
                 write!(
                     f,
-                    "corepc_client::client_sync::{}::Client({:?})", $version, self.inner
+                    "corepc_client::client_sync::{}::Client({:?})", $version, *inner
                 )
             }
         }
@@ -80,32 +84,34 @@ macro_rules! define_jsonrpc_minreq_client {
         impl Client {
             /// Creates a client to a bitcoind JSON-RPC server without authentication.
             pub fn new(url: &str) -> Self {
-                let transport = jsonrpc::http::minreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
-
-                Self { inner }
+                let inner = minreq_client(url, None, None);
+                Self { inner: RwLock::new(inner), cookie_file: None }
             }
 
             /// Creates a client to a bitcoind JSON-RPC server with authentication.
+            ///
+            /// When using [`Auth::CookieFile`] the cookie file is read again if the server rejects
+            /// the credentials, bitcoind writes a new cookie every time it starts.
             pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
                 if matches!(auth, Auth::None) {
                     return Err(Error::MissingUserPassword);
                 }
+                let cookie_file = match auth {
+                    Auth::CookieFile(ref path) => Some((url.to_owned(), path.clone())),
+                    _ => None,
+                };
                 let (user, pass) = auth.get_user_pass()?;
 
-                let transport = jsonrpc::http::minreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .basic_auth(user.unwrap(), pass)
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
+                let inner = minreq_client(url, user, pass);
+                Ok(Self { inner: RwLock::new(inner), cookie_file })
+            }
 
-                Ok(Self { inner })
+            /// Creates a client to a bitcoind JSON-RPC server authenticating using the cookie file
+            /// at `path`.
+            ///
+            /// Equivalent to calling [`Client::new_with_auth`] with [`Auth::CookieFile`].
+            pub fn new_from_cookie_file(url: &str, path: &std::path::Path) -> Result<Self> {
+                Self::new_with_auth(url, Auth::CookieFile(path.to_path_buf()))
             }
 
             /// Call an RPC `method` with given `args` list.
@@ -115,19 +121,119 @@ macro_rules! define_jsonrpc_minreq_client {
                 args: &[serde_json::Value],
             ) -> Result<T> {
                 let raw = serde_json::value::to_raw_value(args)?;
-                let req = self.inner.build_request(&method, Some(&*raw));
                 if log::log_enabled!(log::Level::Debug) {
                     log::debug!(target: "corepc", "request: {} {}", method, serde_json::Value::from(args));
                 }
 
-                let resp = self.inner.send_request(req).map_err(Error::from);
+                let mut resp = self.send_request(method, &raw);
+                if is_unauthorized(&resp) && self.reload_cookie_file()? {
+                    resp = self.send_request(method, &raw);
+                }
                 log_response(method, &resp);
                 Ok(resp?.result()?)
             }
+
+            /// Calls a batch of RPC methods using a single HTTP request.
+            ///
+            /// Each item in `calls` is a method name and its `args` list. Responses are matched to
+            /// requests by `id` so the returned results are in the same order as `calls`, a failed
+            /// call does not fail the whole batch.
+            pub fn batch(
+                &self,
+                calls: &[(&str, &[serde_json::Value])],
+            ) -> Result<Vec<Result<serde_json::Value>>> {
+                if calls.is_empty() {
+                    return Ok(vec![]);
+                }
+
+                let raws = calls
+                    .iter()
+                    .map(|(method, args)| Ok((*method, serde_json::value::to_raw_value(args)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(target: "corepc", "batch request: {} calls", raws.len());
+                }
+
+                let mut resps = self.send_batch(&raws);
+                if is_unauthorized(&resps) && self.reload_cookie_file()? {
+                    resps = self.send_batch(&raws);
+                }
+                let results = calls
+                    .iter()
+                    .zip(resps?)
+                    .map(|((method, _), resp)| {
+                        let resp = resp.ok_or(Error::MissingBatchResponse);
+                        log_response(method, &resp);
+                        Ok(resp?.result()?)
+                    })
+                    .collect();
+                Ok(results)
+            }
+
+            fn send_request(
+                &self,
+                method: &str,
+                raw: &serde_json::value::RawValue,
+            ) -> Result<jsonrpc::Response> {
+                let inner = self.inner.read().expect("poisoned lock");
+                let req = inner.build_request(method, Some(raw));
+                Ok(inner.send_request(req)?)
+            }
+
+            fn send_batch(
+                &self,
+                raws: &[(&str, Box<serde_json::value::RawValue>)],
+            ) -> Result<Vec<Option<jsonrpc::Response>>> {
+                let inner = self.inner.read().expect("poisoned lock");
+                let reqs = raws
+                    .iter()
+                    .map(|(method, raw)| inner.build_request(method, Some(&**raw)))
+                    .collect::<Vec<_>>();
+                Ok(inner.send_batch(&reqs)?)
+            }
+
+            /// Re-reads the cookie file, returns `false` if not authenticating using a cookie file.
+            fn reload_cookie_file(&self) -> Result<bool> {
+                let (url, path) = match self.cookie_file {
+                    Some((ref url, ref path)) => (url, path),
+                    None => return Ok(false),
+                };
+                let (user, pass) = Auth::CookieFile(path.clone()).get_user_pass()?;
+
+                let mut inner = self.inner.write().expect("poisoned lock");
+                *inner = minreq_client(url, user, pass);
+                Ok(true)
+            }
         }
     }
 }
 
+/// Creates a `jsonrpc::Client` using the `minreq` transport.
+fn minreq_client(url: &str, user: Option<String>, pass: Option<String>) -> jsonrpc::client::Client {
+    let builder = jsonrpc::http::minreq_http::Builder::new()
+        .url(url)
+        .expect("jsonrpc v0.18, this function does not error")
+        .timeout(std::time::Duration::from_secs(60));
+    let transport = match user {
+        Some(user) => builder.basic_auth(user, pass).build(),
+        None => builder.build(),
+    };
+    jsonrpc::client::Client::with_transport(transport)
+}
+
+/// Returns `true` if the server rejected the request credentials (HTTP status code 401).
+fn is_unauthorized<T>(resp: &Result<T>) -> bool {
+    use jsonrpc::minreq_http::Error as HttpError;
+
+    match resp {
+        Err(Error::JsonRpc(jsonrpc::Error::Transport(ref e))) => matches!(
+            e.downcast_ref::<HttpError>(),
+            Some(HttpError::Http(ref e)) if e.status_code == 401
+        ),
+        _ => false,
+    }
+}
+
 /// Implements the `check_expected_server_version()` on `Client`.
 ///
 /// Requires `Client` to be in scope and implement `server_version()`.
@@ -163,6 +269,13 @@ where
     Ok(serde_json::to_value(val)?)
 }
 
+/// Converts `rate` to BTC/kvB, the unit Core uses for fee rate arguments before v0.21.
+///
+/// Converts from sat/kwu so that rates below 1 sat/vB are not truncated to zero.
+fn fee_rate_to_btc_per_kvb(rate: bitcoin::FeeRate) -> f64 {
+    rate.to_sat_per_kwu() as f64 / 25_000_000.0
+}
+
 /// Shorthand for converting an `Option` into an `Option<serde_json::Value>`.
 #[allow(dead_code)] // TODO: Remove this if unused still when we are done.
 fn opt_into_json<T>(opt: Option<T>) -> Result<serde_json::Value>
@@ -175,6 +288,12 @@ where
     }
 }
 
+/// Trims trailing `null` arguments so that Core uses its defaults for unset optional arguments.
+fn trim_trailing_nulls(args: &[serde_json::Value]) -> &[serde_json::Value] {
+    let len = args.iter().rposition(|arg| !arg.is_null()).map_or(0, |i| i + 1);
+    &args[..len]
+}
+
 /// Shorthand for `serde_json::Value::Null`.
 #[allow(dead_code)] // TODO: Remove this if unused still when we are done.
 fn null() -> serde_json::Value { serde_json::Value::Null }
@@ -59,6 +59,13 @@ macro_rules! impl_client_v17__get_block {
             pub fn get_block_verbose_one(&self, hash: BlockHash) -> Result<GetBlockVerboseOne> {
                 self.call("getblock", &[into_json(hash)?, 1.into()])
             }
+
+            /// Gets a block by blockhash with verbose set to 2.
+            ///
+            /// Includes the decoded transactions and, if block undo data is available, their fees.
+            pub fn get_block_verbose_two(&self, hash: BlockHash) -> Result<GetBlockVerboseTwo> {
+                self.call("getblock", &[into_json(hash)?, 2.into()])
+            }
         }
     };
 }
@@ -71,6 +78,30 @@ macro_rules! impl_client_v17__get_block_count {
             pub fn get_block_count(&self) -> Result<GetBlockCount> {
                 self.call("getblockcount", &[])
             }
+
+            /// Polls `getblockcount` until the chain reaches `height`, returns the block count.
+            ///
+            /// Returns `Error::Timeout` if `height` is not reached within `timeout`.
+            pub fn wait_for_block_height(
+                &self,
+                height: u64,
+                timeout: std::time::Duration,
+            ) -> Result<u64> {
+                const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+                let start = std::time::Instant::now();
+                loop {
+                    let count = self.get_block_count()?.0;
+                    if count >= height {
+                        return Ok(count);
+                    }
+                    let elapsed = start.elapsed();
+                    if elapsed >= timeout {
+                        return Err(Error::Timeout(timeout));
+                    }
+                    std::thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+                }
+            }
         }
     };
 }
@@ -112,12 +143,32 @@ macro_rules! impl_client_v17__get_block_header {
 macro_rules! impl_client_v17__get_block_stats {
     () => {
         impl Client {
-            pub fn get_block_stats_by_height(&self, height: u32) -> Result<GetBlockStats> {
-                self.call("getblockstats", &[into_json(height)?])
+            /// Computes per block statistics for the block at `height`.
+            ///
+            /// Only the fields listed in `stats` (e.g. `["avgfee", "height"]`) are returned, all
+            /// fields if `None`.
+            pub fn get_block_stats_by_height(
+                &self,
+                height: u32,
+                stats: Option<&[&str]>,
+            ) -> Result<GetBlockStats> {
+                use $crate::client_sync::opt_into_json;
+                let args = [into_json(height)?, opt_into_json(stats)?];
+                self.call("getblockstats", $crate::client_sync::trim_trailing_nulls(&args))
             }
 
-            pub fn get_block_stats_by_block_hash(&self, hash: &BlockHash) -> Result<GetBlockStats> {
-                self.call("getblockstats", &[into_json(hash)?])
+            /// Computes per block statistics for the block with `hash`.
+            ///
+            /// Only the fields listed in `stats` (e.g. `["avgfee", "height"]`) are returned, all
+            /// fields if `None`.
+            pub fn get_block_stats_by_block_hash(
+                &self,
+                hash: &BlockHash,
+                stats: Option<&[&str]>,
+            ) -> Result<GetBlockStats> {
+                use $crate::client_sync::opt_into_json;
+                let args = [into_json(hash)?, opt_into_json(stats)?];
+                self.call("getblockstats", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -138,8 +189,18 @@ macro_rules! impl_client_v17__get_chain_tips {
 macro_rules! impl_client_v17__get_chain_tx_stats {
     () => {
         impl Client {
-            pub fn get_chain_tx_stats(&self) -> Result<GetChainTxStats> {
-                self.call("getchaintxstats", &[])
+            /// Computes statistics about the total number and rate of transactions in the chain.
+            ///
+            /// The window is `nblocks` blocks ending at `block_hash`, Core defaults to one month
+            /// ending at the chain tip.
+            pub fn get_chain_tx_stats(
+                &self,
+                nblocks: Option<u32>,
+                block_hash: Option<BlockHash>,
+            ) -> Result<GetChainTxStats> {
+                use $crate::client_sync::opt_into_json;
+                let args = [opt_into_json(nblocks)?, opt_into_json(block_hash)?];
+                self.call("getchaintxstats", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -254,8 +315,20 @@ macro_rules! impl_client_v17__get_tx_out {
 macro_rules! impl_client_v17__get_tx_out_proof {
     () => {
         impl Client {
-            pub fn get_tx_out_proof(&self, txids: &[Txid]) -> Result<String> {
-                self.call("gettxoutproof", &[into_json(txids)?])
+            /// Gets a hex-encoded proof that `txids` were included in a block.
+            ///
+            /// Without `block_hash` Core can only find the block if one of the transactions has
+            /// an unspent output or `-txindex` is enabled.
+            pub fn get_tx_out_proof(
+                &self,
+                txids: &[Txid],
+                block_hash: Option<BlockHash>,
+            ) -> Result<String> {
+                match block_hash {
+                    Some(hash) =>
+                        self.call("gettxoutproof", &[into_json(txids)?, into_json(hash)?]),
+                    None => self.call("gettxoutproof", &[into_json(txids)?]),
+                }
             }
         }
     };
@@ -278,6 +351,9 @@ macro_rules! impl_client_v17__get_tx_out_set_info {
 macro_rules! impl_client_v17__precious_block {
     () => {
         impl Client {
+            /// Treats block `hash` as if it were received before others with the same work.
+            ///
+            /// Calling this on a block that is already the active tip is a no-op.
             pub fn precious_block(&self, hash: BlockHash) -> Result<()> {
                 match self.call("preciousblock", &[into_json(hash)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -295,9 +371,41 @@ macro_rules! impl_client_v17__prune_blockchain {
     () => {
         impl Client {
             /// Instructs the node to prune the blockchain up to a specified height or timestamp.
+            ///
+            /// Core treats `target` as a block height if it is less than 1,000,000,000 and as a
+            /// UNIX timestamp otherwise. Prefer `prune_blockchain_to_height` or
+            /// `prune_blockchain_to_timestamp` which make the intent explicit.
             pub fn prune_blockchain(&self, target: u64) -> Result<PruneBlockchain> {
                 self.call("pruneblockchain", &[target.into()])
             }
+
+            /// Instructs the node to prune the blockchain up to block `height`.
+            ///
+            /// Returns an error without calling the node if `height` would be interpreted by Core
+            /// as a timestamp.
+            pub fn prune_blockchain_to_height(&self, height: u32) -> Result<PruneBlockchain> {
+                if u64::from(height) >= 1_000_000_000 {
+                    return Err(Error::InvalidArgument(format!(
+                        "prune height {} would be interpreted as a timestamp",
+                        height
+                    )));
+                }
+                self.prune_blockchain(height.into())
+            }
+
+            /// Instructs the node to prune the blockchain up to the block with UNIX `timestamp`.
+            ///
+            /// Returns an error without calling the node if `timestamp` would be interpreted by
+            /// Core as a block height.
+            pub fn prune_blockchain_to_timestamp(&self, timestamp: u64) -> Result<PruneBlockchain> {
+                if timestamp < 1_000_000_000 {
+                    return Err(Error::InvalidArgument(format!(
+                        "prune timestamp {} would be interpreted as a block height",
+                        timestamp
+                    )));
+                }
+                self.prune_blockchain(timestamp)
+            }
         }
     };
 }
@@ -15,7 +15,14 @@ macro_rules! impl_client_v17__get_memory_info {
     () => {
         impl Client {
             pub fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
-                self.call("getmemoryinfo", &[])
+                self.call("getmemoryinfo", &["stats".into()])
+            }
+
+            /// Gets an XML string describing the low-level heap state (`mode` set to "mallocinfo").
+            ///
+            /// Only available if Core was compiled with glibc 2.10+.
+            pub fn get_memory_info_mallocinfo(&self) -> Result<String> {
+                self.call("getmemoryinfo", &["mallocinfo".into()])
             }
         }
     };
@@ -36,7 +43,15 @@ macro_rules! impl_client_v17__help {
 macro_rules! impl_client_v17__logging {
     () => {
         impl Client {
-            pub fn logging(&self) -> Result<Logging> { self.call("logging", &[]) }
+            /// Gets the logging configuration, first enabling the `include` categories and
+            /// disabling the `exclude` categories. Pass empty slices to only query the config.
+            pub fn logging(&self, include: &[&str], exclude: &[&str]) -> Result<Logging> {
+                if include.is_empty() && exclude.is_empty() {
+                    self.call("logging", &[])
+                } else {
+                    self.call("logging", &[into_json(include)?, into_json(exclude)?])
+                }
+            }
         }
     };
 }
@@ -56,7 +71,13 @@ macro_rules! impl_client_v17__stop {
 macro_rules! impl_client_v17__uptime {
     () => {
         impl Client {
-            pub fn uptime(&self) -> Result<u32> { self.call("uptime", &[]) }
+            /// Returns the total uptime of the server.
+            pub fn uptime(&self) -> Result<std::time::Duration> {
+                self.uptime_secs().map(std::time::Duration::from_secs)
+            }
+
+            /// Returns the total uptime of the server in seconds.
+            pub fn uptime_secs(&self) -> Result<u64> { self.call("uptime", &[]) }
         }
     };
 }
@@ -14,12 +14,17 @@
 macro_rules! impl_client_v17__generate_to_address {
     () => {
         impl Client {
+            /// Mines `nblocks` blocks to `address`, Core tries up to 1000000 iterations per block if
+            /// `max_tries` is `None`.
             pub fn generate_to_address(
                 &self,
-                nblocks: usize,
+                nblocks: u64,
                 address: &bitcoin::Address,
+                max_tries: Option<u64>,
             ) -> Result<GenerateToAddress> {
-                self.call("generatetoaddress", &[nblocks.into(), into_json(address)?])
+                use $crate::client_sync::opt_into_json;
+                let args = [nblocks.into(), into_json(address)?, opt_into_json(max_tries)?];
+                self.call("generatetoaddress", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
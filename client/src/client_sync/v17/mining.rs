@@ -41,7 +41,20 @@ macro_rules! impl_client_v17__get_mining_info {
 macro_rules! impl_client_v17__get_network_hashes_per_second {
     () => {
         impl Client {
-            pub fn get_network_hash_ps(&self) -> Result<f64> { self.call("getnetworkhashps", &[]) }
+            /// Gets the estimated network hashes per second.
+            ///
+            /// Averaged over the last `nblocks` blocks (default 120, -1 for since the last
+            /// difficulty change) ending at `height` (default -1 for the chain tip).
+            pub fn get_network_hash_ps(
+                &self,
+                nblocks: Option<i64>,
+                height: Option<i64>,
+            ) -> Result<f64> {
+                use $crate::client_sync::opt_into_json;
+
+                let args = [opt_into_json(nblocks)?, opt_into_json(height)?];
+                self.call("getnetworkhashps", $crate::client_sync::trim_trailing_nulls(&args))
+            }
         }
     };
 }
@@ -51,6 +64,11 @@ macro_rules! impl_client_v17__get_network_hashes_per_second {
 macro_rules! impl_client_v17__prioritise_transaction {
     () => {
         impl Client {
+            /// Adjusts the fee used by block template selection for `txid` by `fee_delta`.
+            ///
+            /// A negative `fee_delta` deprioritises the transaction. The fee is not actually paid,
+            /// only the fee used by the mining algorithm changes. The deprecated `dummy` argument
+            /// is always sent as 0.
             pub fn prioritise_transaction(
                 &self,
                 txid: &Txid,
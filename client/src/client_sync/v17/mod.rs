@@ -18,7 +18,10 @@ use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use bitcoin::address::{Address, NetworkChecked};
-use bitcoin::{sign_message, Amount, Block, BlockHash, PublicKey, Txid};
+use bitcoin::{
+    absolute, sign_message, Amount, Block, BlockHash, FeeRate, OutPoint, PublicKey, ScriptBuf,
+    Sequence, Txid,
+};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::client_sync::into_json;
@@ -134,7 +137,7 @@ crate::impl_client_v17__import_privkey!();
 crate::impl_client_v17__import_pruned_funds!();
 crate::impl_client_v17__import_pubkey!();
 crate::impl_client_v17__import_wallet!();
-crate::impl_client_v17__key_pool_refill!();
+crate::impl_client_v17__keypool_refill!();
 crate::impl_client_v17__list_address_groupings!();
 crate::impl_client_v17__list_labels!();
 crate::impl_client_v17__list_lock_unspent!();
@@ -163,7 +166,7 @@ crate::impl_client_v17__wallet_process_psbt!();
 // == Zmq ==
 crate::impl_client_v17__get_zmq_notifications!();
 
-/// Argument to the `Client::get_new_address_with_type` function.
+/// Argument to the address generating functions e.g., `Client::new_address_with_type`.
 ///
 /// For Core versions 0.17 through to v22. For Core v23 and onwards use `v23::AddressType`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -189,11 +192,31 @@ impl fmt::Display for AddressType {
 
 /// Arg for the `getblocktemplate` method.
 ///
-/// For Core versions 0.17 through to v28. For Core v29 and onwards use `v29::TemplateRequest`.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+/// Modern versions of Core require `rules` to include [`TemplateRules::Segwit`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TemplateRequest {
-    /// A list of strings.
+    /// Either "template", "proposal" (see BIP 23), or omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// Client side supported features e.g., "longpoll", "coinbasevalue", "proposal".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+    /// Client side supported softfork deployments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<TemplateRules>,
+    /// Delay processing the request until the result would vary significantly from this id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longpollid: Option<String>,
+    /// Proposed block data to check, hex encoded (only valid with `mode` set to "proposal").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+}
+
+impl TemplateRequest {
+    /// Creates a template request with `rules` set to `segwit` and all other fields omitted.
+    pub fn segwit() -> Self {
+        TemplateRequest { rules: vec![TemplateRules::Segwit], ..Default::default() }
+    }
 }
 
 /// Client side supported softfork deployment.
@@ -225,19 +248,113 @@ pub struct Input {
 // Abuse `HashMap` so we can derive serialize to get the correct JSON object.
 #[derive(Debug, Serialize)]
 pub struct Output(
-    /// Map of address to value. Always only has a single item in it.
-    HashMap<String, f64>,
+    /// Map of address to value in BTC. Always only has a single item in it.
+    HashMap<String, String>,
 );
 
 impl Output {
     /// Creates a single output that serializes as Core expects.
+    ///
+    /// The value is serialized as a BTC decimal string so no precision is lost.
     pub fn new(addr: Address, value: Amount) -> Self {
         let mut map = HashMap::new();
-        map.insert(addr.to_string(), value.to_btc());
+        map.insert(addr.to_string(), value.to_string_in(bitcoin::Denomination::Bitcoin));
         Output(map)
     }
 }
 
+/// Builds the arguments for the `createrawtransaction` method.
+///
+/// Output amounts are serialized as BTC decimal strings and the `data` output as hex.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CreateRawTransactionBuilder {
+    inputs: Vec<(OutPoint, Option<Sequence>)>,
+    outputs: Vec<(Address, Amount)>,
+    data: Option<Vec<u8>>,
+    locktime: Option<absolute::LockTime>,
+    replaceable: Option<bool>,
+}
+
+impl CreateRawTransactionBuilder {
+    /// Creates a new builder with no inputs or outputs.
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds an input spending `outpoint`.
+    pub fn input(mut self, outpoint: OutPoint) -> Self {
+        self.inputs.push((outpoint, None));
+        self
+    }
+
+    /// Adds an input spending `outpoint` with an explicit `sequence` number.
+    pub fn input_with_sequence(mut self, outpoint: OutPoint, sequence: Sequence) -> Self {
+        self.inputs.push((outpoint, Some(sequence)));
+        self
+    }
+
+    /// Adds an output paying `amount` to `address`.
+    pub fn output(mut self, address: Address, amount: Amount) -> Self {
+        self.outputs.push((address, amount));
+        self
+    }
+
+    /// Sets the data of the `OP_RETURN` output (Core allows only one).
+    pub fn data(mut self, data: &[u8]) -> Self {
+        self.data = Some(data.to_vec());
+        self
+    }
+
+    /// Sets the transaction lock time.
+    pub fn locktime(mut self, locktime: absolute::LockTime) -> Self {
+        self.locktime = Some(locktime);
+        self
+    }
+
+    /// Sets whether the transaction signals BIP-125 replaceability.
+    ///
+    /// Ignored by Core for inputs with an explicit sequence number.
+    pub fn replaceable(mut self, replaceable: bool) -> Self {
+        self.replaceable = Some(replaceable);
+        self
+    }
+
+    /// Returns the positional arguments for `createrawtransaction`.
+    pub fn params(&self) -> Vec<serde_json::Value> {
+        use bitcoin::hex::DisplayHex as _;
+        use serde_json::{json, Value};
+
+        let inputs: Vec<Value> = self
+            .inputs
+            .iter()
+            .map(|(outpoint, sequence)| {
+                let mut input = json!({ "txid": outpoint.txid.to_string(), "vout": outpoint.vout });
+                if let Some(sequence) = sequence {
+                    input["sequence"] = sequence.to_consensus_u32().into();
+                }
+                input
+            })
+            .collect();
+
+        let mut outputs: Vec<Value> = self
+            .outputs
+            .iter()
+            .map(|(address, amount)| {
+                json!({ address.to_string(): amount.to_string_in(bitcoin::Denomination::Bitcoin) })
+            })
+            .collect();
+        if let Some(ref data) = self.data {
+            outputs.push(json!({ "data": data.to_lower_hex_string() }));
+        }
+
+        let params = [
+            Value::Array(inputs),
+            Value::Array(outputs),
+            self.locktime.map_or(Value::Null, |l| l.to_consensus_u32().into()),
+            self.replaceable.map_or(Value::Null, Value::Bool),
+        ];
+        crate::client_sync::trim_trailing_nulls(&params).to_vec()
+    }
+}
+
 /// An element in the `inputs` argument of method `walletcreatefundedpsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct WalletCreateFundedPsbtInput {
@@ -250,12 +367,61 @@ impl WalletCreateFundedPsbtInput {
     pub fn new(txid: Txid, vout: u32) -> Self { Self { txid, vout } }
 }
 
+/// Args for the `walletcreatefundedpsbt` method `options` argument.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCreateFundedPsbtOptions {
+    /// The address to receive the change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address>,
+    /// The index of the change output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// Also select inputs which are watch only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Lock selected unspent outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// Set a specific fee rate, sent to Core in BTC/kvB.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_fee_rate_btc_kvb"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// The outputs to subtract the fee from, by index.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether the transaction should signal BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    #[serde(rename = "conf_target", skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u32>,
+    /// The fee estimate mode.
+    #[serde(rename = "estimate_mode", skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+fn serialize_fee_rate_btc_kvb<S: Serializer>(
+    rate: &Option<FeeRate>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match rate {
+        Some(rate) => serializer.serialize_f64(crate::client_sync::fee_rate_to_btc_per_kvb(*rate)),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Args for the `addnode` method.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AddNodeCommand {
+    /// Adds the node to the added node list and keeps trying to connect to it.
     Add,
+    /// Removes the node from the added node list.
     Remove,
+    /// Makes a single, transient connection attempt without adding the node to the list.
     OneTry,
 }
 
@@ -267,6 +433,140 @@ pub enum SetBanCommand {
     Remove,
 }
 
+/// Args for the `bumpfee` method `options` argument.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BumpFeeOptions {
+    /// Confirmation target in blocks.
+    pub conf_target: Option<u32>,
+    /// Fee rate of the new transaction, Core v0.19 onwards.
+    pub fee_rate: Option<FeeRate>,
+    /// Whether the new transaction should signal BIP-125 replaceability.
+    pub replaceable: Option<bool>,
+    /// The fee estimate mode.
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// Args for the `createwallet` method optional arguments.
+///
+/// Unset fields use Core's defaults, `descriptors` and `load_on_startup` are Core v21 onwards.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateWalletOptions {
+    /// Disable the possibility of private keys (only watchonlys are possible in this mode).
+    pub disable_private_keys: Option<bool>,
+    /// Create a blank wallet, a blank wallet has no keys or HD seed.
+    pub blank: Option<bool>,
+    /// Encrypt the wallet with this passphrase.
+    pub passphrase: Option<String>,
+    /// Keep track of coin reuse, and treat dirty and clean coins differently.
+    pub avoid_reuse: Option<bool>,
+    /// Create a native descriptor wallet.
+    pub descriptors: Option<bool>,
+    /// Save wallet name to persistent settings and load on startup.
+    pub load_on_startup: Option<bool>,
+}
+
+/// Args for the `estimate_mode` argument of the fee estimating methods.
+///
+/// Serialized in upper case, the form listed in the Core v0.17 help text and accepted by all
+/// supported versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EstimateMode {
+    /// Use Core's default mode.
+    Unset,
+    /// Potentially lower fee, responds faster to short term drops in fee market.
+    Economical,
+    /// Potentially higher fee, considers a longer history of blocks.
+    Conservative,
+}
+
+impl fmt::Display for EstimateMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use EstimateMode::*;
+
+        let s = match *self {
+            Unset => "UNSET",
+            Economical => "ECONOMICAL",
+            Conservative => "CONSERVATIVE",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
+
+/// An element in the `prevtxs` argument of the `signrawtransaction*` methods.
+///
+/// Used to sign inputs spending outputs the node does not know about yet.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrevTx {
+    /// The id of the transaction that contains the output.
+    pub txid: Txid,
+    /// The index of the output.
+    pub vout: u32,
+    /// The script pubkey of the output.
+    pub script_pub_key: ScriptBuf,
+    /// The redeem script, required for P2SH outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, required for P2WSH outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<ScriptBuf>,
+    /// The value of the output, required for segwit outputs. Sent as a BTC decimal string.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_amount_btc_string"
+    )]
+    pub amount: Option<Amount>,
+}
+
+fn serialize_amount_btc_string<S: Serializer>(
+    amount: &Option<Amount>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match amount {
+        Some(amount) =>
+            serializer.serialize_str(&amount.to_string_in(bitcoin::Denomination::Bitcoin)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Args for the `sighashtype` argument of the signing methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SighashType {
+    /// Sign all inputs and outputs.
+    #[serde(rename = "ALL")]
+    All,
+    /// Sign all inputs and no outputs.
+    #[serde(rename = "NONE")]
+    None,
+    /// Sign all inputs and the output with the same index.
+    #[serde(rename = "SINGLE")]
+    Single,
+    /// Sign this input and all outputs.
+    #[serde(rename = "ALL|ANYONECANPAY")]
+    AllPlusAnyoneCanPay,
+    /// Sign this input and no outputs.
+    #[serde(rename = "NONE|ANYONECANPAY")]
+    NonePlusAnyoneCanPay,
+    /// Sign this input and the output with the same index.
+    #[serde(rename = "SINGLE|ANYONECANPAY")]
+    SinglePlusAnyoneCanPay,
+}
+
+impl fmt::Display for SighashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            SighashType::All => "ALL",
+            SighashType::None => "NONE",
+            SighashType::Single => "SINGLE",
+            SighashType::AllPlusAnyoneCanPay => "ALL|ANYONECANPAY",
+            SighashType::NonePlusAnyoneCanPay => "NONE|ANYONECANPAY",
+            SighashType::SinglePlusAnyoneCanPay => "SINGLE|ANYONECANPAY",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
+
 /// Args for the `importmulti` method.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ImportMultiRequest {
@@ -280,6 +580,36 @@ pub struct ImportMultiRequest {
     pub timestamp: ImportMultiTimestamp,
 }
 
+/// Args for the `listunspent` method `query_options` argument.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListUnspentQueryOptions {
+    /// Minimum value of each UTXO.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub minimum_amount: Option<Amount>,
+    /// Maximum value of each UTXO.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub maximum_amount: Option<Amount>,
+    /// Maximum number of UTXOs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_count: Option<u32>,
+    /// Minimum sum value of all UTXOs.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub minimum_sum_amount: Option<Amount>,
+}
+
 /// `scriptPubKey` can be a string for script or json for address.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -311,3 +641,145 @@ impl Serialize for ImportMultiTimestamp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn create_raw_transaction_builder_params() {
+        let txid = Txid::all_zeros();
+        let address = Address::p2wsh(&bitcoin::ScriptBuf::new(), bitcoin::KnownHrp::Regtest);
+
+        let builder = CreateRawTransactionBuilder::new()
+            .input(OutPoint { txid, vout: 0 })
+            .input_with_sequence(OutPoint { txid, vout: 1 }, Sequence::from_consensus(10))
+            .output(address.clone(), Amount::from_sat(1))
+            .data(&[0xde, 0xad]);
+        let expected = vec![
+            json!([
+                { "txid": txid.to_string(), "vout": 0 },
+                { "txid": txid.to_string(), "vout": 1, "sequence": 10 },
+            ]),
+            json!([{ address.to_string(): "0.00000001" }, { "data": "dead" }]),
+        ];
+        assert_eq!(builder.params(), expected);
+
+        // Unset lock time is sent as null if `replaceable` is set.
+        let builder = builder.replaceable(true);
+        assert_eq!(builder.params()[2], serde_json::Value::Null);
+        assert_eq!(builder.params()[3], json!(true));
+
+        let builder = builder.locktime(absolute::LockTime::from_consensus(100));
+        assert_eq!(builder.params()[2], json!(100));
+    }
+
+    #[test]
+    fn output_serializes_value_as_btc_string() {
+        let address = Address::p2wsh(&bitcoin::ScriptBuf::new(), bitcoin::KnownHrp::Regtest);
+        // 0.12345678 has no exact f64 representation.
+        let output = Output::new(address.clone(), Amount::from_sat(12_345_678));
+        assert_eq!(
+            serde_json::to_value(&output).unwrap(),
+            json!({ address.to_string(): "0.12345678" })
+        );
+    }
+
+    #[test]
+    fn estimate_mode_serializes_to_core_strings() {
+        for (mode, want) in [
+            (EstimateMode::Unset, "UNSET"),
+            (EstimateMode::Economical, "ECONOMICAL"),
+            (EstimateMode::Conservative, "CONSERVATIVE"),
+        ] {
+            assert_eq!(serde_json::to_value(mode).unwrap(), json!(want));
+            assert_eq!(mode.to_string(), want);
+        }
+    }
+
+    #[test]
+    fn wallet_create_funded_psbt_options_serialize_core_keys() {
+        let options = WalletCreateFundedPsbtOptions {
+            change_position: Some(1),
+            fee_rate: Some(FeeRate::from_sat_per_vb(10).unwrap()),
+            subtract_fee_from_outputs: vec![0],
+            conf_target: Some(6),
+            ..Default::default()
+        };
+        let want = json!({
+            "changePosition": 1,
+            "feeRate": 0.0001,
+            "subtractFeeFromOutputs": [0],
+            "conf_target": 6,
+        });
+        assert_eq!(serde_json::to_value(&options).unwrap(), want);
+    }
+
+    #[test]
+    fn wallet_create_funded_psbt_options_fee_rate_below_one_sat_per_vb() {
+        // 100 sat/kwu is 0.4 sat/vB.
+        let options = WalletCreateFundedPsbtOptions {
+            fee_rate: Some(FeeRate::from_sat_per_kwu(100)),
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_value(&options).unwrap(), json!({ "feeRate": 0.000004 }));
+    }
+
+    #[test]
+    fn sighash_type_serializes_to_core_strings() {
+        for (ty, want) in [
+            (SighashType::All, "ALL"),
+            (SighashType::None, "NONE"),
+            (SighashType::Single, "SINGLE"),
+            (SighashType::AllPlusAnyoneCanPay, "ALL|ANYONECANPAY"),
+            (SighashType::NonePlusAnyoneCanPay, "NONE|ANYONECANPAY"),
+            (SighashType::SinglePlusAnyoneCanPay, "SINGLE|ANYONECANPAY"),
+        ] {
+            assert_eq!(serde_json::to_value(ty).unwrap(), json!(want));
+            assert_eq!(ty.to_string(), want);
+        }
+    }
+
+    #[test]
+    fn prev_tx_serializes_core_keys() {
+        let prev_tx = PrevTx {
+            txid: Txid::all_zeros(),
+            vout: 1,
+            script_pub_key: bitcoin::ScriptBuf::from_bytes(vec![0x51]),
+            redeem_script: None,
+            witness_script: None,
+            amount: Some(Amount::from_sat(1_001)),
+        };
+        let want = json!({
+            "txid": Txid::all_zeros().to_string(),
+            "vout": 1,
+            "scriptPubKey": "51",
+            "amount": "0.00001001",
+        });
+        assert_eq!(serde_json::to_value(&prev_tx).unwrap(), want);
+    }
+
+    #[test]
+    fn template_request_omits_unset_fields() {
+        assert_eq!(
+            serde_json::to_value(TemplateRequest::segwit()).unwrap(),
+            json!({ "rules": ["segwit"] })
+        );
+
+        let request = TemplateRequest {
+            mode: Some("template".to_string()),
+            capabilities: vec!["longpoll".to_string()],
+            rules: vec![TemplateRules::Segwit, TemplateRules::Taproot],
+            ..Default::default()
+        };
+        let want = json!({
+            "mode": "template",
+            "capabilities": ["longpoll"],
+            "rules": ["segwit", "taproot"],
+        });
+        assert_eq!(serde_json::to_value(&request).unwrap(), want);
+    }
+}
@@ -14,6 +14,10 @@
 macro_rules! impl_client_v17__add_node {
     () => {
         impl Client {
+            /// Adds, removes or tries a connection to `node` (e.g. "192.0.2.1:8333").
+            ///
+            /// Nodes connected to with [`AddNodeCommand::OneTry`] do not show up in
+            /// `getaddednodeinfo`.
             pub fn add_node(&self, node: &str, command: AddNodeCommand) -> Result<()> {
                 match self.call("addnode", &[into_json(node)?, into_json(command)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -47,12 +51,27 @@ macro_rules! impl_client_v17__disconnect_node {
     () => {
         impl Client {
             pub fn disconnect_node(&self, address: &str) -> Result<()> {
+                self.disconnect_node_by_address(address)
+            }
+
+            /// Disconnects from the peer with `address` e.g., "192.168.0.6:8333".
+            pub fn disconnect_node_by_address(&self, address: &str) -> Result<()> {
                 match self.call("disconnectnode", &[into_json(address)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
                 }
             }
+
+            /// Disconnects from the peer with node id `node_id` (see `id` in `getpeerinfo`).
+            pub fn disconnect_node_by_id(&self, node_id: u64) -> Result<()> {
+                // Core requires the address to be empty when disconnecting by id.
+                match self.call("disconnectnode", &[into_json("")?, into_json(node_id)?]) {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
         }
     };
 }
@@ -62,8 +81,11 @@ macro_rules! impl_client_v17__disconnect_node {
 macro_rules! impl_client_v17__get_added_node_info {
     () => {
         impl Client {
-            pub fn get_added_node_info(&self) -> Result<GetAddedNodeInfo> {
-                self.call("getaddednodeinfo", &[])
+            pub fn get_added_node_info(&self, node: Option<&str>) -> Result<GetAddedNodeInfo> {
+                match node {
+                    Some(node) => self.call("getaddednodeinfo", &[into_json(node)?]),
+                    None => self.call("getaddednodeinfo", &[]),
+                }
             }
         }
     };
@@ -150,8 +172,27 @@ macro_rules! impl_client_v17__ping {
 macro_rules! impl_client_v17__set_ban {
     () => {
         impl Client {
-            pub fn set_ban(&self, subnet: &str, command: SetBanCommand) -> Result<()> {
-                match self.call("setban", &[into_json(subnet)?, into_json(command)?]) {
+            /// Adds or removes `subnet` (an IP with optional netmask e.g., "192.0.2.0/24") from the
+            /// ban list.
+            ///
+            /// `bantime` is in seconds (0 or `None` for the node's default of 24 hours), or a
+            /// UNIX timestamp if `absolute` is `Some(true)`. Both are ignored when removing.
+            pub fn set_ban(
+                &self,
+                subnet: &str,
+                command: SetBanCommand,
+                bantime: Option<u64>,
+                absolute: Option<bool>,
+            ) -> Result<()> {
+                use $crate::client_sync::opt_into_json;
+
+                let args = [
+                    into_json(subnet)?,
+                    into_json(command)?,
+                    opt_into_json(bantime)?,
+                    opt_into_json(absolute)?,
+                ];
+                match self.call("setban", $crate::client_sync::trim_trailing_nulls(&args)) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
@@ -166,7 +207,8 @@ macro_rules! impl_client_v17__set_ban {
 macro_rules! impl_client_v17__set_network_active {
     () => {
         impl Client {
-            pub fn set_network_active(&self, state: bool) -> Result<SetNetworkActive> {
+            /// Disables or enables all p2p network activity, returns whether networking is now active.
+            pub fn set_network_active(&self, state: bool) -> Result<bool> {
                 self.call("setnetworkactive", &[into_json(state)?])
             }
         }
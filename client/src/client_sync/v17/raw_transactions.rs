@@ -14,7 +14,17 @@
 macro_rules! impl_client_v17__combine_psbt {
     () => {
         impl Client {
+            /// Combines the signatures and other data of `txs` into a single PSBT.
+            ///
+            /// Returns [`Error::InvalidArgument`] without calling Core if fewer than two PSBTs
+            /// are supplied because Core's own error for this case is cryptic.
             pub fn combine_psbt(&self, txs: &[bitcoin::Psbt]) -> Result<CombinePsbt> {
+                if txs.len() < 2 {
+                    return Err(Error::InvalidArgument(format!(
+                        "combinepsbt requires at least two PSBTs, got {}",
+                        txs.len()
+                    )));
+                }
                 let txs = txs.iter().map(|psbt| format!("{}", psbt)).collect::<Vec<String>>();
                 self.call("combinepsbt", &[txs.into()])
             }
@@ -46,9 +56,23 @@ macro_rules! impl_client_v17__combine_raw_transaction {
 macro_rules! impl_client_v17__convert_to_psbt {
     () => {
         impl Client {
-            pub fn convert_to_psbt(&self, tx: &bitcoin::Transaction) -> Result<ConvertToPsbt> {
+            /// Converts `tx` to a PSBT.
+            ///
+            /// Core errors if `tx` has signatures unless `permit_sig_data` is set, in which case
+            /// they are dropped. `is_witness` overrides Core's guess of the serialization format.
+            pub fn convert_to_psbt(
+                &self,
+                tx: &bitcoin::Transaction,
+                permit_sig_data: Option<bool>,
+                is_witness: Option<bool>,
+            ) -> Result<ConvertToPsbt> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
-                self.call("converttopsbt", &[hex.into()])
+                let args = [
+                    hex.into(),
+                    $crate::client_sync::opt_into_json(permit_sig_data)?,
+                    $crate::client_sync::opt_into_json(is_witness)?,
+                ];
+                self.call("converttopsbt", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -78,6 +102,14 @@ macro_rules! impl_client_v17__create_raw_transaction {
             ) -> Result<CreateRawTransaction> {
                 self.call("createrawtransaction", &[into_json(inputs)?, into_json(outputs)?])
             }
+
+            /// Calls `createrawtransaction` with the arguments built by `builder`.
+            pub fn create_raw_transaction_from_builder(
+                &self,
+                builder: &CreateRawTransactionBuilder,
+            ) -> Result<CreateRawTransaction> {
+                self.call("createrawtransaction", &builder.params())
+            }
         }
     };
 }
@@ -99,10 +131,17 @@ macro_rules! impl_client_v17__decode_psbt {
 macro_rules! impl_client_v17__finalize_psbt {
     () => {
         impl Client {
-            pub fn finalize_psbt(&self, psbt: &bitcoin::Psbt) -> Result<FinalizePsbt> {
+            /// If `extract` is `None` Core defaults to extracting the transaction when complete.
+            pub fn finalize_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                extract: Option<bool>,
+            ) -> Result<FinalizePsbt> {
                 let psbt = format!("{}", psbt);
-                // Pass extract=false so Core returns the PSBT field in the response.
-                self.call("finalizepsbt", &[psbt.into(), false.into()])
+                match extract {
+                    Some(extract) => self.call("finalizepsbt", &[psbt.into(), extract.into()]),
+                    None => self.call("finalizepsbt", &[psbt.into()]),
+                }
             }
         }
     };
@@ -142,12 +181,23 @@ macro_rules! impl_client_v17__decode_script {
 macro_rules! impl_client_v17__fund_raw_transaction {
     () => {
         impl Client {
+            /// Funds `tx`, at `fee_rate` if set otherwise at the wallet's estimated fee rate.
+            ///
+            /// Before Core v0.21 the fee rate is sent as `feeRate` in BTC/kvB.
             pub fn fund_raw_transaction(
                 &self,
                 tx: &bitcoin::Transaction,
+                fee_rate: Option<bitcoin::FeeRate>,
             ) -> Result<FundRawTransaction> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
-                self.call("fundrawtransaction", &[hex.into()])
+                match fee_rate {
+                    Some(rate) => {
+                        let fee_rate_btc_kvb = $crate::client_sync::fee_rate_to_btc_per_kvb(rate);
+                        let options = serde_json::json!({ "feeRate": fee_rate_btc_kvb });
+                        self.call("fundrawtransaction", &[hex.into(), options])
+                    }
+                    None => self.call("fundrawtransaction", &[hex.into()]),
+                }
             }
         }
     };
@@ -168,6 +218,30 @@ macro_rules! impl_client_v17__get_raw_transaction {
             ) -> Result<GetRawTransactionVerbose> {
                 self.call("getrawtransaction", &[into_json(&txid)?, true.into()])
             }
+
+            /// Gets the raw transactions for `txids` using a single batch request.
+            ///
+            /// The results are in the same order as `txids`.
+            pub fn batch_get_raw_transaction(
+                &self,
+                txids: &[Txid],
+            ) -> Result<Vec<Result<GetRawTransaction>>> {
+                let args = txids
+                    .iter()
+                    .map(|txid| Ok(vec![into_json(txid)?, false.into()]))
+                    .collect::<Result<Vec<_>>>()?;
+                let calls = args
+                    .iter()
+                    .map(|args| ("getrawtransaction", args.as_slice()))
+                    .collect::<Vec<_>>();
+
+                let results = self
+                    .batch(&calls)?
+                    .into_iter()
+                    .map(|result| Ok(serde_json::from_value(result?)?))
+                    .collect();
+                Ok(results)
+            }
         }
     };
 }
@@ -209,14 +283,25 @@ macro_rules! impl_client_v17__sign_raw_transaction {
 macro_rules! impl_client_v17__sign_raw_transaction_with_key {
     () => {
         impl Client {
+            /// Signs `tx` with `keys`, using `sighash_type` if set otherwise Core's default `ALL`.
             pub fn sign_raw_transaction_with_key(
                 &self,
                 tx: &bitcoin::Transaction,
                 keys: &[bitcoin::PrivateKey],
+                sighash_type: Option<SighashType>,
             ) -> Result<SignRawTransactionWithKey> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
                 let keys = keys.iter().map(|k| format!("{}", k)).collect::<Vec<String>>();
-                self.call("signrawtransactionwithkey", &[hex.into(), into_json(keys)?])
+                let args = [
+                    hex.into(),
+                    into_json(keys)?,
+                    serde_json::Value::Null, // prevtxs
+                    $crate::client_sync::opt_into_json(sighash_type)?,
+                ];
+                self.call(
+                    "signrawtransactionwithkey",
+                    $crate::client_sync::trim_trailing_nulls(&args),
+                )
             }
         }
     };
@@ -17,7 +17,7 @@ macro_rules! impl_client_v17__create_multisig {
             pub fn create_multisig(
                 &self,
                 nrequired: u32,
-                keys: Vec<PublicKey>,
+                keys: &[PublicKey],
             ) -> Result<CreateMultisig> {
                 self.call("createmultisig", &[nrequired.into(), into_json(keys)?])
             }
@@ -30,8 +30,16 @@ macro_rules! impl_client_v17__create_multisig {
 macro_rules! impl_client_v17__estimate_smart_fee {
     () => {
         impl Client {
-            pub fn estimate_smart_fee(&self, blocks: u32) -> Result<EstimateSmartFee> {
-                self.call("estimatesmartfee", &[blocks.into()])
+            pub fn estimate_smart_fee(
+                &self,
+                conf_target: u32,
+                estimate_mode: Option<EstimateMode>,
+            ) -> Result<EstimateSmartFee> {
+                match estimate_mode {
+                    Some(mode) =>
+                        self.call("estimatesmartfee", &[conf_target.into(), into_json(mode)?]),
+                    None => self.call("estimatesmartfee", &[conf_target.into()]),
+                }
             }
         }
     };
@@ -58,11 +66,9 @@ macro_rules! impl_client_v17__sign_message_with_priv_key {
 macro_rules! impl_client_v17__validate_address {
     () => {
         impl Client {
-            pub fn validate_address(
-                &self,
-                address: &Address<NetworkChecked>,
-            ) -> Result<ValidateAddress> {
-                self.call("validateaddress", &[address.to_string().into()])
+            /// Validates `address`, which may be any string (an invalid address is not an error).
+            pub fn validate_address(&self, address: &str) -> Result<ValidateAddress> {
+                self.call("validateaddress", &[address.into()])
             }
         }
     };
@@ -14,6 +14,10 @@
 macro_rules! impl_client_v17__abandon_transaction {
     () => {
         impl Client {
+            /// Marks an unconfirmed, non-mempool wallet transaction as abandoned.
+            ///
+            /// If `txid` is not a wallet transaction the returned error has code
+            /// `RpcErrorCode::InvalidAddressOrKey`.
             pub fn abandon_transaction(&self, txid: Txid) -> Result<()> {
                 match self.call("abandontransaction", &[into_json(txid)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -44,26 +48,52 @@ macro_rules! impl_client_v17__add_multisig_address {
                 &self,
                 nrequired: u32,
                 keys: Vec<PublicKey>,
+                label: Option<&str>,
+                address_type: Option<AddressType>,
             ) -> Result<AddMultisigAddress> {
-                self.call("addmultisigaddress", &[nrequired.into(), into_json(keys)?])
+                self.add_multisig_address(nrequired, into_json(keys)?, label, address_type)
             }
 
             pub fn add_multisig_address_with_addresses(
                 &self,
                 nrequired: u32,
                 keys: Vec<Address>,
+                label: Option<&str>,
+                address_type: Option<AddressType>,
             ) -> Result<AddMultisigAddress> {
-                self.call("addmultisigaddress", &[nrequired.into(), into_json(keys)?])
+                self.add_multisig_address(nrequired, into_json(keys)?, label, address_type)
+            }
+
+            fn add_multisig_address(
+                &self,
+                nrequired: u32,
+                keys: serde_json::Value,
+                label: Option<&str>,
+                address_type: Option<AddressType>,
+            ) -> Result<AddMultisigAddress> {
+                match (label, address_type) {
+                    (label, Some(ty)) => self.call(
+                        "addmultisigaddress",
+                        &[nrequired.into(), keys, label.unwrap_or("").into(), into_json(ty)?],
+                    ),
+                    (Some(label), None) =>
+                        self.call("addmultisigaddress", &[nrequired.into(), keys, label.into()]),
+                    (None, None) => self.call("addmultisigaddress", &[nrequired.into(), keys]),
+                }
             }
         }
     };
 }
 
-/// Implements Bitcoin Core JSON-RPC API method `bumpfee`.
+/// Implements Bitcoin Core JSON-RPC API method `backupwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__backup_wallet {
     () => {
         impl Client {
+            /// Safely copies the current wallet file to `destination` on the node's filesystem.
+            ///
+            /// If Core cannot write the backup (e.g. the parent directory does not exist or is not
+            /// writable) the returned error has code `RpcErrorCode::WalletError`.
             pub fn backup_wallet(&self, destination: &Path) -> Result<()> {
                 match self.call("backupwallet", &[into_json(destination)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -80,8 +110,34 @@ macro_rules! impl_client_v17__backup_wallet {
 macro_rules! impl_client_v17__bump_fee {
     () => {
         impl Client {
-            pub fn bump_fee(&self, txid: Txid) -> Result<BumpFee> {
-                self.call("bumpfee", &[into_json(txid)?])
+            /// Bumps the fee of wallet transaction `txid`.
+            ///
+            /// Before Core v0.21 the `fee_rate` option is in BTC/kvB, Core v0.17 and v0.18 do not
+            /// support it at all.
+            pub fn bump_fee(
+                &self,
+                txid: Txid,
+                options: Option<&BumpFeeOptions>,
+            ) -> Result<BumpFee> {
+                let options = match options {
+                    Some(options) => options,
+                    None => return self.call("bumpfee", &[into_json(txid)?]),
+                };
+                let mut map = serde_json::Map::new();
+                if let Some(conf_target) = options.conf_target {
+                    map.insert("confTarget".into(), conf_target.into());
+                }
+                if let Some(rate) = options.fee_rate {
+                    let fee_rate_btc_kvb = $crate::client_sync::fee_rate_to_btc_per_kvb(rate);
+                    map.insert("fee_rate".into(), fee_rate_btc_kvb.into());
+                }
+                if let Some(replaceable) = options.replaceable {
+                    map.insert("replaceable".into(), replaceable.into());
+                }
+                if let Some(mode) = options.estimate_mode {
+                    map.insert("estimate_mode".into(), into_json(mode)?);
+                }
+                self.call("bumpfee", &[into_json(txid)?, map.into()])
             }
         }
     };
@@ -129,7 +185,7 @@ macro_rules! impl_client_v17__dump_wallet {
 macro_rules! impl_client_v17__encrypt_wallet {
     () => {
         impl Client {
-            // filename is either absolute or relative to bitcoind.
+            /// Encrypts the wallet with `passphrase`, after which the wallet starts out locked.
             pub fn encrypt_wallet(&self, passphrase: &str) -> Result<EncryptWallet> {
                 self.call("encryptwallet", &[into_json(passphrase)?])
             }
@@ -227,6 +283,14 @@ macro_rules! impl_client_v17__get_raw_change_address {
             pub fn get_raw_change_address(&self) -> Result<GetRawChangeAddress> {
                 self.call("getrawchangeaddress", &[])
             }
+
+            /// Gets a new change address of type `ty` - low level RPC call.
+            pub fn get_raw_change_address_with_type(
+                &self,
+                ty: AddressType,
+            ) -> Result<GetRawChangeAddress> {
+                self.call("getrawchangeaddress", &[into_json(ty)?])
+            }
         }
     };
 }
@@ -239,8 +303,15 @@ macro_rules! impl_client_v17__get_received_by_address {
             pub fn get_received_by_address(
                 &self,
                 address: &Address<NetworkChecked>,
+                minconf: Option<u32>,
             ) -> Result<GetReceivedByAddress> {
-                self.call("getreceivedbyaddress", &[address.to_string().into()])
+                match minconf {
+                    Some(minconf) => self.call(
+                        "getreceivedbyaddress",
+                        &[address.to_string().into(), minconf.into()],
+                    ),
+                    None => self.call("getreceivedbyaddress", &[address.to_string().into()]),
+                }
             }
         }
     };
@@ -252,7 +323,18 @@ macro_rules! impl_client_v17__get_transaction {
     () => {
         impl Client {
             pub fn get_transaction(&self, txid: Txid) -> Result<GetTransaction> {
-                self.call("gettransaction", &[into_json(txid)?])
+                self.get_transaction_with_options(txid, None)
+            }
+
+            /// Gets wallet transaction `txid`, optionally including watch-only details.
+            pub fn get_transaction_with_options(
+                &self,
+                txid: Txid,
+                include_watchonly: Option<bool>,
+            ) -> Result<GetTransaction> {
+                let args =
+                    [into_json(txid)?, $crate::client_sync::opt_into_json(include_watchonly)?];
+                self.call("gettransaction", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -263,6 +345,8 @@ macro_rules! impl_client_v17__get_transaction {
 macro_rules! impl_client_v17__get_unconfirmed_balance {
     () => {
         impl Client {
+            /// Deprecated by Bitcoin Core in favour of `getbalances`, use the `mine.untrusted_pending`
+            /// field of the `getbalances` result instead (Core v0.19 and later).
             pub fn get_unconfirmed_balance(&self) -> Result<GetUnconfirmedBalance> {
                 self.call("getunconfirmedbalance", &[])
             }
@@ -315,8 +399,18 @@ macro_rules! impl_client_v17__import_multi {
 macro_rules! impl_client_v17__import_privkey {
     () => {
         impl Client {
-            pub fn import_privkey(&self, privkey: &bitcoin::PrivateKey) -> Result<()> {
-                match self.call("importprivkey", &[into_json(privkey)?]) {
+            /// Imports `privkey`, sent to Core in WIF, optionally with a `label` and `rescan`.
+            ///
+            /// Core rescans the chain for transactions by default, this may take a long time.
+            pub fn import_privkey(
+                &self,
+                privkey: &bitcoin::PrivateKey,
+                label: Option<&str>,
+                rescan: Option<bool>,
+            ) -> Result<()> {
+                use $crate::client_sync::opt_into_json;
+                let args = [privkey.to_wif().into(), opt_into_json(label)?, opt_into_json(rescan)?];
+                match self.call("importprivkey", $crate::client_sync::trim_trailing_nulls(&args)) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
@@ -383,11 +477,16 @@ macro_rules! impl_client_v17__import_wallet {
 
 /// Implements Bitcoin Core JSON-RPC API method `keypoolrefill`.
 #[macro_export]
-macro_rules! impl_client_v17__key_pool_refill {
+macro_rules! impl_client_v17__keypool_refill {
     () => {
         impl Client {
-            pub fn key_pool_refill(&self) -> Result<()> {
-                match self.call("keypoolrefill", &[]) {
+            /// Fills the keypool, up to `new_size` keys if given otherwise to the `-keypool` size.
+            ///
+            /// Errors if the wallet has private keys disabled.
+            pub fn keypool_refill(&self, new_size: Option<u32>) -> Result<()> {
+                use $crate::client_sync::opt_into_json;
+                let args = [opt_into_json(new_size)?];
+                match self.call("keypoolrefill", $crate::client_sync::trim_trailing_nulls(&args)) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
@@ -414,7 +513,12 @@ macro_rules! impl_client_v17__list_address_groupings {
 macro_rules! impl_client_v17__list_labels {
     () => {
         impl Client {
-            pub fn list_labels(&self) -> Result<ListLabels> { self.call("listlabels", &[]) }
+            /// Lists all labels, or only those assigned to addresses with the given `purpose`.
+            pub fn list_labels(&self, purpose: Option<AddressPurpose>) -> Result<ListLabels> {
+                use $crate::client_sync::opt_into_json;
+                let args = [opt_into_json(purpose)?];
+                self.call("listlabels", $crate::client_sync::trim_trailing_nulls(&args))
+            }
         }
     };
 }
@@ -448,8 +552,12 @@ macro_rules! impl_client_v17__list_received_by_address {
 macro_rules! impl_client_v17__list_since_block {
     () => {
         impl Client {
-            pub fn list_since_block(&self) -> Result<ListSinceBlock> {
-                self.call("listsinceblock", &[])
+            /// If `blockhash` is `None` all transactions are returned.
+            pub fn list_since_block(&self, blockhash: Option<BlockHash>) -> Result<ListSinceBlock> {
+                match blockhash {
+                    Some(hash) => self.call("listsinceblock", &[into_json(hash)?]),
+                    None => self.call("listsinceblock", &[]),
+                }
             }
         }
     };
@@ -460,8 +568,24 @@ macro_rules! impl_client_v17__list_since_block {
 macro_rules! impl_client_v17__list_transactions {
     () => {
         impl Client {
-            pub fn list_transactions(&self) -> Result<ListTransactions> {
-                self.call("listtransactions", &[])
+            /// Arguments that are `None` use the Core defaults.
+            pub fn list_transactions(
+                &self,
+                label: Option<&str>,
+                count: Option<u32>,
+                skip: Option<u32>,
+                include_watchonly: Option<bool>,
+            ) -> Result<ListTransactions> {
+                use $crate::client_sync::opt_into_json;
+
+                let args = [
+                    opt_into_json(label)?,
+                    opt_into_json(count)?,
+                    opt_into_json(skip)?,
+                    opt_into_json(include_watchonly)?,
+                ];
+                // Core treats `null` as the default, trailing ones can be left off.
+                self.call("listtransactions", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -472,7 +596,27 @@ macro_rules! impl_client_v17__list_transactions {
 macro_rules! impl_client_v17__list_unspent {
     () => {
         impl Client {
-            pub fn list_unspent(&self) -> Result<ListUnspent> { self.call("listunspent", &[]) }
+            /// Arguments that are `None` use the Core defaults.
+            pub fn list_unspent(
+                &self,
+                minconf: Option<u32>,
+                maxconf: Option<u32>,
+                addresses: Option<&[Address]>,
+                include_unsafe: Option<bool>,
+                query_options: Option<ListUnspentQueryOptions>,
+            ) -> Result<ListUnspent> {
+                use $crate::client_sync::opt_into_json;
+
+                let args = [
+                    opt_into_json(minconf)?,
+                    opt_into_json(maxconf)?,
+                    opt_into_json(addresses)?,
+                    opt_into_json(include_unsafe)?,
+                    opt_into_json(query_options)?,
+                ];
+                // Core treats `null` as the default, trailing ones can be left off.
+                self.call("listunspent", $crate::client_sync::trim_trailing_nulls(&args))
+            }
         }
     };
 }
@@ -504,26 +648,20 @@ macro_rules! impl_client_v17__load_wallet {
 macro_rules! impl_client_v17__lock_unspent {
     () => {
         impl Client {
-            /// Lock the given list of transaction outputs. Returns true on success.
-            ///
-            /// This wraps Core RPC: `lockunspent false [{"txid":"..","vout":n},...]`.
-            pub fn lock_unspent(&self, outputs: &[(Txid, u32)]) -> Result<LockUnspent> {
-                let outs: Vec<_> = outputs
-                    .iter()
-                    .map(|(txid, vout)| serde_json::json!({"txid": txid, "vout": vout}))
-                    .collect();
-                self.call("lockunspent", &[into_json(false)?, outs.into()])
-            }
-
-            /// Unlock the given list of transaction outputs. Returns true on success.
+            /// Locks (`unlock` is false) or unlocks (`unlock` is true) the given transaction outputs.
             ///
-            /// This wraps Core RPC: `lockunspent true [{"txid":"..","vout":n},...]`.
-            pub fn unlock_unspent(&self, outputs: &[(Txid, u32)]) -> Result<LockUnspent> {
+            /// Locked outputs are not selected when the wallet funds transactions. Returns true on
+            /// success.
+            pub fn lock_unspent(
+                &self,
+                unlock: bool,
+                outputs: &[bitcoin::OutPoint],
+            ) -> Result<LockUnspent> {
                 let outs: Vec<_> = outputs
                     .iter()
-                    .map(|(txid, vout)| serde_json::json!({"txid": txid, "vout": vout}))
+                    .map(|out| serde_json::json!({"txid": out.txid, "vout": out.vout}))
                     .collect();
-                self.call("lockunspent", &[into_json(true)?, outs.into()])
+                self.call("lockunspent", &[into_json(unlock)?, outs.into()])
             }
         }
     };
@@ -546,8 +684,19 @@ macro_rules! impl_client_v17__remove_pruned_funds {
 macro_rules! impl_client_v17__rescan_blockchain {
     () => {
         impl Client {
-            pub fn rescan_blockchain(&self) -> Result<RescanBlockchain> {
-                self.call("rescanblockchain", &[])
+            /// Rescans the chain from `start_height` (default genesis) to `stop_height` (default tip).
+            ///
+            /// The call blocks until the rescan completes, the result holds the range actually scanned.
+            pub fn rescan_blockchain(
+                &self,
+                start_height: Option<u32>,
+                stop_height: Option<u32>,
+            ) -> Result<RescanBlockchain> {
+                let args = [
+                    $crate::client_sync::opt_into_json(start_height)?,
+                    $crate::client_sync::opt_into_json(stop_height)?,
+                ];
+                self.call("rescanblockchain", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -558,13 +707,42 @@ macro_rules! impl_client_v17__rescan_blockchain {
 macro_rules! impl_client_v17__send_many {
     () => {
         impl Client {
-            pub fn send_many(&self, amounts: BTreeMap<Address, Amount>) -> Result<SendMany> {
+            /// Sends to multiple addresses in a single transaction.
+            ///
+            /// Amounts are sent as BTC decimal strings so no precision is lost. Each address in
+            /// `subtract_fee_from` must also be a key in `amounts`.
+            pub fn send_many(
+                &self,
+                amounts: BTreeMap<Address, Amount>,
+                comment: Option<&str>,
+                subtract_fee_from: &[Address],
+                replaceable: Option<bool>,
+                conf_target: Option<u32>,
+                estimate_mode: Option<EstimateMode>,
+            ) -> Result<SendMany> {
                 let dummy = ""; // Must be set to "" for backwards compatibility.
-                let amount_btc: BTreeMap<String, f64> = amounts
+                let amount_btc: BTreeMap<String, String> = amounts
                     .into_iter()
-                    .map(|(addr, amount)| (addr.to_string(), amount.to_btc()))
+                    .map(|(addr, amount)| {
+                        (addr.to_string(), amount.to_string_in(bitcoin::Denomination::Bitcoin))
+                    })
                     .collect();
-                self.call("sendmany", &[into_json(dummy)?, into_json(amount_btc)?])
+                let subtract_fee_from = if subtract_fee_from.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    into_json(subtract_fee_from.iter().map(|a| a.to_string()).collect::<Vec<_>>())?
+                };
+                let args = [
+                    into_json(dummy)?,
+                    into_json(amount_btc)?,
+                    serde_json::Value::Null, // minconf
+                    $crate::client_sync::opt_into_json(comment)?,
+                    subtract_fee_from,
+                    $crate::client_sync::opt_into_json(replaceable)?,
+                    $crate::client_sync::opt_into_json(conf_target)?,
+                    $crate::client_sync::opt_into_json(estimate_mode)?,
+                ];
+                self.call("sendmany", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -581,7 +759,10 @@ macro_rules! impl_client_v17__send_to_address {
                 address: &Address<NetworkChecked>,
                 amount: Amount,
             ) -> Result<SendToAddress> {
-                let args = [address.to_string().into(), into_json(amount.to_btc())?];
+                let args = [
+                    address.to_string().into(),
+                    amount.to_string_in(bitcoin::Denomination::Bitcoin).into(),
+                ];
                 self.call("sendtoaddress", &args)
             }
 
@@ -598,7 +779,7 @@ macro_rules! impl_client_v17__send_to_address {
 
                 let args = [
                     address.to_string().into(),
-                    into_json(amount.to_btc())?,
+                    amount.to_string_in(bitcoin::Denomination::Bitcoin).into(),
                     comment.into(),
                     comment_to.into(),
                     subtract_fee_from_amount.into(),
@@ -606,6 +787,29 @@ macro_rules! impl_client_v17__send_to_address {
                 ];
                 self.call("sendtoaddress", &args)
             }
+
+            // Send to address - with a fee estimated for `conf_target` blocks using `estimate_mode`.
+            pub fn send_to_address_with_conf_target(
+                &self,
+                address: &Address<NetworkChecked>,
+                amount: Amount,
+                conf_target: Option<u32>,
+                estimate_mode: Option<EstimateMode>,
+            ) -> Result<SendToAddress> {
+                use $crate::client_sync::opt_into_json;
+                // Core defaults for comment, comment_to, subtract_fee_from_amount and replaceable.
+                let args = [
+                    address.to_string().into(),
+                    amount.to_string_in(bitcoin::Denomination::Bitcoin).into(),
+                    serde_json::Value::Null,
+                    serde_json::Value::Null,
+                    serde_json::Value::Null,
+                    serde_json::Value::Null,
+                    opt_into_json(conf_target)?,
+                    opt_into_json(estimate_mode)?,
+                ];
+                self.call("sendtoaddress", $crate::client_sync::trim_trailing_nulls(&args))
+            }
         }
     };
 }
@@ -615,13 +819,34 @@ macro_rules! impl_client_v17__send_to_address {
 macro_rules! impl_client_v17__set_hd_seed {
     () => {
         impl Client {
-            pub fn set_hd_seed(&self) -> Result<()> {
-                match self.call("sethdseed", &[]) {
+            pub fn set_hd_seed(
+                &self,
+                new_keypool: Option<bool>,
+                seed: Option<&bitcoin::PrivateKey>,
+            ) -> Result<()> {
+                let args = [
+                    $crate::client_sync::opt_into_json(new_keypool)?,
+                    $crate::client_sync::opt_into_json(seed.map(|key| key.to_wif()))?,
+                ];
+                match self.call("sethdseed", $crate::client_sync::trim_trailing_nulls(&args)) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
                 }
             }
+
+            /// Calls `sethdseed` then `getwalletinfo` and returns the `hdseedid` of the new seed.
+            ///
+            /// `sethdseed` returns nothing, use this to verify the seed was actually set.
+            pub fn set_hd_seed_and_get_id(
+                &self,
+                new_keypool: Option<bool>,
+                seed: Option<&bitcoin::PrivateKey>,
+            ) -> Result<Option<bitcoin::hashes::hash160::Hash>> {
+                self.set_hd_seed(new_keypool, seed)?;
+                let info = self.get_wallet_info()?;
+                Ok(info.hd_seed_id.map(|id| id.parse()).transpose()?)
+            }
         }
     };
 }
@@ -631,8 +856,16 @@ macro_rules! impl_client_v17__set_hd_seed {
 macro_rules! impl_client_v17__set_tx_fee {
     () => {
         impl Client {
-            pub fn set_tx_fee(&self, fee_rate: bitcoin::FeeRate) -> Result<SetTxFee> {
-                let fee_rate_btc_kvb = fee_rate.to_sat_per_vb_floor() as f64 / 100_000.0;
+            /// Sets the wallet's transaction fee rate, returns true if successful.
+            ///
+            /// Returns [`Error::InvalidArgument`] without calling Core if `fee_rate` is zero.
+            pub fn set_tx_fee(&self, fee_rate: bitcoin::FeeRate) -> Result<bool> {
+                if fee_rate == bitcoin::FeeRate::ZERO {
+                    return Err(Error::InvalidArgument(
+                        "settxfee requires a non-zero fee rate".into(),
+                    ));
+                }
+                let fee_rate_btc_kvb = $crate::client_sync::fee_rate_to_btc_per_kvb(fee_rate);
                 self.call("settxfee", &[fee_rate_btc_kvb.into()])
             }
         }
@@ -656,13 +889,27 @@ macro_rules! impl_client_v17__sign_message {
 macro_rules! impl_client_v17__sign_raw_transaction_with_wallet {
     () => {
         impl Client {
-            // `hexstring`: The transaction hex string.
+            /// Signs `tx` with the wallet's keys.
+            ///
+            /// `prev_txs` describes outputs spent by `tx` that the node does not know about yet.
             pub fn sign_raw_transaction_with_wallet(
                 &self,
                 tx: &bitcoin::Transaction,
+                prev_txs: &[PrevTx],
+                sighash_type: Option<SighashType>,
             ) -> Result<SignRawTransactionWithWallet> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
-                self.call("signrawtransactionwithwallet", &[into_json(hex)?])
+                let prev_txs = if prev_txs.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    into_json(prev_txs)?
+                };
+                let args =
+                    [into_json(hex)?, prev_txs, $crate::client_sync::opt_into_json(sighash_type)?];
+                self.call(
+                    "signrawtransactionwithwallet",
+                    $crate::client_sync::trim_trailing_nulls(&args),
+                )
             }
         }
     };
@@ -689,7 +936,13 @@ macro_rules! impl_client_v17__unload_wallet {
 macro_rules! impl_client_v17__wallet_passphrase {
     () => {
         impl Client {
-            pub fn wallet_passphrase(&self, passphrase: &str, timeout: u64) -> Result<()> {
+            /// Unlocks the wallet for `timeout`, sent to Core in whole seconds rounded up.
+            pub fn wallet_passphrase(
+                &self,
+                passphrase: &str,
+                timeout: std::time::Duration,
+            ) -> Result<()> {
+                let timeout = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
                 match self.call("walletpassphrase", &[passphrase.into(), timeout.into()]) {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
@@ -705,21 +958,31 @@ macro_rules! impl_client_v17__wallet_passphrase {
 macro_rules! impl_client_v17__wallet_create_funded_psbt {
     () => {
         impl Client {
+            /// Creates and funds a PSBT, `inputs` may be empty to let the wallet select them.
             pub fn wallet_create_funded_psbt(
                 &self,
                 inputs: Vec<WalletCreateFundedPsbtInput>,
                 outputs: Vec<BTreeMap<Address, Amount>>,
+                options: Option<&WalletCreateFundedPsbtOptions>,
             ) -> Result<WalletCreateFundedPsbt> {
-                // Convert outputs: Vec<BTreeMap<Address, Amount>> to Vec<BTreeMap<String, f64>>
+                // Amounts are sent as BTC decimal strings so no precision is lost.
                 let outputs_json: Vec<_> = outputs
                     .into_iter()
                     .map(|map| {
                         map.into_iter()
-                            .map(|(addr, amt)| (addr.to_string(), amt.to_btc()))
+                            .map(|(addr, amt)| {
+                                (addr.to_string(), amt.to_string_in(bitcoin::Denomination::Bitcoin))
+                            })
                             .collect::<BTreeMap<_, _>>()
                     })
                     .collect();
-                self.call("walletcreatefundedpsbt", &[into_json(inputs)?, into_json(outputs_json)?])
+                let args = [
+                    into_json(inputs)?,
+                    into_json(outputs_json)?,
+                    serde_json::Value::Null, // locktime
+                    $crate::client_sync::opt_into_json(options)?,
+                ];
+                self.call("walletcreatefundedpsbt", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -730,6 +993,7 @@ macro_rules! impl_client_v17__wallet_create_funded_psbt {
 macro_rules! impl_client_v17__wallet_lock {
     () => {
         impl Client {
+            /// Removes the wallet encryption key from memory, locking the wallet.
             pub fn wallet_lock(&self) -> Result<()> {
                 match self.call("walletlock", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -768,12 +1032,20 @@ macro_rules! impl_client_v17__wallet_passphrase_change {
 macro_rules! impl_client_v17__wallet_process_psbt {
     () => {
         impl Client {
-            pub fn wallet_process_psbt(&self, psbt: &bitcoin::Psbt) -> Result<WalletProcessPsbt> {
+            /// If `sign` is `None` Core defaults to signing the PSBT.
+            pub fn wallet_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                sign: Option<bool>,
+            ) -> Result<WalletProcessPsbt> {
                 // Core expects the PSBT as a base64 string argument (same representation
                 // used by `finalizepsbt`). Serializing the struct with `into_json` produced
                 // an object which Core rejected ("Expected type string, got object").
                 let psbt = format!("{}", psbt);
-                self.call("walletprocesspsbt", &[psbt.into()])
+                match sign {
+                    Some(sign) => self.call("walletprocesspsbt", &[psbt.into(), sign.into()]),
+                    None => self.call("walletprocesspsbt", &[psbt.into()]),
+                }
             }
         }
     };
@@ -23,8 +23,8 @@ use crate::types::v18::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
-        TemplateRules, WalletCreateFundedPsbtInput,
+        AddNodeCommand, AddressType, BumpFeeOptions, CreateRawTransactionBuilder, EstimateMode, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, ListUnspentQueryOptions, Input, Output, PrevTx, SetBanCommand, SighashType, TemplateRequest,
+        TemplateRules, WalletCreateFundedPsbtInput, WalletCreateFundedPsbtOptions,
     },
 };
 
@@ -148,7 +148,7 @@ crate::impl_client_v17__import_privkey!();
 crate::impl_client_v17__import_pruned_funds!();
 crate::impl_client_v17__import_pubkey!();
 crate::impl_client_v17__import_wallet!();
-crate::impl_client_v17__key_pool_refill!();
+crate::impl_client_v17__keypool_refill!();
 crate::impl_client_v17__list_address_groupings!();
 crate::impl_client_v17__list_labels!();
 crate::impl_client_v17__list_lock_unspent!();
@@ -166,6 +166,7 @@ crate::impl_client_v17__rescan_blockchain!();
 crate::impl_client_v17__send_many!();
 crate::impl_client_v17__send_to_address!();
 crate::impl_client_v17__set_hd_seed!();
+crate::impl_client_v18__set_label!();
 crate::impl_client_v17__set_tx_fee!();
 crate::impl_client_v17__sign_message!();
 crate::impl_client_v17__sign_raw_transaction_with_wallet!();
@@ -14,8 +14,17 @@
 macro_rules! impl_client_v18__derive_addresses {
     () => {
         impl Client {
-            pub fn derive_addresses(&self, descriptor: &str) -> Result<DeriveAddresses> {
-                self.call("deriveaddresses", &[descriptor.into()])
+            /// Derives addresses from `descriptor`, `range` is required for ranged descriptors.
+            pub fn derive_addresses(
+                &self,
+                descriptor: &str,
+                range: Option<(u64, u64)>,
+            ) -> Result<DeriveAddresses> {
+                match range {
+                    Some((begin, end)) => self
+                        .call("deriveaddresses", &[descriptor.into(), into_json([begin, end])?]),
+                    None => self.call("deriveaddresses", &[descriptor.into()]),
+                }
             }
         }
     };
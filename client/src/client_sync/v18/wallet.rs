@@ -13,8 +13,16 @@
 macro_rules! impl_client_v18__get_received_by_label {
     () => {
         impl Client {
-            pub fn get_received_by_label(&self, label: &str) -> Result<GetReceivedByLabel> {
-                self.call("getreceivedbylabel", &[label.into()])
+            pub fn get_received_by_label(
+                &self,
+                label: &str,
+                minconf: Option<u32>,
+            ) -> Result<GetReceivedByLabel> {
+                match minconf {
+                    Some(minconf) =>
+                        self.call("getreceivedbylabel", &[label.into(), minconf.into()]),
+                    None => self.call("getreceivedbylabel", &[label.into()]),
+                }
             }
         }
     };
@@ -43,3 +51,23 @@ macro_rules! impl_client_v18__list_wallet_dir {
         }
     };
 }
+
+/// Implements Bitcoin Core JSON-RPC API method `setlabel`.
+#[macro_export]
+macro_rules! impl_client_v18__set_label {
+    () => {
+        impl Client {
+            /// Sets the label associated with `address`.
+            ///
+            /// Addresses not belonging to the wallet are added to the address book with purpose
+            /// `send`.
+            pub fn set_label(&self, address: &Address, label: &str) -> Result<()> {
+                match self.call("setlabel", &[into_json(address)?, label.into()]) {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
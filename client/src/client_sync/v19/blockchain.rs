@@ -20,3 +20,23 @@ macro_rules! impl_client_v19__get_block_filter {
         }
     };
 }
+
+/// Implements Bitcoin Core JSON-RPC API method `scantxoutset`.
+///
+/// Requires the `experimental-rpc` feature. Only the result of `action = "start"` deserializes into
+/// `ScanTxOutSet`, `"abort"` and `"status"` return different data.
+#[cfg(feature = "experimental-rpc")]
+#[macro_export]
+macro_rules! impl_client_v19__scan_tx_out_set {
+    () => {
+        impl Client {
+            pub fn scan_tx_out_set(
+                &self,
+                action: &str,
+                scanobjects: &[String],
+            ) -> Result<ScanTxOutSet> {
+                self.call("scantxoutset", &[action.into(), into_json(scanobjects)?])
+            }
+        }
+    };
+}
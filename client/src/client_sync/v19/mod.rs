@@ -5,6 +5,7 @@
 //! We ignore option arguments unless they effect the shape of the returned JSON data.
 
 pub mod blockchain;
+pub mod raw_transactions;
 pub mod wallet;
 
 use std::collections::BTreeMap;
@@ -19,8 +20,8 @@ use crate::types::v19::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
-        TemplateRules, WalletCreateFundedPsbtInput,
+        AddNodeCommand, AddressType, BumpFeeOptions, CreateRawTransactionBuilder, CreateWalletOptions, EstimateMode, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, ListUnspentQueryOptions, Input, Output, PrevTx, SetBanCommand, SighashType, TemplateRequest,
+        TemplateRules, WalletCreateFundedPsbtInput, WalletCreateFundedPsbtOptions,
     },
 };
 
@@ -50,6 +51,8 @@ crate::impl_client_v17__get_tx_out_set_info!();
 crate::impl_client_v17__precious_block!();
 crate::impl_client_v17__prune_blockchain!();
 crate::impl_client_v17__save_mempool!();
+#[cfg(feature = "experimental-rpc")]
+crate::impl_client_v19__scan_tx_out_set!();
 crate::impl_client_v17__verify_chain!();
 crate::impl_client_v17__verify_tx_out_proof!();
 
@@ -105,8 +108,8 @@ crate::impl_client_v18__join_psbts!();
 crate::impl_client_v17__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Util ==
 crate::impl_client_v17__create_multisig!();
@@ -124,6 +127,7 @@ crate::impl_client_v17__add_multisig_address!();
 crate::impl_client_v17__backup_wallet!();
 crate::impl_client_v17__bump_fee!();
 crate::impl_client_v17__create_wallet!();
+crate::impl_client_v19__create_wallet_with_options!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
@@ -135,7 +139,7 @@ crate::impl_client_v18__get_received_by_label!();
 crate::impl_client_v17__get_new_address!();
 crate::impl_client_v17__get_raw_change_address!();
 crate::impl_client_v17__get_received_by_address!();
-crate::impl_client_v17__get_transaction!();
+crate::impl_client_v19__get_transaction!();
 crate::impl_client_v17__get_unconfirmed_balance!();
 crate::impl_client_v17__get_wallet_info!();
 crate::impl_client_v17__import_address!();
@@ -144,7 +148,7 @@ crate::impl_client_v17__import_privkey!();
 crate::impl_client_v17__import_pruned_funds!();
 crate::impl_client_v17__import_pubkey!();
 crate::impl_client_v17__import_wallet!();
-crate::impl_client_v17__key_pool_refill!();
+crate::impl_client_v17__keypool_refill!();
 crate::impl_client_v17__list_address_groupings!();
 crate::impl_client_v18__list_received_by_label!();
 crate::impl_client_v17__list_labels!();
@@ -162,6 +166,7 @@ crate::impl_client_v17__rescan_blockchain!();
 crate::impl_client_v17__send_many!();
 crate::impl_client_v17__send_to_address!();
 crate::impl_client_v17__set_hd_seed!();
+crate::impl_client_v18__set_label!();
 crate::impl_client_v17__set_tx_fee!();
 crate::impl_client_v19__set_wallet_flag!();
 crate::impl_client_v17__sign_message!();
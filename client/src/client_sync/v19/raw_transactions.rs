@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v0.19`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements Bitcoin Core JSON-RPC API method `testmempoolaccept`.
+///
+/// Prior to v0.19 the second argument was `allowhighfees` instead of `maxfeerate`.
+#[macro_export]
+macro_rules! impl_client_v19__test_mempool_accept {
+    () => {
+        impl Client {
+            pub fn test_mempool_accept(
+                &self,
+                txs: &[bitcoin::Transaction],
+                max_fee_rate: Option<bitcoin::FeeRate>,
+            ) -> Result<TestMempoolAccept> {
+                let encoded = txs
+                    .iter()
+                    .map(|tx| bitcoin::consensus::encode::serialize_hex(tx))
+                    .collect::<Vec<String>>();
+                match max_fee_rate {
+                    Some(rate) => {
+                        let max_fee_rate_btc_kvb =
+                            $crate::client_sync::fee_rate_to_btc_per_kvb(rate);
+                        self.call(
+                            "testmempoolaccept",
+                            &[into_json(encoded)?, max_fee_rate_btc_kvb.into()],
+                        )
+                    }
+                    None => self.call("testmempoolaccept", &[into_json(encoded)?]),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `utxoupdatepsbt`.
+#[macro_export]
+macro_rules! impl_client_v19__utxo_update_psbt {
+    () => {
+        impl Client {
+            pub fn utxo_update_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                descriptors: Option<&[String]>,
+            ) -> Result<UtxoUpdatePsbt> {
+                let psbt = format!("{}", psbt);
+                match descriptors {
+                    Some(descriptors) =>
+                        self.call("utxoupdatepsbt", &[psbt.into(), into_json(descriptors)?]),
+                    None => self.call("utxoupdatepsbt", &[psbt.into()]),
+                }
+            }
+        }
+    };
+}
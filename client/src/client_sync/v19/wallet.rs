@@ -9,6 +9,33 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements Bitcoin Core JSON-RPC API method `createwallet` with its optional arguments.
+#[macro_export]
+macro_rules! impl_client_v19__create_wallet_with_options {
+    () => {
+        impl Client {
+            /// Creates and loads a new wallet, unset `options` use Core's defaults.
+            pub fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                use $crate::client_sync::opt_into_json;
+                let args = [
+                    wallet.into(),
+                    opt_into_json(options.disable_private_keys)?,
+                    opt_into_json(options.blank)?,
+                    opt_into_json(options.passphrase.as_deref())?,
+                    opt_into_json(options.avoid_reuse)?,
+                    opt_into_json(options.descriptors)?,
+                    opt_into_json(options.load_on_startup)?,
+                ];
+                self.call("createwallet", $crate::client_sync::trim_trailing_nulls(&args))
+            }
+        }
+    };
+}
+
 /// Implements Bitcoin Core JSON-RPC API method `getbalances`.
 #[macro_export]
 macro_rules! impl_client_v19__get_balances {
@@ -19,13 +46,52 @@ macro_rules! impl_client_v19__get_balances {
     };
 }
 
+/// Implements Bitcoin Core JSON-RPC API method `gettransaction`.
+///
+/// The `verbose` argument was added in v0.19.
+#[macro_export]
+macro_rules! impl_client_v19__get_transaction {
+    () => {
+        impl Client {
+            pub fn get_transaction(&self, txid: Txid) -> Result<GetTransaction> {
+                self.get_transaction_with_options(txid, None, None)
+            }
+
+            /// Gets wallet transaction `txid`, `verbose` adds the `decoded` field to the result.
+            pub fn get_transaction_with_options(
+                &self,
+                txid: Txid,
+                include_watchonly: Option<bool>,
+                verbose: Option<bool>,
+            ) -> Result<GetTransaction> {
+                let args = [
+                    into_json(txid)?,
+                    $crate::client_sync::opt_into_json(include_watchonly)?,
+                    $crate::client_sync::opt_into_json(verbose)?,
+                ];
+                self.call("gettransaction", $crate::client_sync::trim_trailing_nulls(&args))
+            }
+        }
+    };
+}
+
 /// Implements Bitcoin Core JSON-RPC API method `setwalletflag`.
+///
+/// The only flag currently documented by Core is `avoid_reuse`. If `value` is `None` the flag is
+/// set to `true`.
 #[macro_export]
 macro_rules! impl_client_v19__set_wallet_flag {
     () => {
         impl Client {
-            pub fn set_wallet_flag(&self, flag: &str) -> Result<SetWalletFlag> {
-                self.call("setwalletflag", &[into_json(flag)?])
+            pub fn set_wallet_flag(
+                &self,
+                flag: &str,
+                value: Option<bool>,
+            ) -> Result<SetWalletFlag> {
+                match value {
+                    Some(value) => self.call("setwalletflag", &[into_json(flag)?, value.into()]),
+                    None => self.call("setwalletflag", &[into_json(flag)?]),
+                }
             }
         }
     };
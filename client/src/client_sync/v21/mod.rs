@@ -6,6 +6,7 @@
 
 mod generating;
 mod hidden;
+mod raw_transactions;
 mod util;
 mod wallet;
 
@@ -22,8 +23,8 @@ use crate::types::v21::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
-        TemplateRules, WalletCreateFundedPsbtInput,
+        AddNodeCommand, AddressType, BumpFeeOptions, CreateRawTransactionBuilder, CreateWalletOptions, EstimateMode, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, ListUnspentQueryOptions, Input, Output, PrevTx, SetBanCommand, SighashType, TemplateRequest,
+        TemplateRules, WalletCreateFundedPsbtInput, WalletCreateFundedPsbtOptions,
     },
 };
 
@@ -107,14 +108,14 @@ crate::impl_client_v17__decode_psbt!();
 crate::impl_client_v17__decode_raw_transaction!();
 crate::impl_client_v17__decode_script!();
 crate::impl_client_v17__finalize_psbt!();
-crate::impl_client_v17__fund_raw_transaction!();
+crate::impl_client_v21__fund_raw_transaction!();
 crate::impl_client_v17__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
 crate::impl_client_v17__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Util ==
 crate::impl_client_v17__create_multisig!();
@@ -131,8 +132,9 @@ crate::impl_client_v17__abandon_transaction!();
 crate::impl_client_v17__abort_rescan!();
 crate::impl_client_v17__add_multisig_address!();
 crate::impl_client_v17__backup_wallet!();
-crate::impl_client_v17__bump_fee!();
+crate::impl_client_v21__bump_fee!();
 crate::impl_client_v21__create_wallet!();
+crate::impl_client_v19__create_wallet_with_options!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
@@ -144,7 +146,7 @@ crate::impl_client_v19__get_balances!();
 crate::impl_client_v17__get_new_address!();
 crate::impl_client_v17__get_raw_change_address!();
 crate::impl_client_v17__get_received_by_address!();
-crate::impl_client_v17__get_transaction!();
+crate::impl_client_v19__get_transaction!();
 crate::impl_client_v17__get_unconfirmed_balance!();
 crate::impl_client_v17__get_wallet_info!();
 crate::impl_client_v17__import_address!();
@@ -154,7 +156,7 @@ crate::impl_client_v17__import_privkey!();
 crate::impl_client_v17__import_pruned_funds!();
 crate::impl_client_v17__import_pubkey!();
 crate::impl_client_v17__import_wallet!();
-crate::impl_client_v17__key_pool_refill!();
+crate::impl_client_v17__keypool_refill!();
 crate::impl_client_v17__list_address_groupings!();
 crate::impl_client_v17__list_labels!();
 crate::impl_client_v18__list_received_by_label!();
@@ -165,7 +167,7 @@ crate::impl_client_v17__list_transactions!();
 crate::impl_client_v17__list_unspent!();
 crate::impl_client_v18__list_wallet_dir!();
 crate::impl_client_v17__list_wallets!();
-crate::impl_client_v17__load_wallet!();
+crate::impl_client_v21__load_wallet!();
 crate::impl_client_v17__lock_unspent!();
 crate::impl_client_v21__psbt_bump_fee!();
 crate::impl_client_v17__remove_pruned_funds!();
@@ -175,6 +177,7 @@ crate::impl_client_v17__send_many!();
 crate::impl_client_v21__send_many_verbose!();
 crate::impl_client_v17__send_to_address!();
 crate::impl_client_v17__set_hd_seed!();
+crate::impl_client_v18__set_label!();
 crate::impl_client_v17__set_tx_fee!();
 crate::impl_client_v19__set_wallet_flag!();
 crate::impl_client_v17__sign_message!();
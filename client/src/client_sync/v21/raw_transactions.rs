@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v0.21`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements Bitcoin Core JSON-RPC API method `fundrawtransaction`.
+///
+/// From v0.21 the fee rate can be sent as `fee_rate` in sat/vB.
+#[macro_export]
+macro_rules! impl_client_v21__fund_raw_transaction {
+    () => {
+        impl Client {
+            /// Funds `tx`, at `fee_rate` if set otherwise at the wallet's estimated fee rate.
+            pub fn fund_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+                fee_rate: Option<bitcoin::FeeRate>,
+            ) -> Result<FundRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                match fee_rate {
+                    Some(rate) => {
+                        let fee_rate_sat_vb = rate.to_sat_per_kwu() as f64 / 250.0;
+                        let options = serde_json::json!({ "fee_rate": fee_rate_sat_vb });
+                        self.call("fundrawtransaction", &[hex.into(), options])
+                    }
+                    None => self.call("fundrawtransaction", &[hex.into()]),
+                }
+            }
+        }
+    };
+}
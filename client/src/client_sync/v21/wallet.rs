@@ -9,6 +9,43 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements Bitcoin Core JSON-RPC API method `bumpfee`.
+///
+/// From v0.21 the `fee_rate` option is in sat/vB and `confTarget` is renamed `conf_target`.
+#[macro_export]
+macro_rules! impl_client_v21__bump_fee {
+    () => {
+        impl Client {
+            /// Bumps the fee of wallet transaction `txid`.
+            pub fn bump_fee(
+                &self,
+                txid: Txid,
+                options: Option<&BumpFeeOptions>,
+            ) -> Result<BumpFee> {
+                let options = match options {
+                    Some(options) => options,
+                    None => return self.call("bumpfee", &[into_json(txid)?]),
+                };
+                let mut map = serde_json::Map::new();
+                if let Some(conf_target) = options.conf_target {
+                    map.insert("conf_target".into(), conf_target.into());
+                }
+                if let Some(rate) = options.fee_rate {
+                    let fee_rate_sat_vb = rate.to_sat_per_kwu() as f64 / 250.0;
+                    map.insert("fee_rate".into(), fee_rate_sat_vb.into());
+                }
+                if let Some(replaceable) = options.replaceable {
+                    map.insert("replaceable".into(), replaceable.into());
+                }
+                if let Some(mode) = options.estimate_mode {
+                    map.insert("estimate_mode".into(), into_json(mode)?);
+                }
+                self.call("bumpfee", &[into_json(txid)?, map.into()])
+            }
+        }
+    };
+}
+
 /// Implements Bitcoin Core JSON-RPC API method `createwallet`.
 #[macro_export]
 macro_rules! impl_client_v21__create_wallet {
@@ -74,6 +111,26 @@ macro_rules! impl_client_v21__import_descriptors {
     };
 }
 
+/// Implements Bitcoin Core JSON-RPC API method `loadwallet`.
+#[macro_export]
+macro_rules! impl_client_v21__load_wallet {
+    () => {
+        impl Client {
+            /// Loads `wallet`, optionally adding it to (or removing it from) the list of wallets
+            /// loaded on node startup.
+            pub fn load_wallet(
+                &self,
+                wallet: &str,
+                load_on_startup: Option<bool>,
+            ) -> Result<LoadWallet> {
+                use $crate::client_sync::opt_into_json;
+                let args = [wallet.into(), opt_into_json(load_on_startup)?];
+                self.call("loadwallet", $crate::client_sync::trim_trailing_nulls(&args))
+            }
+        }
+    };
+}
+
 /// Implements Bitcoin Core JSON-RPC API method `psbtbumpfee`.
 #[macro_export]
 macro_rules! impl_client_v21__psbt_bump_fee {
@@ -91,7 +148,14 @@ macro_rules! impl_client_v21__psbt_bump_fee {
 macro_rules! impl_client_v21__send {
     () => {
         impl Client {
-            pub fn send(&self, outputs: &BTreeMap<String, f64>) -> Result<Send> {
+            /// Sends to `outputs`, amounts are sent as BTC decimal strings so no precision is lost.
+            pub fn send(&self, outputs: &BTreeMap<Address, Amount>) -> Result<Send> {
+                let outputs: BTreeMap<String, String> = outputs
+                    .iter()
+                    .map(|(addr, amount)| {
+                        (addr.to_string(), amount.to_string_in(bitcoin::Denomination::Bitcoin))
+                    })
+                    .collect();
                 self.call("send", &[into_json(outputs)?])
             }
         }
@@ -108,16 +172,18 @@ macro_rules! impl_client_v21__send_many_verbose {
                 amounts: BTreeMap<Address, Amount>,
             ) -> Result<SendManyVerbose> {
                 let dummy = ""; // Backwards compatibility dummy.
-                let amount_btc: BTreeMap<String, f64> = amounts
+                let amount_btc: BTreeMap<String, String> = amounts
                     .into_iter()
-                    .map(|(addr, amount)| (addr.to_string(), amount.to_btc()))
+                    .map(|(addr, amount)| {
+                        (addr.to_string(), amount.to_string_in(bitcoin::Denomination::Bitcoin))
+                    })
                     .collect();
                 let minconf = 1u64;
                 let comment = "";
                 let subtract_fee_from: Vec<String> = Vec::new();
                 let replaceable = true;
                 let conf_target = 1u64;
-                let estimate_mode = "unset";
+                let estimate_mode = EstimateMode::Unset;
                 let fee_rate = serde_json::Value::Null;
                 let verbose = true;
                 self.call(
@@ -130,7 +196,7 @@ macro_rules! impl_client_v21__send_many_verbose {
                         into_json(subtract_fee_from)?,
                         replaceable.into(),
                         conf_target.into(),
-                        estimate_mode.into(),
+                        into_json(estimate_mode)?,
                         fee_rate,
                         verbose.into(),
                     ],
@@ -145,8 +211,16 @@ macro_rules! impl_client_v21__send_many_verbose {
 macro_rules! impl_client_v21__unload_wallet {
     () => {
         impl Client {
-            pub fn unload_wallet(&self, wallet: &str) -> Result<UnloadWallet> {
-                self.call("unloadwallet", &[wallet.into()])
+            /// Unloads `wallet`, optionally adding it to (or removing it from) the list of wallets
+            /// loaded on node startup.
+            pub fn unload_wallet(
+                &self,
+                wallet: &str,
+                load_on_startup: Option<bool>,
+            ) -> Result<UnloadWallet> {
+                use $crate::client_sync::opt_into_json;
+                let args = [wallet.into(), opt_into_json(load_on_startup)?];
+                self.call("unloadwallet", $crate::client_sync::trim_trailing_nulls(&args))
             }
         }
     };
@@ -157,8 +231,11 @@ macro_rules! impl_client_v21__unload_wallet {
 macro_rules! impl_client_v21__upgrade_wallet {
     () => {
         impl Client {
-            pub fn upgrade_wallet(&self) -> Result<UpgradeWallet> {
-                self.call("upgradewallet", &[])
+            pub fn upgrade_wallet(&self, version: Option<u32>) -> Result<UpgradeWallet> {
+                match version {
+                    Some(version) => self.call("upgradewallet", &[version.into()]),
+                    None => self.call("upgradewallet", &[]),
+                }
             }
         }
     };
@@ -21,18 +21,6 @@ macro_rules! impl_client_v22__list_descriptors {
     };
 }
 
-/// Implements Bitcoin Core JSON-RPC API method `loadwallet`.
-#[macro_export]
-macro_rules! impl_client_v22__load_wallet {
-    () => {
-        impl Client {
-            pub fn load_wallet(&self, wallet: &str) -> Result<LoadWallet> {
-                self.call("loadwallet", &[wallet.into()])
-            }
-        }
-    };
-}
-
 /// Implements Bitcoin Core JSON-RPC API method `walletdisplayaddress`.
 #[macro_export]
 macro_rules! impl_client_v22__wallet_display_address {
@@ -20,8 +20,8 @@ use crate::types::v23::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest, TemplateRules,
-        WalletCreateFundedPsbtInput,
+        AddNodeCommand, BumpFeeOptions, CreateRawTransactionBuilder, CreateWalletOptions, EstimateMode, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, ListUnspentQueryOptions, Input, Output, PrevTx, SetBanCommand, SighashType, TemplateRequest, TemplateRules,
+        WalletCreateFundedPsbtInput, WalletCreateFundedPsbtOptions,
     },
     v21::ImportDescriptorsRequest,
 };
@@ -108,14 +108,14 @@ crate::impl_client_v17__decode_psbt!();
 crate::impl_client_v17__decode_raw_transaction!();
 crate::impl_client_v17__decode_script!();
 crate::impl_client_v17__finalize_psbt!();
-crate::impl_client_v17__fund_raw_transaction!();
+crate::impl_client_v21__fund_raw_transaction!();
 crate::impl_client_v17__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
 crate::impl_client_v17__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Signer ==
 crate::impl_client_v22__enumerate_signers!();
@@ -135,8 +135,9 @@ crate::impl_client_v17__abandon_transaction!();
 crate::impl_client_v17__abort_rescan!();
 crate::impl_client_v17__add_multisig_address!();
 crate::impl_client_v17__backup_wallet!();
-crate::impl_client_v17__bump_fee!();
+crate::impl_client_v21__bump_fee!();
 crate::impl_client_v23__create_wallet!();
+crate::impl_client_v19__create_wallet_with_options!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
@@ -148,7 +149,7 @@ crate::impl_client_v18__get_received_by_label!();
 crate::impl_client_v17__get_new_address!();
 crate::impl_client_v17__get_raw_change_address!();
 crate::impl_client_v17__get_received_by_address!();
-crate::impl_client_v17__get_transaction!();
+crate::impl_client_v19__get_transaction!();
 crate::impl_client_v17__get_unconfirmed_balance!();
 crate::impl_client_v17__get_wallet_info!();
 crate::impl_client_v17__import_address!();
@@ -158,7 +159,7 @@ crate::impl_client_v17__import_privkey!();
 crate::impl_client_v17__import_pruned_funds!();
 crate::impl_client_v17__import_pubkey!();
 crate::impl_client_v17__import_wallet!();
-crate::impl_client_v17__key_pool_refill!();
+crate::impl_client_v17__keypool_refill!();
 crate::impl_client_v17__list_address_groupings!();
 crate::impl_client_v22__list_descriptors!();
 crate::impl_client_v18__list_received_by_label!();
@@ -170,7 +171,7 @@ crate::impl_client_v17__list_transactions!();
 crate::impl_client_v17__list_unspent!();
 crate::impl_client_v18__list_wallet_dir!();
 crate::impl_client_v17__list_wallets!();
-crate::impl_client_v22__load_wallet!();
+crate::impl_client_v21__load_wallet!();
 crate::impl_client_v17__lock_unspent!();
 crate::impl_client_v23__new_keypool!();
 crate::impl_client_v21__psbt_bump_fee!();
@@ -182,6 +183,7 @@ crate::impl_client_v17__send_many!();
 crate::impl_client_v21__send_many_verbose!();
 crate::impl_client_v17__send_to_address!();
 crate::impl_client_v17__set_hd_seed!();
+crate::impl_client_v18__set_label!();
 crate::impl_client_v17__set_tx_fee!();
 crate::impl_client_v19__set_wallet_flag!();
 crate::impl_client_v17__sign_message!();
@@ -198,7 +200,7 @@ crate::impl_client_v17__wallet_process_psbt!();
 // == Zmq ==
 crate::impl_client_v17__get_zmq_notifications!();
 
-/// Argument to the `Client::get_new_address_with_type` function.
+/// Argument to the address generating functions e.g., `Client::new_address_with_type`.
 ///
 /// For Core v23 and onwards. For earlier versions use `v17::AddressType`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements Bitcoin Core JSON-RPC API method `getrawtransaction` with verbosity set to 2.
+#[macro_export]
+macro_rules! impl_client_v25__get_raw_transaction_verbose_two {
+    () => {
+        impl Client {
+            /// Gets a transaction including its fee and the outputs spent by its inputs.
+            ///
+            /// The fee and prevouts are only included if block undo data is available, i.e.,
+            /// not for mempool or coinbase transactions.
+            pub fn get_raw_transaction_verbose_two(
+                &self,
+                txid: Txid,
+            ) -> Result<GetRawTransactionVerboseTwo> {
+                self.call("getrawtransaction", &[into_json(&txid)?, 2.into()])
+            }
+        }
+    };
+}
@@ -14,16 +14,24 @@
 macro_rules! impl_client_v29__derive_addresses {
     () => {
         impl Client {
-            // For single derivation descriptors.
-            pub fn derive_addresses(&self, descriptor: &str) -> Result<DeriveAddresses> {
-                self.call("deriveaddresses", &[descriptor.into()])
+            // For single derivation descriptors, `range` is required for ranged descriptors.
+            pub fn derive_addresses(
+                &self,
+                descriptor: &str,
+                range: Option<(u64, u64)>,
+            ) -> Result<DeriveAddresses> {
+                match range {
+                    Some((begin, end)) => self
+                        .call("deriveaddresses", &[descriptor.into(), into_json([begin, end])?]),
+                    None => self.call("deriveaddresses", &[descriptor.into()]),
+                }
             }
 
             // For multipath descriptors.
             pub fn derive_addresses_multipath(
                 &self,
                 descriptor: &str,
-                range: (u32, u32),
+                range: (u64, u64),
             ) -> Result<DeriveAddressesMultipath> {
                 let range = json!([range.0, range.1]);
                 self.call("deriveaddresses", &[descriptor.into(), range.into()])
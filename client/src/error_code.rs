@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Error codes returned by the Bitcoin Core JSON-RPC API.
+//!
+//! See `src/rpc/protocol.h` in the Bitcoin Core repository.
+
+use core::fmt;
+
+/// An error code returned by Bitcoin Core in a JSON-RPC error object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RpcErrorCode {
+    // Standard JSON-RPC 2.0 errors.
+    /// Invalid request object (-32600).
+    InvalidRequest,
+    /// Method not found (-32601).
+    MethodNotFound,
+    /// Invalid method parameters (-32602).
+    InvalidParams,
+    /// Internal JSON-RPC error (-32603).
+    InternalError,
+    /// Invalid JSON was received by the server (-32700).
+    ParseError,
+
+    // General application defined errors.
+    /// Exception thrown in command handling (-1).
+    MiscError,
+    /// Unexpected type was passed as parameter (-3).
+    TypeError,
+    /// Invalid address or key (-5).
+    InvalidAddressOrKey,
+    /// Ran out of memory during operation (-7).
+    OutOfMemory,
+    /// Invalid, missing or duplicate parameter (-8).
+    InvalidParameter,
+    /// Database error (-20).
+    DatabaseError,
+    /// Error parsing or validating structure in raw format (-22).
+    DeserializationError,
+    /// General error during transaction or block submission (-25).
+    VerifyError,
+    /// Transaction or block was rejected by network rules (-26).
+    VerifyRejected,
+    /// Transaction already in chain (-27).
+    VerifyAlreadyInChain,
+    /// Client still warming up (-28).
+    InWarmup,
+    /// RPC method is deprecated (-32).
+    MethodDeprecated,
+
+    // P2P client errors.
+    /// Bitcoin is not connected (-9).
+    ClientNotConnected,
+    /// Still downloading initial blocks (-10).
+    ClientInInitialDownload,
+    /// Node is already added (-23).
+    ClientNodeAlreadyAdded,
+    /// Node has not been added before (-24).
+    ClientNodeNotAdded,
+    /// Node to disconnect not found in connected nodes (-29).
+    ClientNodeNotConnected,
+    /// Invalid IP/Subnet (-30).
+    ClientInvalidIpOrSubnet,
+    /// No valid connection manager instance found (-31).
+    ClientP2pDisabled,
+    /// Max number of outbound or block-relay connections already open (-34).
+    ClientNodeCapacityReached,
+
+    // Chain errors.
+    /// No mempool instance found (-33).
+    ClientMempoolDisabled,
+
+    // Wallet errors.
+    /// Unspecified problem with wallet (-4).
+    WalletError,
+    /// Not enough funds in wallet or account (-6).
+    WalletInsufficientFunds,
+    /// Invalid label name (-11).
+    WalletInvalidLabelName,
+    /// Keypool ran out, call keypoolrefill first (-12).
+    WalletKeypoolRanOut,
+    /// Enter the wallet passphrase with walletpassphrase first (-13).
+    WalletUnlockNeeded,
+    /// The wallet passphrase entered was incorrect (-14).
+    WalletPassphraseIncorrect,
+    /// Command given in wrong wallet encryption state (-15).
+    WalletWrongEncState,
+    /// Failed to encrypt the wallet (-16).
+    WalletEncryptionFailed,
+    /// Wallet is already unlocked (-17).
+    WalletAlreadyUnlocked,
+    /// Invalid wallet specified (-18).
+    WalletNotFound,
+    /// No wallet specified, error when there are multiple wallets loaded (-19).
+    WalletNotSpecified,
+    /// This same wallet is already loaded (-35).
+    WalletAlreadyLoaded,
+    /// There is already a wallet with the same name (-36).
+    WalletAlreadyExists,
+
+    /// An error code not known to this library.
+    Other(i32),
+}
+
+impl RpcErrorCode {
+    /// Converts an integer error code into an [`RpcErrorCode`].
+    pub fn from_i32(code: i32) -> Self {
+        use RpcErrorCode::*;
+
+        match code {
+            -32600 => InvalidRequest,
+            -32601 => MethodNotFound,
+            -32602 => InvalidParams,
+            -32603 => InternalError,
+            -32700 => ParseError,
+            -1 => MiscError,
+            -3 => TypeError,
+            -5 => InvalidAddressOrKey,
+            -7 => OutOfMemory,
+            -8 => InvalidParameter,
+            -20 => DatabaseError,
+            -22 => DeserializationError,
+            -25 => VerifyError,
+            -26 => VerifyRejected,
+            -27 => VerifyAlreadyInChain,
+            -28 => InWarmup,
+            -32 => MethodDeprecated,
+            -9 => ClientNotConnected,
+            -10 => ClientInInitialDownload,
+            -23 => ClientNodeAlreadyAdded,
+            -24 => ClientNodeNotAdded,
+            -29 => ClientNodeNotConnected,
+            -30 => ClientInvalidIpOrSubnet,
+            -31 => ClientP2pDisabled,
+            -34 => ClientNodeCapacityReached,
+            -33 => ClientMempoolDisabled,
+            -4 => WalletError,
+            -6 => WalletInsufficientFunds,
+            -11 => WalletInvalidLabelName,
+            -12 => WalletKeypoolRanOut,
+            -13 => WalletUnlockNeeded,
+            -14 => WalletPassphraseIncorrect,
+            -15 => WalletWrongEncState,
+            -16 => WalletEncryptionFailed,
+            -17 => WalletAlreadyUnlocked,
+            -18 => WalletNotFound,
+            -19 => WalletNotSpecified,
+            -35 => WalletAlreadyLoaded,
+            -36 => WalletAlreadyExists,
+            other => Other(other),
+        }
+    }
+
+    /// Returns the integer error code.
+    pub fn to_i32(self) -> i32 {
+        use RpcErrorCode::*;
+
+        match self {
+            InvalidRequest => -32600,
+            MethodNotFound => -32601,
+            InvalidParams => -32602,
+            InternalError => -32603,
+            ParseError => -32700,
+            MiscError => -1,
+            TypeError => -3,
+            InvalidAddressOrKey => -5,
+            OutOfMemory => -7,
+            InvalidParameter => -8,
+            DatabaseError => -20,
+            DeserializationError => -22,
+            VerifyError => -25,
+            VerifyRejected => -26,
+            VerifyAlreadyInChain => -27,
+            InWarmup => -28,
+            MethodDeprecated => -32,
+            ClientNotConnected => -9,
+            ClientInInitialDownload => -10,
+            ClientNodeAlreadyAdded => -23,
+            ClientNodeNotAdded => -24,
+            ClientNodeNotConnected => -29,
+            ClientInvalidIpOrSubnet => -30,
+            ClientP2pDisabled => -31,
+            ClientNodeCapacityReached => -34,
+            ClientMempoolDisabled => -33,
+            WalletError => -4,
+            WalletInsufficientFunds => -6,
+            WalletInvalidLabelName => -11,
+            WalletKeypoolRanOut => -12,
+            WalletUnlockNeeded => -13,
+            WalletPassphraseIncorrect => -14,
+            WalletWrongEncState => -15,
+            WalletEncryptionFailed => -16,
+            WalletAlreadyUnlocked => -17,
+            WalletNotFound => -18,
+            WalletNotSpecified => -19,
+            WalletAlreadyLoaded => -35,
+            WalletAlreadyExists => -36,
+            Other(code) => code,
+        }
+    }
+}
+
+impl From<i32> for RpcErrorCode {
+    fn from(code: i32) -> Self { Self::from_i32(code) }
+}
+
+impl From<RpcErrorCode> for i32 {
+    fn from(code: RpcErrorCode) -> Self { code.to_i32() }
+}
+
+impl fmt::Display for RpcErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ({})", self, self.to_i32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for code in -40..0 {
+            assert_eq!(RpcErrorCode::from_i32(code).to_i32(), code);
+        }
+        for code in [-32600, -32601, -32602, -32603, -32700] {
+            let known = RpcErrorCode::from_i32(code);
+            assert!(!matches!(known, RpcErrorCode::Other(_)));
+            assert_eq!(known.to_i32(), code);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "client-sync")]
+    fn sync_error_carries_code() {
+        use crate::client_sync::Error;
+
+        // Response body returned by Bitcoin Core v29 for `getwalletinfo` with no wallet loaded.
+        let body = r#"{"result":null,"error":{"code":-18,"message":"No wallet is loaded. Load a wallet using loadwallet or create a new one with createwallet. (Note: A default wallet is no longer automatically created)"},"id":1}"#;
+        let resp: jsonrpc::Response = serde_json::from_str(body).unwrap();
+        let err = Error::from(resp.result::<serde_json::Value>().unwrap_err());
+
+        assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::WalletNotFound));
+    }
+
+    #[test]
+    #[cfg(feature = "client-async")]
+    fn async_error_carries_code() {
+        use crate::client_async::RpcError;
+
+        let body = r#"{"code":-26,"message":"min relay fee not met, 100 < 141"}"#;
+        let err: RpcError = serde_json::from_str(body).unwrap();
+
+        assert_eq!(err.error_code(), RpcErrorCode::VerifyRejected);
+    }
+}
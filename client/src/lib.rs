@@ -8,6 +8,14 @@ pub extern crate bitcoin;
 /// Re-export the `corepc-types` crate.
 pub extern crate types;
 
+mod error_code;
+
+pub use crate::error_code::RpcErrorCode;
+
 #[cfg(feature = "client-sync")]
 #[macro_use]
 pub mod client_sync;
+
+#[cfg(feature = "client-async")]
+#[macro_use]
+pub mod client_async;
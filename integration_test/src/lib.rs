@@ -1,6 +1,7 @@
 //! Provides a macro that implements the tests.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use node::{Conf, P2P};
 use rand::distributions::Alphanumeric;
@@ -74,12 +75,14 @@ impl NodeExt for Node {
 
     fn fund_wallet(&self) {
         let address = self.client.new_address().expect("failed to get new address");
-        self.client.generate_to_address(101, &address).expect("failed to generate to address");
+        self.client
+            .generate_to_address(101, &address, None)
+            .expect("failed to generate to address");
     }
 
     fn mine_a_block(&self) {
         let address = self.client.new_address().expect("failed to get new address");
-        self.client.generate_to_address(1, &address).expect("failed to generate to address");
+        self.client.generate_to_address(1, &address, None).expect("failed to generate to address");
     }
 
     fn create_mempool_transaction(&self) -> (bitcoin::Address, bitcoin::Txid) {
@@ -122,6 +125,19 @@ pub fn random_tmp_file() -> PathBuf {
     tmp
 }
 
+/// Polls `condition` every 100ms for up to five seconds.
+///
+/// Returns `true` as soon as `condition` does, `false` if it never did.
+pub fn wait_for(mut condition: impl FnMut() -> bool) -> bool {
+    for _ in 0..50 {
+        if condition() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    false
+}
+
 /// Creates a Bitcoin network with three connected nodes.
 pub fn three_node_network() -> (Node, Node, Node) {
     let exe = node::exe_path().expect("failed to get bitcoind executable");
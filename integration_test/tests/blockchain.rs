@@ -59,9 +59,32 @@ fn blockchain__get_block__modelled() {
     let model: Result<mtype::GetBlockVerboseOne, GetBlockVerboseOneError> = json.into_model();
     model.unwrap();
 
-    // TODO: Test getblock 2
-    // let json = node.client.get_block_with_verbosity(block_hash, 2).expect("getblock verbosity 2");
-    // assert!(json.into_model().is_ok());
+    let json: GetBlockVerboseTwo =
+        node.client.get_block_verbose_two(block_hash).expect("getblock verbose=2");
+    let model: Result<mtype::GetBlockVerboseTwo, GetBlockVerboseTwoError> = json.into_model();
+    model.unwrap();
+}
+
+#[test]
+fn blockchain__get_block_verbose_two__modelled() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_, tx) = node.create_mined_transaction();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
+
+    let json: GetBlockVerboseTwo =
+        node.client.get_block_verbose_two(block_hash).expect("getblock verbose=2");
+    let model: Result<mtype::GetBlockVerboseTwo, GetBlockVerboseTwoError> = json.into_model();
+    let block = model.unwrap();
+
+    assert_eq!(block.tx.len(), 2);
+    assert!(block.tx[0].tx.is_coinbase());
+    assert!(block.tx[0].fee.is_none());
+    assert_eq!(block.tx[1].tx, tx);
+
+    // Older versions of Core do not include the fee in the verbosity 2 result.
+    #[cfg(not(feature = "v21_and_below"))]
+    assert!(block.tx[1].fee.expect("fee") > bitcoin::Amount::ZERO);
 }
 
 #[test]
@@ -70,7 +93,49 @@ fn blockchain__get_blockchain_info__modelled() {
 
     let json: GetBlockchainInfo = node.client.get_blockchain_info().expect("rpc");
     let model: Result<mtype::GetBlockchainInfo, GetBlockchainInfoError> = json.into_model();
-    model.unwrap();
+    let model = model.unwrap();
+
+    let best_block_hash = node.client.best_block_hash().expect("best_block_hash");
+    assert_eq!(model.best_block_hash, best_block_hash);
+
+    // Each regtest block, including genesis, adds 2 to the chain work.
+    let mut work = [0_u8; 32];
+    work[31] = 2;
+    assert_eq!(model.chain_work, bitcoin::Work::from_be_bytes(work));
+
+    // Softforks moved to `getdeploymentinfo` in v23.
+    #[cfg(all(not(feature = "v18_and_below"), feature = "v22_and_below"))]
+    {
+        let segwit = model.softforks.get("segwit").expect("segwit softfork");
+        assert_eq!(segwit.type_, mtype::SoftforkType::Buried);
+        assert!(segwit.active);
+    }
+}
+
+#[test]
+#[cfg(feature = "v22_and_below")] // Softforks moved to `getdeploymentinfo` in v23.
+fn blockchain__get_blockchain_info__bip9_started() {
+    // On regtest `testdummy` is defined from genesis and starts with the second 144 block period.
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let address = node.client.new_address().expect("failed to get new address");
+    node.client.generate_to_address(144, &address, None).expect("generatetoaddress");
+
+    let json: GetBlockchainInfo = node.client.get_blockchain_info().expect("getblockchaininfo");
+    let model: mtype::GetBlockchainInfo = json.into_model().expect("GetBlockchainInfo into model");
+
+    let testdummy = model.softforks.get("testdummy").expect("testdummy softfork");
+    assert_eq!(testdummy.type_, mtype::SoftforkType::Bip9);
+    assert!(!testdummy.active);
+
+    let bip9 = testdummy.bip9.as_ref().expect("bip9 info");
+    assert_eq!(bip9.status, mtype::Bip9SoftforkStatus::Started);
+    assert_eq!(bip9.bit, Some(28));
+    assert_eq!(bip9.since, 144);
+
+    let statistics = bip9.statistics.as_ref().expect("statistics");
+    assert_eq!(statistics.period, 144);
+    assert_eq!(statistics.threshold, Some(108));
+    assert_eq!(statistics.possible, Some(true));
 }
 
 #[test]
@@ -90,7 +155,12 @@ fn blockchain__get_block_filter__modelled() {
 
     let json: GetBlockFilter = node.client.get_block_filter(hash).expect("getblockfilter");
     let model: Result<mtype::GetBlockFilter, GetBlockFilterError> = json.into_model();
-    model.unwrap();
+    let filter = model.unwrap().filter;
+
+    // The basic filter commits to the coinbase output script.
+    let block = node.client.get_block(hash).expect("getblock");
+    let script = block.txdata[0].output[0].script_pubkey.as_bytes();
+    assert!(filter.match_any(&hash, core::iter::once(script)).expect("match_any"));
 }
 
 #[test]
@@ -124,6 +194,30 @@ fn blockchain__get_block_from_peer() {
     let _: () = node1.client.get_block_from_peer(hash, peer_id).expect("getblockfrompeer");
 }
 
+#[test]
+fn blockchain__wait_for_block_height() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let address = node.client.new_address().expect("failed to get new address");
+    let timeout = std::time::Duration::from_secs(30);
+
+    let start = std::time::Instant::now();
+    let count = std::thread::scope(|s| {
+        s.spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            node.client.generate_to_address(5, &address, None).expect("generatetoaddress");
+        });
+        node.client.wait_for_block_height(5, timeout).expect("wait_for_block_height")
+    });
+    assert!(count >= 5);
+    assert!(start.elapsed() < timeout);
+
+    let short = std::time::Duration::from_millis(200);
+    match node.client.wait_for_block_height(1_000, short) {
+        Err(client_sync::Error::Timeout(d)) => assert_eq!(d, short),
+        other => panic!("expected a timeout, got: {:?}", other),
+    }
+}
+
 #[test]
 fn blockchain__get_block_hash__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
@@ -165,14 +259,15 @@ fn getblockstats() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
-    let json: GetBlockStats = node.client.get_block_stats_by_height(1).expect("getblockstats");
+    let json: GetBlockStats =
+        node.client.get_block_stats_by_height(1, None).expect("getblockstats");
     let model: Result<mtype::GetBlockStats, GetBlockStatsError> = json.into_model();
     model.unwrap();
 
     // No need for explicit types, used explicitly in test below.
     let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
     let json: GetBlockStats =
-        node.client.get_block_stats_by_block_hash(&block_hash).expect("getblockstats");
+        node.client.get_block_stats_by_block_hash(&block_hash, None).expect("getblockstats");
     let model: Result<mtype::GetBlockStats, GetBlockStatsError> = json.into_model();
     model.unwrap();
 }
@@ -182,18 +277,39 @@ fn getblockstats_txindex() {
     node.fund_wallet();
 
     // Get block stats by height.
-    let json: GetBlockStats = node.client.get_block_stats_by_height(101).expect("getblockstats");
+    let json: GetBlockStats =
+        node.client.get_block_stats_by_height(101, None).expect("getblockstats");
     let model: Result<mtype::GetBlockStats, GetBlockStatsError> = json.into_model();
     model.unwrap();
 
     // Get block stats by block hash.
     let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
     let json: GetBlockStats =
-        node.client.get_block_stats_by_block_hash(&block_hash).expect("getblockstats");
+        node.client.get_block_stats_by_block_hash(&block_hash, None).expect("getblockstats");
     let model: Result<mtype::GetBlockStats, GetBlockStatsError> = json.into_model();
     model.unwrap();
 }
 
+#[test]
+fn blockchain__get_block_stats__selected_stats() {
+    // Version 17 and 18 cannot compute fee stats if `-txindex` is not enabled.
+    let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+
+    let json: GetBlockStats = node
+        .client
+        .get_block_stats_by_height(101, Some(&["avgfee", "height"]))
+        .expect("getblockstats");
+    let model: mtype::GetBlockStats = json.into_model().expect("GetBlockStats into model");
+
+    assert_eq!(model.height, Some(101));
+    assert_eq!(model.average_fee, Some(bitcoin::Amount::ZERO));
+    assert!(model.block_hash.is_none());
+    assert!(model.fee_rate_percentiles.is_none());
+    assert!(model.subsidy.is_none());
+    assert!(model.txs.is_none());
+}
+
 #[test]
 #[cfg(not(feature = "v25_and_below"))]
 fn blockchain__get_chain_states__modelled() {
@@ -217,15 +333,48 @@ fn blockchain__get_chain_tips__modelled() {
     model.unwrap();
 }
 
+#[test]
+fn blockchain__get_chain_tips__fork() {
+    let node1 = Node::with_wallet(Wallet::Default, &[]);
+    let node2 = Node::with_wallet(Wallet::Default, &[]);
+    let (hash_a, hash_b) = create_competing_tips(&node1, &node2);
+
+    // Switching to block B fully validates it, leaving block A as a valid fork.
+    node1.client.precious_block(hash_b).expect("preciousblock");
+
+    let json: GetChainTips = node1.client.get_chain_tips().expect("getchaintips");
+    let model: mtype::GetChainTips = json.into_model().expect("GetChainTips into model");
+    let status_of = |hash| model.0.iter().find(|tip| tip.hash == hash).map(|tip| tip.status);
+
+    assert_eq!(status_of(hash_b), Some(mtype::ChainTipsStatus::Active));
+    assert_eq!(status_of(hash_a), Some(mtype::ChainTipsStatus::ValidFork));
+}
+
 #[test]
 fn blockchain__get_chain_tx_stats__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
 
-    let json: GetChainTxStats = node.client.get_chain_tx_stats().expect("getchaintxstats");
+    let json: GetChainTxStats =
+        node.client.get_chain_tx_stats(None, None).expect("getchaintxstats");
     let model: Result<mtype::GetChainTxStats, GetChainTxStatsError> = json.into_model();
     model.unwrap();
 }
 
+#[test]
+fn blockchain__get_chain_tx_stats__window() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let block_hash = node.client.get_block_hash(50).expect("getblockhash").block_hash().unwrap();
+
+    let json: GetChainTxStats =
+        node.client.get_chain_tx_stats(Some(10), Some(block_hash)).expect("getchaintxstats");
+    let model: mtype::GetChainTxStats = json.into_model().expect("GetChainTxStats into model");
+
+    assert_eq!(model.window_block_count, 10);
+    assert_eq!(model.window_final_block_hash, block_hash);
+    assert_eq!(model.window_tx_count, Some(10));
+}
+
 #[test]
 #[cfg(not(feature = "v22_and_below"))]
 fn blockchain__get_deployment_info__modelled() {
@@ -387,7 +536,7 @@ fn blockchain__get_tx_out_proof() {
     let (_address, tx) = node.create_mined_transaction();
     let txid = tx.compute_txid();
 
-    let _ = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+    let _ = node.client.get_tx_out_proof(&[txid], None).expect("gettxoutproof");
 }
 
 #[test]
@@ -448,25 +597,68 @@ fn blockchain__precious_block() {
 }
 
 #[test]
-fn blockchain__prune_blockchain() {
-    const NBLOCKS: usize = 1;
+fn blockchain__precious_block__reorg() {
+    let node1 = Node::with_wallet(Wallet::Default, &[]);
+    let node2 = Node::with_wallet(Wallet::Default, &[]);
+    let (hash_a, hash_b) = create_competing_tips(&node1, &node2);
+    assert_eq!(node1.client.best_block_hash().expect("best_block_hash"), hash_a);
 
-    let node = Node::with_wallet(Wallet::Default, &["-prune=550"]);
-    let address = node.client.new_address().expect("Failed to get new address");
+    node1.client.precious_block(hash_b).expect("preciousblock");
+    assert_eq!(node1.client.best_block_hash().expect("best_block_hash"), hash_b);
 
-    let gen_result = node
-        .client
-        .generate_to_address(NBLOCKS, &address)
-        .expect("generate_to_address RPC call failed");
-    assert_eq!(
-        gen_result.0.len(),
-        NBLOCKS,
-        "generate_to_address did not return the expected number of block hashes"
-    );
+    // Already the active tip, this is a no-op.
+    node1.client.precious_block(hash_b).expect("preciousblock");
+    assert_eq!(node1.client.best_block_hash().expect("best_block_hash"), hash_b);
+}
+
+#[test]
+fn blockchain__prune_blockchain__modelled() {
+    // Manual pruning mode. Core requires keeping at least 288 blocks.
+    //
+    // Only whole block files are pruned, `-fastprune` (not available before v24) uses block files
+    // small enough for a regtest chain to fill several of them.
+    let args: &[&str] = match () {
+        #[cfg(feature = "v23_and_below")]
+        () => &["-prune=1"],
+        #[cfg(not(feature = "v23_and_below"))]
+        () => &["-prune=1", "-fastprune"],
+    };
+    let node = Node::with_wallet(Wallet::Default, args);
+    let address = node.client.new_address().expect("Failed to get new address");
+    node.client.generate_to_address(1_000, &address, None).expect("generatetoaddress");
+    // Read before pruning, the block data may be gone afterwards.
+    let hash = node.client.get_block_hash(20).expect("getblockhash").block_hash().unwrap();
+    let time = node.client.get_block(hash).expect("getblock").header.time;
+
+    let json: PruneBlockchain =
+        node.client.prune_blockchain_to_height(300).expect("pruneblockchain");
+    let model: Result<mtype::PruneBlockchain, NumericError> = json.into_model();
+    let first = model.unwrap().0;
+    // Pruning stops at the last whole block file so this may be lower than requested.
+    assert!(first.map_or(true, |h| h <= 300));
+
+    #[cfg(not(feature = "v23_and_below"))]
+    {
+        let first = first.expect("a block file was pruned");
+        let json: PruneBlockchain =
+            node.client.prune_blockchain_to_height(700).expect("pruneblockchain");
+        let second = json.into_model().unwrap().0.expect("a block file was pruned");
+        assert!(second > first && second <= 700);
+    }
 
-    let target_height: u64 = 500;
+    let json: PruneBlockchain =
+        node.client.prune_blockchain_to_timestamp(u64::from(time)).expect("pruneblockchain");
+    json.into_model().unwrap();
 
-    let _: Result<PruneBlockchain, _> = node.client.prune_blockchain(target_height);
+    // Arguments Core would interpret the other way are rejected without calling the node.
+    assert!(matches!(
+        node.client.prune_blockchain_to_height(1_000_000_000),
+        Err(client_sync::Error::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        node.client.prune_blockchain_to_timestamp(20),
+        Err(client_sync::Error::InvalidArgument(_))
+    ));
 }
 
 #[test]
@@ -482,7 +674,8 @@ fn blockchain__savemempool() {
 
     #[cfg(not(feature = "v22_and_below"))]
     {
-        let _: Result<SaveMempool, _> = node.client.save_mempool();
+        let json: SaveMempool = node.client.save_mempool().expect("savemempool");
+        assert!(json.filename.ends_with("mempool.dat"));
     }
 }
 
@@ -504,6 +697,28 @@ fn blockchain__scan_blocks_modelled() {
     let _: ScanBlocksAbort = node.client.scan_blocks_abort().expect("scanblocks abort");
 }
 
+#[test]
+#[cfg(all(
+    feature = "experimental-rpc",
+    feature = "v19_and_below",
+    not(feature = "v18_and_below")
+))]
+fn blockchain__scan_tx_out_set__modelled() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (address, _tx) = node.create_mined_transaction();
+
+    let scan_object = format!("addr({})", address);
+    let json: ScanTxOutSet =
+        node.client.scan_tx_out_set("start", &[scan_object]).expect("scantxoutset");
+    let model: Result<mtype::ScanTxOutSet, ScanTxOutSetError> = json.into_model();
+    let scan = model.unwrap();
+
+    assert!(scan.success);
+    assert_eq!(scan.unspents.len(), 1);
+    assert_eq!(scan.total_amount, scan.unspents[0].amount);
+}
+
 #[test]
 fn blockchain__verify_tx_out_proof__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
@@ -518,6 +733,28 @@ fn blockchain__get_tx_out_proof__modelled() {
     verify_tx_out_proof(&node).unwrap();
 }
 
+#[test]
+fn blockchain__get_tx_out_proof__block_hash_round_trip() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_, txid_a) = node.create_mempool_transaction();
+    let (_, txid_b) = node.create_mempool_transaction();
+    node.mine_a_block();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash");
+
+    let proof =
+        node.client.get_tx_out_proof(&[txid_a, txid_b], Some(block_hash)).expect("gettxoutproof");
+
+    let json: VerifyTxOutProof = node.client.verify_tx_out_proof(&proof).expect("verifytxoutproof");
+    let model: Result<mtype::VerifyTxOutProof, hex::HexToArrayError> = json.into_model();
+    let mut got = model.unwrap().0;
+    got.sort();
+
+    let mut want = vec![txid_a, txid_b];
+    want.sort();
+    assert_eq!(got, want);
+}
+
 #[test]
 fn blockchain__verify_chain() {
     let node = Node::with_wallet(Wallet::None, &[]);
@@ -529,14 +766,13 @@ fn verify_tx_out_proof(node: &Node) -> Result<(), client_sync::Error> {
     let (_address, tx) = node.create_mined_transaction();
     let txid = tx.compute_txid();
 
-    let proof = node.client.get_tx_out_proof(&[txid])?;
+    let proof = node.client.get_tx_out_proof(&[txid], None)?;
 
     let json: VerifyTxOutProof = node.client.verify_tx_out_proof(&proof)?;
     let model: Result<mtype::VerifyTxOutProof, hex::HexToArrayError> = json.into_model();
     let txids = model.unwrap();
 
-    // sanity check
-    assert_eq!(txids.0.len(), 1);
+    assert_eq!(txids.0, vec![txid]);
 
     Ok(())
 }
@@ -553,12 +789,12 @@ fn create_child_spending_parent(node: &Node, parent_txid: bitcoin::Txid) -> bitc
     let unsigned = raw.transaction().expect("raw.transaction");
 
     let funded: FundRawTransaction =
-        node.client.fund_raw_transaction(&unsigned).expect("fundrawtransaction");
+        node.client.fund_raw_transaction(&unsigned, None).expect("fundrawtransaction");
     let funded_tx = funded.transaction().expect("funded.transaction");
 
     let signed: SignRawTransaction = node
         .client
-        .sign_raw_transaction_with_wallet(&funded_tx)
+        .sign_raw_transaction_with_wallet(&funded_tx, &[], None)
         .expect("signrawtransactionwithwallet");
     let sign_raw_transaction =
         signed.into_model().expect("SignRawTransactionWithWallet into model");
@@ -567,3 +803,23 @@ fn create_child_spending_parent(node: &Node, parent_txid: bitcoin::Txid) -> bitc
 
     child_txid
 }
+
+/// Mines a competing block at the same height on each of two unconnected nodes and submits the
+/// block from `node2` to `node1`. Returns the hashes of the blocks mined by `node1` and `node2`.
+fn create_competing_tips(node1: &Node, node2: &Node) -> (bitcoin::BlockHash, bitcoin::BlockHash) {
+    node1.mine_a_block();
+    node2.mine_a_block();
+    let hash_a = node1.client.best_block_hash().expect("best_block_hash");
+    let hash_b = node2.client.best_block_hash().expect("best_block_hash");
+    assert_ne!(hash_a, hash_b);
+
+    // Core returns "inconclusive" because the stale block does not become the tip.
+    let block_b = node2.client.get_block(hash_b).expect("getblock");
+    match node1.client.submit_block(&block_b) {
+        Ok(()) => {}
+        Err(client_sync::Error::Returned(s)) => assert!(s.contains("inconclusive")),
+        Err(e) => panic!("submitblock failed: {}", e),
+    }
+
+    (hash_a, hash_b)
+}
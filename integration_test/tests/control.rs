@@ -4,20 +4,36 @@
 
 #![allow(non_snake_case)] // Test names intentionally use double underscore.
 
+use std::time::Duration;
+
 use integration_test::{Node, NodeExt as _, Wallet};
+use node::mtype;
 use node::vtype::*; // All the version specific types.
 
 #[test]
 fn control__get_memory_info() {
     let node = Node::with_wallet(Wallet::None, &[]);
     let _: GetMemoryInfoStats = node.client.get_memory_info().unwrap();
+
+    let xml: String = node.client.get_memory_info_mallocinfo().unwrap();
+    assert!(xml.starts_with("<malloc"));
 }
 
 #[test]
 #[cfg(not(feature = "v17"))]
-fn control__get_rpc_info() {
+fn control__get_rpc_info__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
-    let _ = node.client.get_rpc_info().unwrap();
+
+    let json: GetRpcInfo = node.client.get_rpc_info().expect("getrpcinfo");
+    let model: mtype::GetRpcInfo = json.into_model();
+
+    // The currently running `getrpcinfo` call is always in the active list.
+    assert!(model.active_commands.iter().any(|c| c.method == "getrpcinfo"));
+
+    #[cfg(feature = "v18_and_below")]
+    assert!(model.log_path.is_none());
+    #[cfg(not(feature = "v18_and_below"))]
+    assert!(model.log_path.expect("logpath").ends_with("debug.log"));
 }
 
 #[test]
@@ -27,9 +43,17 @@ fn control__help() {
 }
 
 #[test]
-fn control__logging() {
+fn control__logging__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
-    let _: Logging = node.client.logging().unwrap();
+
+    let json: Logging = node.client.logging(&[], &[]).expect("logging");
+    let model: mtype::Logging = json.into_model();
+    assert!(model.0.contains_key("net"));
+
+    let json: Logging = node.client.logging(&["net"], &["rpc"]).expect("logging");
+    let model: mtype::Logging = json.into_model();
+    assert_eq!(model.0.get("net"), Some(&true));
+    assert_eq!(model.0.get("rpc"), Some(&false));
 }
 
 #[test]
@@ -41,5 +65,12 @@ fn control__stop() {
 #[test]
 fn control__uptime() {
     let node = Node::with_wallet(Wallet::None, &[]);
-    let _ = node.client.uptime().unwrap();
+    // Uptime has a resolution of one second.
+    std::thread::sleep(Duration::from_millis(1100));
+
+    let uptime: Duration = node.client.uptime().expect("uptime");
+    assert!(uptime > Duration::ZERO);
+
+    let secs: u64 = node.client.uptime_secs().expect("uptime");
+    assert!(secs >= uptime.as_secs());
 }
@@ -4,6 +4,8 @@
 
 #![allow(non_snake_case)] // Test names intentionally use double underscore.
 
+use std::collections::BTreeSet;
+
 use bitcoin::hex;
 use integration_test::{Node, NodeExt as _, Wallet};
 use node::mtype;
@@ -63,18 +65,36 @@ fn generating__generate__modelled() {
 
 #[test]
 fn generating__generate_to_address__modelled() {
-    const NBLOCKS: usize = 1;
+    const NBLOCKS: u64 = 1;
 
     let node = Node::with_wallet(Wallet::Default, &[]);
     let address = node.client.new_address().expect("failed to get new address");
 
     let json: GenerateToAddress =
-        node.client.generate_to_address(NBLOCKS, &address).expect("generatetoaddress");
+        node.client.generate_to_address(NBLOCKS, &address, None).expect("generatetoaddress");
 
     let model: Result<mtype::GenerateToAddress, hex::HexToArrayError> = json.into_model();
     model.unwrap();
 }
 
+#[test]
+fn generating__generate_to_address__block_hashes() {
+    const NBLOCKS: u64 = 10;
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let address = node.client.new_address().expect("failed to get new address");
+    let before = node.client.get_block_count().expect("getblockcount").0;
+
+    let json: GenerateToAddress =
+        node.client.generate_to_address(NBLOCKS, &address, Some(1_000)).expect("generatetoaddress");
+    let model: mtype::GenerateToAddress = json.into_model().expect("GenerateToAddress into model");
+
+    let distinct = model.0.iter().collect::<BTreeSet<_>>();
+    assert_eq!(distinct.len(), NBLOCKS as usize);
+    assert_eq!(node.client.get_block_count().expect("getblockcount").0, before + NBLOCKS);
+    assert_eq!(node.client.best_block_hash().expect("best_block_hash"), model.0[model.0.len() - 1]);
+}
+
 #[test]
 #[cfg(not(feature = "v19_and_below"))]
 fn generating__generate_to_descriptor__modelled() {
@@ -93,7 +113,7 @@ fn generating__generate_to_descriptor__modelled() {
 // This method does not appear in the output of `bitcoin-cli help`.
 #[test]
 fn generating__invalidate_block() {
-    const NBLOCKS: usize = 1;
+    const NBLOCKS: u64 = 1;
 
     let node = Node::with_wallet(Wallet::Default, &[]);
 
@@ -101,7 +121,7 @@ fn generating__invalidate_block() {
     let old_best_block =
         node.client.get_best_block_hash().expect("getbestblockhash").into_model().unwrap().0;
     node.client
-        .generate_to_address(NBLOCKS, &address)
+        .generate_to_address(NBLOCKS, &address, None)
         .expect("generatetoaddress")
         .into_model()
         .unwrap();
@@ -4,7 +4,7 @@
 
 #![allow(non_snake_case)] // Test names intentionally use double underscore.
 
-use bitcoin::SignedAmount;
+use bitcoin::{CompactTarget, Network, SignedAmount, Target, Txid};
 use integration_test::{Node, NodeExt as _, Wallet};
 use node::vtype::*;
 use node::{mtype, TemplateRequest, TemplateRules}; // All the version specific types.
@@ -19,46 +19,49 @@ fn mining__get_block_template__modelled() {
     node2.mine_a_block();
     node3.mine_a_block();
 
-    let options = match () {
-        #[cfg(feature = "v28_and_below")]
-        () => TemplateRequest { rules: vec![TemplateRules::Segwit] },
-        #[cfg(not(feature = "v28_and_below"))]
-        () => TemplateRequest {
-            rules: vec![TemplateRules::Segwit],
-            mode: Some("template".to_string()),
-            ..Default::default()
-        },
+    let options = TemplateRequest {
+        mode: Some("template".to_string()),
+        capabilities: vec!["longpoll".to_string(), "coinbasevalue".to_string()],
+        rules: vec![TemplateRules::Segwit],
+        ..Default::default()
     };
 
     let json: GetBlockTemplate =
         node1.client.get_block_template(&options).expect("get_block_template RPC failed");
     let model: Result<mtype::GetBlockTemplate, GetBlockTemplateError> = json.into_model();
-    model.unwrap();
+    let template = model.unwrap();
+
+    let best_block_hash = node1.client.best_block_hash().expect("best_block_hash");
+    let block_count = node1.client.get_block_count().expect("getblockcount").0;
+    assert_eq!(template.previous_block_hash, best_block_hash);
+    assert_eq!(u64::from(template.height), block_count + 1);
+    // Regtest uses the minimum difficulty and the full 50 BTC subsidy for early blocks.
+    assert_eq!(template.bits, CompactTarget::from_consensus(0x207fffff));
+    assert_eq!(template.target, Target::from_compact(template.bits));
+    assert_eq!(template.coinbase_value, SignedAmount::from_btc(50.0).unwrap());
 }
 
 #[test]
-fn mining__get_mining_info() {
+fn mining__get_mining_info__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
     let json: GetMiningInfo = node.client.get_mining_info().expect("rpc");
+    let model: Result<mtype::GetMiningInfo, GetMiningInfoError> = json.into_model();
+    let model = model.unwrap();
 
-    // Up to v28 (i.e., not 29_0) there is no error converting into model.
-    #[cfg(feature = "v28_and_below")]
-    let _: mtype::GetMiningInfo = json.into_model();
-
-    // v29 onwards
-    #[cfg(not(feature = "v28_and_below"))]
-    {
-        let model: Result<mtype::GetMiningInfo, GetMiningInfoError> = json.into_model();
-        model.unwrap();
-    }
+    assert_eq!(model.chain, Network::Regtest);
 }
 
 #[test]
 fn mining__get_network_hash_ps() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
-    let _ = node.client.get_network_hash_ps().expect("rpc");
+    let hash_ps = node.client.get_network_hash_ps(None, None).expect("getnetworkhashps");
+    assert!(hash_ps >= 0.0);
+
+    // Since the last difficulty change, ending at an explicit height.
+    let hash_ps = node.client.get_network_hash_ps(Some(-1), Some(50)).expect("getnetworkhashps");
+    assert!(hash_ps >= 0.0);
 }
 
 #[test]
@@ -81,6 +84,42 @@ fn mining__prioritise_transaction() {
     assert!(json) // According to docs always returns true.
 }
 
+#[test]
+fn mining__prioritise_transaction__block_template_order() {
+    // Requires connected nodes otherwise `getblocktemplate` errors.
+    let (node1, _node2, _node3) = integration_test::three_node_network();
+    node1.fund_wallet();
+    // Mature a second coinbase so the two transactions below do not depend on each other.
+    node1.mine_a_block();
+
+    let (_, txid_a) = node1.create_mempool_transaction();
+    let (_, txid_b) = node1.create_mempool_transaction();
+
+    let template_txids = || -> Vec<Txid> {
+        let json: GetBlockTemplate =
+            node1.client.get_block_template(&TemplateRequest::segwit()).expect("getblocktemplate");
+        let template: mtype::GetBlockTemplate = json.into_model().expect("into_model");
+        template.transactions.iter().map(|tx| tx.txid).collect()
+    };
+
+    // Prioritising B puts it first in the template.
+    let prioritised = node1
+        .client
+        .prioritise_transaction(&txid_b, SignedAmount::from_sat(1_000_000))
+        .expect("prioritisetransaction");
+    assert!(prioritised);
+    let txids = template_txids();
+    assert_eq!(txids, vec![txid_b, txid_a]);
+
+    // Deprioritising A below the minimum fee rate drops it from the template.
+    node1
+        .client
+        .prioritise_transaction(&txid_a, SignedAmount::from_sat(-1_000_000))
+        .expect("prioritisetransaction");
+    let txids = template_txids();
+    assert_eq!(txids, vec![txid_b]);
+}
+
 #[test]
 #[cfg(feature = "TODO")] // This test is flaky - no clue why.
 fn mining__submit_block() {
@@ -92,7 +131,7 @@ fn mining__submit_block() {
     node2.mine_a_block();
     node3.mine_a_block();
 
-    let options = TemplateRequest { rules: vec![TemplateRules::Segwit] };
+    let options = TemplateRequest::segwit();
     let json: GetBlockTemplate =
         node1.client.get_block_template(&options).expect("getblocktemplate");
     let template: mtype::GetBlockTemplate = json.into_model().unwrap();
@@ -218,6 +257,10 @@ fn mining__submit_block_with_dummy_coinbase(node: &Node, bt: &mtype::GetBlockTem
 #[test]
 #[cfg(not(feature = "v17"))]
 fn mining__submit_header() {
+    use bitcoin::hashes::Hash as _;
+    use bitcoin::BlockHash;
+    use node::client::RpcErrorCode;
+
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
     node.mine_a_block();
@@ -235,4 +278,9 @@ fn mining__submit_header() {
     }
 
     let _: () = node.client.submit_header(&header).expect("submitheader");
+
+    // A header that does not connect to a known block is rejected.
+    header.prev_blockhash = BlockHash::all_zeros();
+    let err = node.client.submit_header(&header).expect_err("submitheader should fail");
+    assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::VerifyError));
 }
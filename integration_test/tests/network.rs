@@ -4,6 +4,9 @@
 
 #![allow(non_snake_case)] // Test names intentionally use double underscore.
 
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
 use integration_test::{Node, NodeExt as _, Wallet};
 use node::vtype::*; // All the version specific types.
 use node::{mtype, AddNodeCommand, SetBanCommand};
@@ -24,12 +27,34 @@ fn network__add_node() {
     let _: () = node.client.add_node(dummy_peer, AddNodeCommand::Remove).expect("addnode remove");
 }
 
+#[test]
+fn network__add_node__onetry() {
+    let (node1, _node2, node3) = integration_test::three_node_network();
+    let before = node1.peers_connected();
+    let node1_addr = node1.params.p2p_socket.expect("node1 listens for p2p").to_string();
+
+    let _: () = node3.client.add_node(&node1_addr, AddNodeCommand::OneTry).expect("addnode onetry");
+
+    // The connection attempt happens asynchronously.
+    let connected = integration_test::wait_for(|| node1.peers_connected() > before);
+    assert!(connected, "node3 did not connect to node1 after addnode onetry");
+
+    // A onetry connection is transient and is not added to the added node list.
+    let json: GetAddedNodeInfo = node3.client.get_added_node_info(None).expect("getaddednodeinfo");
+    assert!(json.0.iter().all(|node| node.added_node != node1_addr));
+
+    let _: () = node3.client.add_node(&node1_addr, AddNodeCommand::Add).expect("addnode add");
+    let json: GetAddedNodeInfo = node3.client.get_added_node_info(None).expect("getaddednodeinfo");
+    assert!(json.0.iter().any(|node| node.added_node == node1_addr));
+}
+
 #[test]
 fn network__clear_banned() {
     let node = Node::with_wallet(Wallet::None, &[]);
     let dummy_subnet = "192.0.2.2";
 
-    let _: () = node.client.set_ban(dummy_subnet, SetBanCommand::Add).expect("setban add");
+    let _: () =
+        node.client.set_ban(dummy_subnet, SetBanCommand::Add, None, None).expect("setban add");
     let _: () = node.client.clear_banned().expect("clearbanned");
 }
 
@@ -44,9 +69,59 @@ fn network__disconnect_node() {
 }
 
 #[test]
-fn network__get_added_node_info() {
-    let node = Node::with_wallet(Wallet::None, &[]);
-    let _: GetAddedNodeInfo = node.client.get_added_node_info().expect("getaddednodeinfo");
+fn network__disconnect_node_by_id() {
+    let (_node1, node2, _node3) = integration_test::three_node_network();
+    let before = node2.peers_connected();
+
+    let peers = node2.client.get_peer_info().expect("getpeerinfo");
+    let peer = peers.0.first().expect("should have at least one peer");
+
+    let _: () = node2.client.disconnect_node_by_id(u64::from(peer.id)).expect("disconnectnode");
+
+    // Disconnection happens asynchronously.
+    let disconnected = integration_test::wait_for(|| node2.peers_connected() < before);
+    assert!(disconnected, "peer count did not drop after disconnectnode");
+}
+
+#[test]
+fn network__disconnect_node_by_address() {
+    let (_node1, node2, _node3) = integration_test::three_node_network();
+
+    let peers = node2.client.get_peer_info().expect("getpeerinfo");
+    let peer = peers.0.first().expect("should have at least one peer");
+
+    let _: () = node2.client.disconnect_node_by_address(&peer.address).expect("disconnectnode");
+}
+
+#[test]
+fn network__get_added_node_info__modelled() {
+    let (node1, _node2, node3) = integration_test::three_node_network();
+    let json: GetAddedNodeInfo = node3.client.get_added_node_info(None).expect("getaddednodeinfo");
+    assert!(json.0.is_empty());
+
+    let node1_socket = node1.params.p2p_socket.expect("node1 listens for p2p");
+    let node1_addr = node1_socket.to_string();
+    node3.client.add_node(&node1_addr, AddNodeCommand::Add).expect("addnode add");
+
+    // Added nodes are connected to asynchronously.
+    let connected = integration_test::wait_for(|| {
+        let json = node3.client.get_added_node_info(Some(&node1_addr)).expect("getaddednodeinfo");
+        json.0.iter().any(|node| node.connected)
+    });
+    assert!(connected, "node3 did not connect to the added node");
+
+    let json: GetAddedNodeInfo =
+        node3.client.get_added_node_info(Some(&node1_addr)).expect("getaddednodeinfo");
+    let model: Result<mtype::GetAddedNodeInfo, GetAddedNodeInfoError> = json.into_model();
+    let added = model.unwrap();
+
+    assert_eq!(added.0.len(), 1);
+    let node = &added.0[0];
+    assert_eq!(node.added_node, node1_addr);
+    assert!(node.connected);
+    assert_eq!(node.addresses.len(), 1);
+    assert_eq!(node.addresses[0].address, mtype::NodeAddressKind::Socket(node1_socket.into()));
+    assert_eq!(node.addresses[0].connected, "outbound");
 }
 
 #[test]
@@ -69,9 +144,14 @@ fn network__get_connection_count() {
 }
 
 #[test]
-fn network__get_net_totals() {
+fn network__get_net_totals__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
-    let _: GetNetTotals = node.client.get_net_totals().expect("getnettotals");
+    let json: GetNetTotals = node.client.get_net_totals().expect("getnettotals");
+    let model: mtype::GetNetTotals = json.into_model();
+
+    // No upload target is set by default.
+    assert_eq!(model.upload_target.target, 0);
+    assert!(model.time.as_secs() > 0);
 }
 
 #[test]
@@ -87,12 +167,13 @@ fn network__get_network_info__modelled() {
 
 #[test]
 #[cfg(not(feature = "v17"))]
-fn network__get_node_addresses() {
+fn network__get_node_addresses__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
 
     #[cfg(feature = "v20_and_below")]
     {
-        let _: GetNodeAddresses = node.client.get_node_addresses().expect("getnodeaddresses");
+        let json: GetNodeAddresses = node.client.get_node_addresses().expect("getnodeaddresses");
+        let _: mtype::GetNodeAddresses = json.into_model();
     }
     #[cfg(not(feature = "v20_and_below"))]
     {
@@ -104,11 +185,15 @@ fn network__get_node_addresses() {
 
         assert_eq!(json.0[0].address, peer_address);
         assert_eq!(json.0[0].port, peer_port);
+
+        let model: mtype::GetNodeAddresses = json.into_model();
+        let expected: std::net::SocketAddr = "1.2.3.4:1234".parse().unwrap();
+        assert_eq!(model.0[0].address, mtype::NodeAddressKind::Socket(expected));
     }
 }
 
 #[test]
-fn network__get_peer_info() {
+fn network__get_peer_info__modelled() {
     get_peer_info_one_node_network();
     get_peer_info_three_node_network();
 }
@@ -131,6 +216,13 @@ fn get_peer_info_three_node_network() {
     // This verifies that we re-exported the correct `PeerInfo` type at the module level.
     let _: PeerInfo = json.0[0];
 
+    let model: Result<mtype::GetPeerInfo, PeerInfoError> = json.into_model();
+    let peers = model.unwrap();
+    match peers.0[0].address {
+        mtype::NodeAddressKind::Socket(socket) => assert!(socket.ip().is_loopback()),
+        ref other => panic!("expected a socket address, got {:?}", other),
+    }
+
     // FIXME: Fails if we use equal to 2 ???
     assert!(node1.peers_connected() >= 1);
     assert!(node2.peers_connected() >= 1);
@@ -142,15 +234,60 @@ fn network__list_banned() {
     let node = Node::with_wallet(Wallet::None, &[]);
     let dummy_subnet = "192.0.2.5/32";
 
-    node.client.set_ban(dummy_subnet, SetBanCommand::Add).expect("setban add");
+    node.client.set_ban(dummy_subnet, SetBanCommand::Add, None, None).expect("setban add");
     let json: ListBanned = node.client.list_banned().expect("listbanned");
     assert!(json.0.iter().any(|item| item.address == dummy_subnet));
 
-    node.client.set_ban(dummy_subnet, SetBanCommand::Remove).expect("setban remove");
+    node.client.set_ban(dummy_subnet, SetBanCommand::Remove, None, None).expect("setban remove");
     let json: ListBanned = node.client.list_banned().expect("listbanned");
     assert!(json.0.iter().all(|item| item.address != dummy_subnet));
 }
 
+#[test]
+fn network__list_banned__modelled() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    let subnet = "192.0.2.8/32";
+    let until = 2_000_000_000;
+
+    node.client
+        .set_ban(subnet, SetBanCommand::Add, Some(until), Some(true))
+        .expect("setban add absolute");
+
+    let json: ListBanned = node.client.list_banned().expect("listbanned");
+    let model: mtype::ListBanned = json.into_model();
+
+    let expected =
+        mtype::BannedSubnet::Ip { address: Ipv4Addr::new(192, 0, 2, 8).into(), prefix_len: 32 };
+    let banned = model.0.iter().find(|item| item.address == expected).expect("banned");
+    assert_eq!(banned.banned_until, Duration::from_secs(until));
+    assert!(banned.ban_created < banned.banned_until);
+}
+
+#[test]
+fn network__set_ban__bantime() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    let relative_subnet = "192.0.2.6/32";
+    let absolute_subnet = "192.0.2.7/32";
+    let until = 2_000_000_000;
+
+    node.client
+        .set_ban(relative_subnet, SetBanCommand::Add, Some(3600), None)
+        .expect("setban add relative");
+    node.client
+        .set_ban(absolute_subnet, SetBanCommand::Add, Some(u64::from(until)), Some(true))
+        .expect("setban add absolute");
+
+    let json: ListBanned = node.client.list_banned().expect("listbanned");
+    let relative = json.0.iter().find(|item| item.address == relative_subnet).expect("banned");
+    assert_eq!(relative.banned_until - relative.ban_created, 3600);
+    let absolute = json.0.iter().find(|item| item.address == absolute_subnet).expect("banned");
+    assert_eq!(absolute.banned_until, until);
+
+    node.client.set_ban(absolute_subnet, SetBanCommand::Remove, None, None).expect("setban remove");
+    let json: ListBanned = node.client.list_banned().expect("listbanned");
+    assert!(json.0.iter().all(|item| item.address != absolute_subnet));
+}
+
 #[test]
 fn network__ping() {
     let node = Node::with_wallet(Wallet::None, &[]);
@@ -162,18 +299,25 @@ fn network__set_ban() {
     let node = Node::with_wallet(Wallet::None, &[]);
     let dummy_subnet = "192.0.2.3";
 
-    let _: () = node.client.set_ban(dummy_subnet, SetBanCommand::Add).expect("setban add");
-    let _: () = node.client.set_ban(dummy_subnet, SetBanCommand::Remove).expect("setban remove");
+    let _: () =
+        node.client.set_ban(dummy_subnet, SetBanCommand::Add, None, None).expect("setban add");
+    let _: () = node
+        .client
+        .set_ban(dummy_subnet, SetBanCommand::Remove, None, None)
+        .expect("setban remove");
 }
 
 #[test]
 fn network__set_network_active() {
     let node = Node::with_wallet(Wallet::None, &[]);
-    let json: SetNetworkActive =
-        node.client.set_network_active(false).expect("setnetworkactive false");
-    assert!(!json.0);
 
-    let json: SetNetworkActive =
-        node.client.set_network_active(true).expect("setnetworkactive true");
-    assert!(json.0);
+    let active = node.client.set_network_active(false).expect("setnetworkactive false");
+    assert!(!active);
+    let json: GetNetworkInfo = node.client.get_network_info().expect("getnetworkinfo");
+    assert!(!json.network_active);
+
+    let active = node.client.set_network_active(true).expect("setnetworkactive true");
+    assert!(active);
+    let json: GetNetworkInfo = node.client.get_network_info().expect("getnetworkinfo");
+    assert!(json.network_active);
 }
@@ -9,11 +9,13 @@ use bitcoin::consensus::encode;
 use bitcoin::hex::FromHex as _;
 use bitcoin::opcodes::all::*;
 use bitcoin::{
-    absolute, consensus, hex, psbt, script, transaction, Amount, ScriptBuf, Transaction, TxOut,
+    absolute, consensus, hex, psbt, script, transaction, Amount, FeeRate, OutPoint, ScriptBuf,
+    Sequence, Transaction, TxOut, Txid,
 };
 use integration_test::{Node, NodeExt as _, Wallet};
+use node::client::client_sync;
 use node::vtype::*;
-use node::{mtype, Input, Output}; // All the version specific types.
+use node::{mtype, CreateRawTransactionBuilder, Input, Output, PrevTx, SighashType}; // All the version specific types.
 
 #[test]
 #[cfg(not(feature = "v17"))] // analyzepsbt was added in v0.18.
@@ -21,10 +23,32 @@ fn raw_transactions__analyze_psbt__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
+    // Without UTXO information the PSBT needs to go to an updater.
     let psbt = create_a_psbt(&node);
     let json: AnalyzePsbt = node.client.analyze_psbt(&psbt).expect("analyzepsbt");
     let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
-    model.unwrap();
+    let analysis = model.unwrap();
+    assert_eq!(analysis.next, mtype::PsbtRole::Updater);
+    assert!(analysis.fee.is_none());
+
+    // With UTXO information but no signatures the PSBT needs to go to a signer.
+    let json = node.client.wallet_process_psbt(&psbt, Some(false)).expect("walletprocesspsbt");
+    let updated = json.into_model().unwrap().psbt;
+    let json: AnalyzePsbt = node.client.analyze_psbt(&updated).expect("analyzepsbt");
+    let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
+    let analysis = model.unwrap();
+    assert_eq!(analysis.next, mtype::PsbtRole::Signer);
+    assert_eq!(analysis.inputs[0].next, Some(mtype::PsbtRole::Signer));
+    // `create_a_psbt` pays a 1000 sat fee.
+    assert_eq!(analysis.fee, Some(Amount::from_sat(1000)));
+    assert!(analysis.estimated_fee_rate.is_some());
+
+    // Once signed and finalized only the extraction is left.
+    let json = node.client.wallet_process_psbt(&psbt, Some(true)).expect("walletprocesspsbt");
+    let signed = json.into_model().unwrap().psbt;
+    let json: AnalyzePsbt = node.client.analyze_psbt(&signed).expect("analyzepsbt");
+    let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
+    assert_eq!(model.unwrap().next, mtype::PsbtRole::Extractor);
 }
 
 #[test]
@@ -80,6 +104,46 @@ fn raw_transactions__combine_psbt__modelled() {
     assert_eq!(combined.0, psbt)
 }
 
+#[test]
+fn raw_transactions__combine_psbt__partial_signatures() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_, _, txid_a, tx_out_a, vout_a) = create_utxo(&node);
+    let (_, _, txid_b, tx_out_b, vout_b) = create_utxo(&node);
+
+    let inputs = vec![
+        Input { txid: txid_a, vout: vout_a, sequence: None },
+        Input { txid: txid_b, vout: vout_b, sequence: None },
+    ];
+    let fee = Amount::from_sat(1000);
+    let address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(address, tx_out_a.value + tx_out_b.value - fee)];
+
+    let json: CreatePsbt = node.client.create_psbt(&inputs, &outputs).expect("createpsbt");
+    let unsigned = json.into_model().unwrap().0;
+
+    let json = node.client.wallet_process_psbt(&unsigned, Some(true)).expect("walletprocesspsbt");
+    let signed = json.into_model().unwrap().psbt;
+
+    // Split the signatures up as if two different signers had each signed one input.
+    let mut first = signed.clone();
+    first.inputs[1] = unsigned.inputs[1].clone();
+    let mut second = signed.clone();
+    second.inputs[0] = unsigned.inputs[0].clone();
+
+    let json: CombinePsbt = node.client.combine_psbt(&[first, second]).expect("combinepsbt");
+    let model: Result<mtype::CombinePsbt, psbt::PsbtParseError> = json.into_model();
+    let combined = model.unwrap().0;
+
+    assert!(combined.inputs.iter().all(|input| input.final_script_witness.is_some()));
+    assert_eq!(combined, signed);
+
+    // Fewer than two PSBTs is rejected before calling Core.
+    let err = node.client.combine_psbt(std::slice::from_ref(&signed)).unwrap_err();
+    assert!(matches!(err, client_sync::Error::InvalidArgument(_)));
+}
+
 #[test]
 fn raw_transactions__combine_raw_transaction__modelled() {
     let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
@@ -108,9 +172,29 @@ fn raw_transactions__convert_to_psbt__modelled() {
 
     let tx = create_a_raw_transaction(&node);
 
-    let json: ConvertToPsbt = node.client.convert_to_psbt(&tx).expect("converttopsbt");
+    let json: ConvertToPsbt = node.client.convert_to_psbt(&tx, None, None).expect("converttopsbt");
     let model: Result<mtype::ConvertToPsbt, psbt::PsbtParseError> = json.into_model();
-    model.unwrap();
+    let psbt = model.unwrap().0;
+    assert_eq!(psbt.unsigned_tx, tx);
+
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&tx, &[], None)
+        .expect("signrawtransactionwithwallet");
+    let signed = json.into_model().unwrap().tx;
+    assert!(!signed.input[0].witness.is_empty());
+
+    // Signed transactions are rejected unless signature data is permitted.
+    assert!(node.client.convert_to_psbt(&signed, Some(false), None).is_err());
+
+    let json: ConvertToPsbt =
+        node.client.convert_to_psbt(&signed, Some(true), Some(true)).expect("converttopsbt");
+    let model: Result<mtype::ConvertToPsbt, psbt::PsbtParseError> = json.into_model();
+    let psbt = model.unwrap().0;
+    // The signature data is dropped.
+    assert_eq!(psbt.unsigned_tx, tx);
+    assert!(psbt.inputs[0].final_script_witness.is_none());
+    assert!(psbt.inputs[0].partial_sigs.is_empty());
 }
 
 #[test]
@@ -127,6 +211,37 @@ fn raw_transactions__create_raw_transaction__modelled() {
     create_sign_send(&node);
 }
 
+#[test]
+fn raw_transactions__create_raw_transaction__builder() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_, txid) = node.create_mempool_transaction();
+    let address = node.client.new_address().expect("failed to create new address");
+    let amount = Amount::from_sat(100_001);
+    let data = [0xca, 0xfe];
+    let locktime = absolute::LockTime::from_height(100).unwrap();
+
+    let builder = CreateRawTransactionBuilder::new()
+        .input(OutPoint { txid, vout: 0 })
+        .input_with_sequence(OutPoint { txid, vout: 1 }, Sequence::from_consensus(10))
+        .output(address.clone(), amount)
+        .data(&data)
+        .locktime(locktime)
+        .replaceable(true);
+    let json: CreateRawTransaction =
+        node.client.create_raw_transaction_from_builder(&builder).expect("createrawtransaction");
+    let tx = json.transaction().unwrap();
+
+    assert_eq!(tx.lock_time, locktime);
+    assert_eq!(tx.input[0].previous_output, OutPoint { txid, vout: 0 });
+    assert_eq!(tx.input[0].sequence, Sequence::ENABLE_RBF_NO_LOCKTIME);
+    assert_eq!(tx.input[1].sequence, Sequence::from_consensus(10));
+    assert_eq!(tx.output[0], TxOut { value: amount, script_pubkey: address.script_pubkey() });
+    assert_eq!(tx.output[1].value, Amount::ZERO);
+    assert_eq!(tx.output[1].script_pubkey, ScriptBuf::new_op_return(data));
+}
+
 // Notes on testing decoding of PBST.
 //
 // - `bip32_derivs` field in the input list of the decoded PSBT changes shape a bunch of times.
@@ -180,6 +295,34 @@ fn raw_transactions__decode_psbt__modelled() {
     // TODO: Add a taproot field and test it with v24
 }
 
+#[test]
+fn raw_transactions__decode_psbt__fee_and_witness_utxo() {
+    let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+
+    // Fill in the UTXO data so that Core can calculate the fee.
+    let psbt = create_a_psbt(&node);
+    let json = node.client.wallet_process_psbt(&psbt, Some(false)).expect("walletprocesspsbt");
+    let psbt = json.into_model().unwrap().psbt;
+
+    let json: DecodePsbt = node.client.decode_psbt(&psbt.to_string()).expect("decodepsbt");
+    let model: Result<mtype::DecodePsbt, DecodePsbtError> = json.clone().into_model();
+    let decoded = model.unwrap();
+    // `create_a_psbt` pays a 1000 sat fee.
+    assert_eq!(decoded.fee, Some(Amount::from_sat(1000)));
+    assert!(decoded.psbt.inputs[0].witness_utxo.is_some());
+
+    // A malformed amount deep in the structure is reported with the index of the input.
+    let mut json = json;
+    json.inputs[0].witness_utxo.as_mut().expect("witness_utxo").amount = -1.0;
+    let model: Result<mtype::DecodePsbt, DecodePsbtError> = json.into_model();
+    let err = model.unwrap_err();
+    assert!(matches!(err, DecodePsbtError::Inputs(0, _)));
+    assert!(err.to_string().contains("input at index 0"));
+    let source = std::error::Error::source(&err).expect("input error");
+    assert!(source.to_string().contains("`witness_utxo`"));
+}
+
 #[test]
 fn raw_transactions__decode_raw_transaction__modelled() {
     let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
@@ -253,14 +396,53 @@ fn raw_transactions__finalize_psbt__modelled() {
     // Create a PSBT and call finalizepsbt directly without signing.
     // This still exercises the RPC and model; it should report complete=false and return the PSBT.
     let psbt = create_a_psbt(&node);
-    let json: FinalizePsbt = node.client.finalize_psbt(&psbt).expect("finalizepsbt");
+    let json: FinalizePsbt = node.client.finalize_psbt(&psbt, None).expect("finalizepsbt");
+    assert!(json.hex.is_none());
     let model: Result<mtype::FinalizePsbt, FinalizePsbtError> = json.into_model();
     let finalized = model.unwrap();
 
     assert!(!finalized.complete);
+    assert!(finalized.tx.is_none());
     assert_eq!(finalized.psbt, Some(psbt));
 }
 
+#[test]
+fn raw_transactions__finalize_psbt__complete() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // `walletprocesspsbt` signs and finalizes the PSBT so this also covers finalizing an already
+    // finalized PSBT.
+    let psbt = create_a_psbt(&node);
+    let signed = node
+        .client
+        .wallet_process_psbt(&psbt, None)
+        .expect("walletprocesspsbt")
+        .into_model()
+        .expect("WalletProcessPsbt into model")
+        .psbt;
+    let want = signed.clone().extract_tx().expect("extract tx");
+
+    let json: FinalizePsbt = node.client.finalize_psbt(&signed, None).expect("finalizepsbt");
+    assert!(json.psbt.is_none());
+    let model: Result<mtype::FinalizePsbt, FinalizePsbtError> = json.into_model();
+    let finalized = model.unwrap();
+
+    assert!(finalized.complete);
+    assert!(finalized.psbt.is_none());
+    assert_eq!(finalized.tx.expect("hex present").compute_txid(), want.compute_txid());
+
+    // Without extracting Core returns the finalized PSBT instead of the transaction.
+    let json: FinalizePsbt = node.client.finalize_psbt(&signed, Some(false)).expect("finalizepsbt");
+    assert!(json.hex.is_none());
+    let model: Result<mtype::FinalizePsbt, FinalizePsbtError> = json.into_model();
+    let finalized = model.unwrap();
+
+    assert!(finalized.complete);
+    assert!(finalized.tx.is_none());
+    assert_eq!(finalized.psbt, Some(signed));
+}
+
 #[test]
 fn raw_transactions__fund_raw_transaction__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
@@ -268,6 +450,37 @@ fn raw_transactions__fund_raw_transaction__modelled() {
     create_fund_sign_send(&node);
 }
 
+#[test]
+fn raw_transactions__fund_raw_transaction__fee_rate() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_addr, _tx, txid, _tx_out, vout) = create_utxo(&node);
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, Amount::from_sat(100_000))];
+    let json: CreateRawTransaction =
+        node.client.create_raw_transaction(&inputs, &outputs).expect("createrawtransaction");
+    let tx = json.transaction().unwrap();
+
+    let fee_rate = FeeRate::from_sat_per_vb(10).unwrap();
+    let json: FundRawTransaction =
+        node.client.fund_raw_transaction(&tx, Some(fee_rate)).expect("fundrawtransaction");
+    let model: Result<mtype::FundRawTransaction, FundRawTransactionError> = json.into_model();
+    let funded = model.unwrap();
+    assert!(funded.change_position.is_some());
+
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&funded.tx, &[], None)
+        .expect("signrawtransactionwithwallet");
+    let signed = json.into_model().unwrap().tx;
+
+    // Core funds using the maximum signature size so the actual rate can only be slightly higher.
+    let rate = funded.fee.to_sat() as f64 / signed.vsize() as f64;
+    assert!((10.0..10.5).contains(&rate), "fee rate {} sat/vB", rate);
+}
+
 #[test]
 fn raw_transactions__send_raw_transaction__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
@@ -277,6 +490,8 @@ fn raw_transactions__send_raw_transaction__modelled() {
 
 #[test]
 fn raw_transactions__get_raw_transaction__modelled() {
+    use bitcoin::hashes::Hash as _;
+
     let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
     node.fund_wallet();
 
@@ -305,6 +520,68 @@ fn raw_transactions__get_raw_transaction__modelled() {
         .expect("getrawtransaction verbose")
         .into_model()
         .unwrap();
+
+    // Get raw transactions using a batch request, including an unknown txid.
+    let (_, tx) = node.create_mined_transaction();
+    let unknown = Txid::all_zeros();
+    let results = node
+        .client
+        .batch_get_raw_transaction(&[tx.compute_txid(), unknown, txid])
+        .expect("getrawtransaction batch");
+    let mut results = results.into_iter();
+    let got = results.next().unwrap().expect("known txid").transaction().unwrap();
+    assert_eq!(got.compute_txid(), tx.compute_txid());
+    assert!(results.next().unwrap().is_err());
+    let got = results.next().unwrap().expect("known txid").transaction().unwrap();
+    assert_eq!(got.compute_txid(), txid);
+    assert!(results.next().is_none());
+}
+
+#[test]
+#[cfg(not(feature = "v24_and_below"))]
+fn raw_transactions__get_raw_transaction_verbose_two__modelled() {
+    let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+    let (_, tx) = node.create_mined_transaction();
+
+    let json: GetRawTransactionVerboseTwo = node
+        .client
+        .get_raw_transaction_verbose_two(tx.compute_txid())
+        .expect("getrawtransaction verbosity 2");
+    let model: Result<mtype::GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError> =
+        json.into_model();
+    let got = model.unwrap();
+
+    assert_eq!(got.transaction, tx);
+    assert_eq!(got.prevouts.len(), tx.input.len());
+
+    // Each prevout should match the output it spends.
+    let mut input_value = Amount::ZERO;
+    for (txin, prevout) in tx.input.iter().zip(&got.prevouts) {
+        let prevout = prevout.as_ref().expect("non-coinbase input has a prevout");
+        let outpoint = txin.previous_output;
+        let prev_tx = node
+            .client
+            .get_raw_transaction(outpoint.txid)
+            .expect("getrawtransaction")
+            .transaction()
+            .unwrap();
+        let spent = &prev_tx.output[outpoint.vout as usize];
+        assert_eq!(prevout.value, spent.value);
+        assert_eq!(prevout.script_pubkey, spent.script_pubkey);
+        input_value += prevout.value;
+    }
+
+    let output_value = tx.output.iter().map(|txout| txout.value).sum();
+    assert_eq!(got.fee, Some(input_value - output_value));
+
+    // The coinbase input is kept, without a prevout.
+    let block_hash = node.client.best_block_hash().expect("best_block_hash");
+    let coinbase = node.client.get_block(block_hash).expect("getblock").txdata[0].compute_txid();
+    let json: GetRawTransactionVerboseTwo =
+        node.client.get_raw_transaction_verbose_two(coinbase).expect("getrawtransaction");
+    let got = json.into_model().unwrap();
+    assert_eq!(got.prevouts, vec![None]);
 }
 
 #[test]
@@ -322,6 +599,31 @@ fn raw_transactions__join_psbts__modelled() {
     let join_psbts = model.unwrap();
 
     assert_eq!(join_psbts.0.inputs.len(), psbt1.inputs.len() + psbt2.inputs.len());
+
+    // Core shuffles the inputs and outputs so compare them without assuming any ordering.
+    let tx = &join_psbts.0.unsigned_tx;
+    let mut got_inputs: Vec<_> = tx.input.iter().map(|txin| txin.previous_output).collect();
+    let mut want_inputs: Vec<_> = psbt1
+        .unsigned_tx
+        .input
+        .iter()
+        .chain(psbt2.unsigned_tx.input.iter())
+        .map(|txin| txin.previous_output)
+        .collect();
+    got_inputs.sort();
+    want_inputs.sort();
+    assert_eq!(got_inputs, want_inputs);
+
+    let mut got_outputs = tx.output.clone();
+    let mut want_outputs: Vec<_> =
+        psbt1.unsigned_tx.output.iter().chain(psbt2.unsigned_tx.output.iter()).cloned().collect();
+    got_outputs.sort();
+    want_outputs.sort();
+    assert_eq!(got_outputs, want_outputs);
+
+    // PSBTs that spend the same input can not be joined.
+    let res = node.client.join_psbts(&[psbt1.clone(), psbt1]);
+    assert!(res.is_err());
 }
 
 #[test]
@@ -337,7 +639,79 @@ fn raw_transactions__sign_raw_transaction__modelled() {
 fn raw_transactions__sign_raw_transaction_with_key__modelled() {
     let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
     node.fund_wallet();
-    create_sign_with_key_send(&node)
+    create_sign_with_key_send(&node, None)
+}
+
+#[test]
+#[cfg(feature = "v22_and_below")] // In v23 dumpprivkey no longer works.
+fn raw_transactions__sign_raw_transaction_with_key__sighash_type() {
+    let node = Node::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+    create_sign_with_key_send(&node, Some(SighashType::SinglePlusAnyoneCanPay))
+}
+
+#[test]
+fn raw_transactions__sign_raw_transaction_with_wallet__prev_txs() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // Sign a parent but do not broadcast it so the node does not know about its outputs.
+    let (_addr, _tx, txid, tx_out, vout) = create_utxo(&node);
+    let fee = Amount::from_sat(1000);
+    let parent_address = node.client.new_address().expect("failed to create new address");
+    let parent_amount = tx_out.value - fee;
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let outputs = vec![Output::new(parent_address.clone(), parent_amount)];
+    let json: CreateRawTransaction =
+        node.client.create_raw_transaction(&inputs, &outputs).expect("createrawtransaction");
+    let parent = node
+        .client
+        .sign_raw_transaction_with_wallet(&json.transaction().unwrap(), &[], None)
+        .expect("signrawtransactionwithwallet")
+        .into_model()
+        .unwrap()
+        .tx;
+    let parent_txid = parent.compute_txid();
+
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let inputs = vec![Input { txid: parent_txid, vout: 0, sequence: None }];
+    let outputs = vec![Output::new(spend_address, parent_amount - fee)];
+    let json: CreateRawTransaction =
+        node.client.create_raw_transaction(&inputs, &outputs).expect("createrawtransaction");
+    let child = json.transaction().unwrap();
+
+    // Without the previous output the wallet cannot sign.
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&child, &[], None)
+        .expect("signrawtransactionwithwallet");
+    let model: Result<mtype::SignRawTransactionWithWallet, SignRawTransactionError> =
+        json.into_model();
+    let unsigned = model.unwrap();
+    assert!(!unsigned.complete);
+    assert_eq!(unsigned.errors.len(), 1);
+
+    let prev_tx = PrevTx {
+        txid: parent_txid,
+        vout: 0,
+        script_pub_key: parent_address.script_pubkey(),
+        redeem_script: None,
+        witness_script: None,
+        amount: Some(parent_amount),
+    };
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&child, &[prev_tx], Some(SighashType::All))
+        .expect("signrawtransactionwithwallet");
+    let model: Result<mtype::SignRawTransactionWithWallet, SignRawTransactionError> =
+        json.into_model();
+    let signed = model.unwrap();
+    assert!(signed.complete);
+    assert!(signed.errors.is_empty());
+
+    // This proves we did everything correctly.
+    node.client.send_raw_transaction(&parent).expect("sendrawtransaction parent");
+    node.client.send_raw_transaction(&signed.tx).expect("sendrawtransaction child");
 }
 
 // FIXME: Doesn't work for v26 for some reason.
@@ -399,17 +773,16 @@ fn raw_transactions__test_mempool_accept__modelled() {
     let tx = create_a_raw_transaction(&node);
 
     // Sign (but don't broadcast).
-    let signed: SignRawTransactionWithWallet =
-        node.client.sign_raw_transaction_with_wallet(&tx).expect("signrawtransactionwithwallet");
+    let signed: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&tx, &[], None)
+        .expect("signrawtransactionwithwallet");
     let signed_model: mtype::SignRawTransactionWithWallet =
         signed.into_model().expect("SignRawTransaction into model");
     let signed_tx = signed_model.tx;
 
     // Call testmempoolaccept with the valid (not yet broadcast) transaction.
-    let json: TestMempoolAccept = node
-        .client
-        .test_mempool_accept(std::slice::from_ref(&signed_tx))
-        .expect("testmempoolaccept");
+    let json = test_mempool_accept(&node, &signed_tx);
     #[cfg(feature = "v20_and_below")]
     type TestMempoolAcceptError = hex::HexToArrayError;
     let model: Result<mtype::TestMempoolAccept, TestMempoolAcceptError> = json.into_model();
@@ -419,6 +792,56 @@ fn raw_transactions__test_mempool_accept__modelled() {
     let res = &test_mempool.results[0];
     assert_eq!(res.txid, signed_tx.compute_txid());
     assert!(res.allowed, "fresh signed tx should be allowed");
+    assert!(res.reject_reason.is_none());
+    #[cfg(not(feature = "v20_and_below"))]
+    assert_eq!(res.fees.as_ref().expect("fees").base, Amount::from_sat(1000));
+
+    // A max fee rate below the transaction's fee rate gets it rejected.
+    #[cfg(not(feature = "v18_and_below"))]
+    {
+        let max_fee_rate = Some(bitcoin::FeeRate::from_sat_per_vb_u32(1));
+        let json: TestMempoolAccept = node
+            .client
+            .test_mempool_accept(std::slice::from_ref(&signed_tx), max_fee_rate)
+            .expect("testmempoolaccept");
+        let model: Result<mtype::TestMempoolAccept, TestMempoolAcceptError> = json.into_model();
+        let res = &model.unwrap().results[0];
+        assert!(!res.allowed);
+        assert!(res.reject_reason.is_some());
+    }
+
+    // Broadcast the transaction then test a double spend of the same input paying a lower fee.
+    node.client.send_raw_transaction(&signed_tx).expect("sendrawtransaction");
+
+    let mut double_spend = tx.clone();
+    double_spend.output[0].value += Amount::from_sat(500);
+    let double_spend = node
+        .client
+        .sign_raw_transaction_with_wallet(&double_spend, &[], None)
+        .expect("signrawtransactionwithwallet")
+        .into_model()
+        .expect("SignRawTransaction into model")
+        .tx;
+
+    let json = test_mempool_accept(&node, &double_spend);
+    let model: Result<mtype::TestMempoolAccept, TestMempoolAcceptError> = json.into_model();
+    let test_mempool = model.unwrap();
+
+    assert_eq!(test_mempool.results.len(), 1);
+    let res = &test_mempool.results[0];
+    assert_eq!(res.txid, double_spend.compute_txid());
+    assert!(!res.allowed, "double spend should be rejected");
+    assert!(res.reject_reason.is_some());
+    assert!(res.fees.is_none());
+}
+
+/// Calls `testmempoolaccept` for a single transaction using the default max fee rate.
+fn test_mempool_accept(node: &Node, tx: &Transaction) -> TestMempoolAccept {
+    #[cfg(feature = "v18_and_below")]
+    let json = node.client.test_mempool_accept(std::slice::from_ref(tx));
+    #[cfg(not(feature = "v18_and_below"))]
+    let json = node.client.test_mempool_accept(std::slice::from_ref(tx), None);
+    json.expect("testmempoolaccept")
 }
 
 #[test]
@@ -428,11 +851,35 @@ fn raw_transactions__utxo_update_psbt__modelled() {
     node.fund_wallet();
 
     let psbt = create_a_psbt(&node);
+    assert!(psbt.inputs.iter().all(|input| input.witness_utxo.is_none()));
+
+    #[cfg(feature = "v18_and_below")]
     let json: UtxoUpdatePsbt = node.client.utxo_update_psbt(&psbt).expect("utxoupdatepsbt");
+    #[cfg(not(feature = "v18_and_below"))]
+    let json: UtxoUpdatePsbt = node.client.utxo_update_psbt(&psbt, None).expect("utxoupdatepsbt");
     let model: Result<mtype::UtxoUpdatePsbt, psbt::PsbtParseError> = json.into_model();
-    let update_psbts = model.unwrap();
+    let update_psbt = model.unwrap();
+
+    assert_eq!(update_psbt.0.inputs.len(), psbt.inputs.len());
+    // The UTXO being spent is a segwit output pulled from the UTXO set.
+    for input in &update_psbt.0.inputs {
+        assert!(input.witness_utxo.is_some() || input.non_witness_utxo.is_some());
+    }
 
-    assert!(update_psbts.0.inputs.len() >= psbt.inputs.len());
+    // Passing descriptors still updates the UTXO data.
+    #[cfg(not(feature = "v18_and_below"))]
+    {
+        let address = node.client.new_address().expect("failed to create new address");
+        let descriptors = [format!("addr({})", address)];
+        let json: UtxoUpdatePsbt =
+            node.client.utxo_update_psbt(&psbt, Some(&descriptors)).expect("utxoupdatepsbt");
+        let model: Result<mtype::UtxoUpdatePsbt, psbt::PsbtParseError> = json.into_model();
+        let update_psbt = model.unwrap();
+
+        for input in &update_psbt.0.inputs {
+            assert!(input.witness_utxo.is_some() || input.non_witness_utxo.is_some());
+        }
+    }
 }
 
 // Manipulates raw transactions.
@@ -476,8 +923,10 @@ fn create_sign_send(node: &Node) {
 
     // wallet.rs expects this call to exist, if you change it then you'll need to update the test
     // `wallet__sign_raw_transaction_with_wallet__modelled`.
-    let json: SignRawTransactionWithWallet =
-        node.client.sign_raw_transaction_with_wallet(&tx).expect("signrawtransactionwithwallet");
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&tx, &[], None)
+        .expect("signrawtransactionwithwallet");
 
     let model: Result<mtype::SignRawTransactionWithWallet, SignRawTransactionError> =
         json.into_model();
@@ -499,7 +948,7 @@ fn create_sign_send(node: &Node) {
 //
 // TODO: Work out how to get a private key without using `dumpprivkey`.
 #[cfg(feature = "v22_and_below")] // In v23 dumpprivkey no longer works.
-fn create_sign_with_key_send(node: &Node) {
+fn create_sign_with_key_send(node: &Node, sighash_type: Option<SighashType>) {
     let (addr, _tx, txid, tx_out, vout) = create_utxo(node);
 
     // Assumes tx_out has a million sats in it.
@@ -536,11 +985,23 @@ fn create_sign_with_key_send(node: &Node) {
     let model: mtype::DumpPrivKey = json.into_model().expect("DumpPrivKey");
     let key = model.0;
 
-    let json: SignRawTransactionWithKey =
-        node.client.sign_raw_transaction_with_key(&tx, &[key]).expect("signrawtransactionwithkey");
+    let json: SignRawTransactionWithKey = node
+        .client
+        .sign_raw_transaction_with_key(&tx, &[key], sighash_type)
+        .expect("signrawtransactionwithkey");
     let model: Result<mtype::SignRawTransactionWithKey, SignRawTransactionError> =
         json.into_model();
     let sign_raw_transaction = model.unwrap();
+    assert!(sign_raw_transaction.complete);
+    assert!(sign_raw_transaction.errors.is_empty());
+
+    // The sighash type is the last byte of the signature, the first witness element.
+    let sig = sign_raw_transaction.tx.input[0].witness.nth(0).expect("signature");
+    let want = match sighash_type {
+        Some(SighashType::SinglePlusAnyoneCanPay) => 0x83,
+        _ => 0x01,
+    };
+    assert_eq!(sig.last(), Some(&want));
 
     // The proves we did everything correctly.
     let json: SendRawTransaction =
@@ -577,7 +1038,7 @@ fn create_fund_sign_send(node: &Node) {
     let tx = json.transaction().unwrap();
 
     let json: FundRawTransaction =
-        node.client.fund_raw_transaction(&tx).expect("fundrawtransaction");
+        node.client.fund_raw_transaction(&tx, None).expect("fundrawtransaction");
     let model: Result<mtype::FundRawTransaction, FundRawTransactionError> =
         json.clone().into_model();
     model.unwrap();
@@ -586,7 +1047,7 @@ fn create_fund_sign_send(node: &Node) {
     // This method is from the wallet section.
     let json: SignRawTransactionWithWallet = node
         .client
-        .sign_raw_transaction_with_wallet(&funded)
+        .sign_raw_transaction_with_wallet(&funded, &[], None)
         .expect("signrawtransactionwithwallet");
     // This proves we did everything correctly.
     let model: Result<mtype::SignRawTransactionWithWallet, SignRawTransactionError> =
@@ -7,8 +7,8 @@
 
 use bitcoin::{address, amount, sign_message, PrivateKey, PublicKey};
 use integration_test::{Node, NodeExt as _, Wallet};
-use node::mtype;
 use node::vtype::*;
+use node::{mtype, EstimateMode};
 
 #[test]
 fn util__create_multisig__modelled() {
@@ -24,7 +24,7 @@ fn util__create_multisig__modelled() {
 
     let node = Node::with_wallet(Wallet::Default, &[]);
     let json: CreateMultisig =
-        node.client.create_multisig(nrequired, vec![pubkey1, pubkey2]).expect("createmultisig");
+        node.client.create_multisig(nrequired, &[pubkey1, pubkey2]).expect("createmultisig");
     let model: Result<mtype::CreateMultisig, CreateMultisigError> = json.into_model();
     model.unwrap();
 }
@@ -38,10 +38,20 @@ fn util__derive_addresses__modelled() {
     let descriptor =
         "pkh(02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8)#sf4k0g3u";
 
-    let json: DeriveAddresses = node.client.derive_addresses(descriptor).expect("deriveaddresses");
-    let model: Result<mtype::DeriveAddresses, address::ParseError> = json.into_model();
+    let json: DeriveAddresses =
+        node.client.derive_addresses(descriptor, None).expect("deriveaddresses");
+    let model: Result<mtype::DeriveAddresses, DeriveAddressesError> = json.into_model();
     model.unwrap();
 
+    // A ranged descriptor requires a range, the checksum is added by `getdescriptorinfo`.
+    let ranged_descriptor = "wpkh(tpubDDe7JUw2CGU1rYZxupmNrhDXuE1fv25gs4je3BBuWCFwTW9QHGgyh5cjAEugd14ysJXTVshPvnUVABfD66HZKCS9gp5AYFd5K2WN2oVFp8t/0/*)";
+    let info: GetDescriptorInfo =
+        node.client.get_descriptor_info(ranged_descriptor).expect("getdescriptorinfo");
+    let json: DeriveAddresses =
+        node.client.derive_addresses(&info.descriptor, Some((0, 2))).expect("deriveaddresses");
+    let model: Result<mtype::DeriveAddresses, DeriveAddressesError> = json.into_model();
+    assert_eq!(model.unwrap().addresses.len(), 3);
+
     // For v29 and above test a multipath descriptor.
     #[cfg(not(feature = "v28_and_below"))]
     {
@@ -53,7 +63,8 @@ fn util__derive_addresses__modelled() {
             .client
             .derive_addresses_multipath(multipath_descriptor, range)
             .expect("deriveaddresses");
-        let model: Result<mtype::DeriveAddressesMultipath, address::ParseError> = json.into_model();
+        let model: Result<mtype::DeriveAddressesMultipath, DeriveAddressesError> =
+            json.into_model();
         let derived = model.unwrap();
 
         // Should return 2 `DeriveAddresses`, one for each derivation path (0 and 1).
@@ -68,20 +79,38 @@ fn util__estimate_smart_fee__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
-    let json: EstimateSmartFee = node.client.estimate_smart_fee(6).expect("estimatesmartfee");
+    let json: EstimateSmartFee = node.client.estimate_smart_fee(6, None).expect("estimatesmartfee");
+    let model: Result<mtype::EstimateSmartFee, amount::ParseAmountError> = json.into_model();
+    model.unwrap();
+
+    let json: EstimateSmartFee = node
+        .client
+        .estimate_smart_fee(6, Some(EstimateMode::Economical))
+        .expect("estimatesmartfee");
     let model: Result<mtype::EstimateSmartFee, amount::ParseAmountError> = json.into_model();
     model.unwrap();
 }
 
 #[test]
 #[cfg(not(feature = "v17"))]
-fn util__get_descriptor_info() {
+fn util__get_descriptor_info__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
     // Use a valid, deterministic public key from the pubkey_sort test vectors
     let descriptor = "pkh(02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8)";
-    let _: GetDescriptorInfo =
+    let json: GetDescriptorInfo =
         node.client.get_descriptor_info(descriptor).expect("getdescriptorinfo");
+
+    #[cfg(feature = "v18_and_below")]
+    let info: mtype::GetDescriptorInfo = json.into_model();
+    #[cfg(not(feature = "v18_and_below"))]
+    let info = {
+        let model: Result<mtype::GetDescriptorInfo, GetDescriptorInfoError> = json.into_model();
+        let info = model.unwrap();
+        assert_eq!(info.checksum.as_deref(), Some("sf4k0g3u"));
+        info
+    };
+    assert!(!info.is_range);
 }
 
 #[test]
@@ -122,6 +151,10 @@ fn util__sign_message_with_priv_key__modelled() {
     let verified: VerifyMessage =
         node.client.verify_message(&addr, &sig.0, message).expect("verifymessage");
     assert!(verified.0, "Signature should verify for the correct address and message");
+
+    let verified: VerifyMessage =
+        node.client.verify_message(&addr, &sig.0, "some other message").expect("verifymessage");
+    assert!(!verified.0, "Signature should not verify for a different message");
 }
 
 #[test]
@@ -130,9 +163,25 @@ fn util__validate_address__modelled() {
     node.fund_wallet();
 
     let addr = node.client.new_address().expect("new_address");
-    let json: ValidateAddress = node.client.validate_address(&addr).expect("validateaddress");
+    let json: ValidateAddress =
+        node.client.validate_address(&addr.to_string()).expect("validateaddress");
     let model: Result<mtype::ValidateAddress, ValidateAddressError> = json.into_model();
-    model.unwrap();
+    let valid = model.unwrap();
+    assert!(valid.is_valid);
+    assert_eq!(valid.address.unwrap(), addr.into_unchecked());
+
+    // An invalid address only returns `isvalid`, from v22 Core also returns error fields.
+    let json: ValidateAddress =
+        node.client.validate_address("not-an-address").expect("validateaddress");
+    let model: Result<mtype::ValidateAddress, ValidateAddressError> = json.into_model();
+    let invalid = model.unwrap();
+    assert!(!invalid.is_valid);
+    assert!(invalid.address.is_none());
+    assert!(invalid.script_pubkey.is_none());
+    #[cfg(feature = "v21_and_below")]
+    assert!(invalid.error.is_none());
+    #[cfg(not(feature = "v21_and_below"))]
+    assert!(invalid.error.is_some());
 }
 
 // This is tested in util__sign_message_with_priv_key__modelled()
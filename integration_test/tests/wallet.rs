@@ -7,21 +7,26 @@
 
 use std::collections::BTreeMap;
 use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use bitcoin::address::{self, Address, KnownHrp, NetworkChecked};
 use bitcoin::bip32::{Xpriv, Xpub};
+use bitcoin::hashes::hash160;
+use bitcoin::opcodes::all::{OP_CHECKMULTISIG, OP_PUSHNUM_2, OP_PUSHNUM_3};
 use bitcoin::{
-    amount, hex, key, psbt, secp256k1, sign_message, Amount, CompressedPublicKey, FeeRate, Network,
-    PrivateKey, PublicKey,
+    amount, hex, key, psbt, script, secp256k1, sign_message, Amount, CompressedPublicKey, FeeRate,
+    Network, OutPoint, PrivateKey, PublicKey, WitnessVersion,
 };
 use integration_test::{Node, NodeExt as _, Wallet};
 use node::vtype::*; // All the version specific types.
+#[cfg(not(feature = "v18_and_below"))]
+use node::CreateWalletOptions;
 #[cfg(not(feature = "v20_and_below"))]
 use node::ImportDescriptorsRequest;
 use node::{
-    mtype, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp,
-    WalletCreateFundedPsbtInput,
+    mtype, AddressType, BumpFeeOptions, EstimateMode, ImportMultiRequest, ImportMultiScriptPubKey,
+    ImportMultiTimestamp, Input, ListUnspentQueryOptions, Output, WalletCreateFundedPsbtInput,
+    WalletCreateFundedPsbtOptions,
 };
 
 #[test]
@@ -30,7 +35,7 @@ fn wallet__abandon_transaction() {
 
     let mining_addr = node.client.new_address().expect("newaddress");
     let json: GenerateToAddress =
-        node.client.generate_to_address(101, &mining_addr).expect("generatetoaddress");
+        node.client.generate_to_address(101, &mining_addr, None).expect("generatetoaddress");
     let block_hashes = json.into_model();
 
     let block_hash = block_hashes.expect("blockhash").0[0];
@@ -48,6 +53,26 @@ fn wallet__abandon_transaction() {
     node.client.invalidate_block(block_hash).expect("invalidateblock");
 
     let _: () = node.client.abandon_transaction(txid).expect("abandontransaction");
+
+    let tx = node.client.get_transaction(txid).expect("gettransaction").into_model().unwrap();
+    let send = tx
+        .details
+        .iter()
+        .find(|detail| detail.category == mtype::TransactionCategory::Send)
+        .expect("send detail");
+    assert_eq!(send.abandoned, Some(true));
+}
+
+#[test]
+fn wallet__abandon_transaction__non_wallet_txid() {
+    use bitcoin::hashes::Hash as _;
+    use node::client::RpcErrorCode;
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+
+    let txid = bitcoin::Txid::all_zeros();
+    let err = node.client.abandon_transaction(txid).expect_err("abandontransaction should fail");
+    assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::InvalidAddressOrKey));
 }
 
 #[test]
@@ -78,16 +103,70 @@ fn wallet__add_multisig_address__modelled() {
 
     let json: AddMultisigAddress = node
         .client
-        .add_multisig_address_with_addresses(nrequired, vec![addr1, addr2])
+        .add_multisig_address_with_addresses(nrequired, vec![addr1, addr2], None, None)
         .expect("addmultisigaddress");
 
     let model: Result<mtype::AddMultisigAddress, AddMultisigAddressError> = json.into_model();
     model.unwrap();
+
+    // Create a 2-of-3 using public keys, a label, and an explicit address type.
+    let pubkeys: Vec<PublicKey> = (0..3)
+        .map(|_| {
+            let addr = node.client.new_address_with_type(AddressType::Bech32).expect("newaddress");
+            node.client
+                .get_address_info(&addr)
+                .expect("getaddressinfo")
+                .into_model()
+                .expect("GetAddressInfo into model")
+                .pubkey
+                .expect("pubkey")
+        })
+        .collect();
+
+    let label = "multisig";
+    let json: AddMultisigAddress = node
+        .client
+        .add_multisig_address_with_keys(
+            nrequired,
+            pubkeys.clone(),
+            Some(label),
+            Some(AddressType::Bech32),
+        )
+        .expect("addmultisigaddress");
+    let model: Result<mtype::AddMultisigAddress, AddMultisigAddressError> = json.into_model();
+    let multisig = model.unwrap();
+
+    let expected = pubkeys
+        .iter()
+        .fold(script::Builder::new().push_opcode(OP_PUSHNUM_2), |builder, pk| builder.push_key(pk))
+        .push_opcode(OP_PUSHNUM_3)
+        .push_opcode(OP_CHECKMULTISIG)
+        .into_script();
+    assert_eq!(multisig.redeem_script, expected);
+
+    let address = multisig.address.require_network(Network::Regtest).expect("regtest address");
+    assert_eq!(address, Address::p2wsh(&expected, KnownHrp::Regtest));
+
+    let json: GetAddressesByLabel =
+        node.client.get_addresses_by_label(label).expect("getaddressesbylabel");
+    assert!(json.0.contains_key(&address.to_string()));
 }
 
 #[test]
 fn wallet__backup_wallet() { backup_and_restore_wallet() }
 
+#[test]
+fn wallet__backup_wallet__invalid_destination() {
+    use node::client::RpcErrorCode;
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let file_path = integration_test::random_tmp_file().join("missing-dir").join("backup.dat");
+
+    let err = node.client.backup_wallet(&file_path).expect_err("backupwallet should fail");
+    assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::WalletError));
+    assert!(!file_path.exists());
+}
+
 fn backup_and_restore_wallet() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     let file_path = integration_test::random_tmp_file();
@@ -95,6 +174,8 @@ fn backup_and_restore_wallet() {
     let _: () = node.client.backup_wallet(&file_path).expect("backupwallet");
     assert!(file_path.exists(), "Backup file should exist at destination");
     assert!(file_path.is_file(), "Backup destination should be a file");
+    let len = fs::metadata(&file_path).expect("metadata").len();
+    assert!(len > 0, "Backup file should not be empty");
 
     // Restore wallet only available for v23 and above.
     #[cfg(not(feature = "v22_and_below"))]
@@ -113,7 +194,7 @@ fn backup_and_restore_wallet() {
 fn wallet__bump_fee__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     let address = node.client.new_address().expect("failed to create new address");
-    let _ = node.client.generate_to_address(101, &address).expect("generatetoaddress");
+    let _ = node.client.generate_to_address(101, &address, None).expect("generatetoaddress");
 
     let txid = node
         .client
@@ -122,9 +203,28 @@ fn wallet__bump_fee__modelled() {
         .txid()
         .unwrap();
 
-    let json: BumpFee = node.client.bump_fee(txid).expect("bumpfee");
+    let json: BumpFee = node.client.bump_fee(txid, None).expect("bumpfee");
     let model: Result<mtype::BumpFee, BumpFeeError> = json.into_model();
-    model.unwrap();
+    let bumped = model.unwrap();
+    assert_ne!(bumped.txid, txid);
+    assert!(bumped.fee > bumped.original_fee);
+    assert!(bumped.errors.is_empty());
+
+    // Bump the replacement again, this time with explicit options.
+    #[cfg(feature = "v18_and_below")] // `fee_rate` option added in v0.19.
+    let options = BumpFeeOptions { conf_target: Some(2), ..Default::default() };
+    #[cfg(not(feature = "v18_and_below"))]
+    let options = BumpFeeOptions {
+        fee_rate: Some(FeeRate::from_sat_per_vb(50).unwrap()),
+        replaceable: Some(true),
+        ..Default::default()
+    };
+    let json: BumpFee = node.client.bump_fee(bumped.txid, Some(&options)).expect("bumpfee");
+    let model: Result<mtype::BumpFee, BumpFeeError> = json.into_model();
+    let rebumped = model.unwrap();
+    assert_ne!(rebumped.txid, bumped.txid);
+    assert_eq!(rebumped.original_fee, bumped.fee);
+    assert!(rebumped.fee > bumped.fee);
 }
 
 #[test]
@@ -133,6 +233,47 @@ fn wallet__create_wallet__modelled() {
     let _ = Node::with_wallet(Wallet::Default, &[]);
 }
 
+#[test]
+#[cfg(not(feature = "v18_and_below"))]
+fn wallet__create_wallet_with_options__blank() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    let options = CreateWalletOptions { blank: Some(true), ..Default::default() };
+
+    let json: CreateWallet =
+        node.client.create_wallet_with_options("blank", &options).expect("createwallet");
+    let model: mtype::CreateWallet = json.into_model();
+    assert_eq!(model.name, "blank");
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    assert_eq!(json.wallet_name, "blank");
+    assert!(json.private_keys_enabled);
+
+    // A blank wallet has no keys to derive addresses from.
+    assert!(node.client.new_address().is_err());
+}
+
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn wallet__create_wallet_with_options__descriptors() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    let options = CreateWalletOptions {
+        disable_private_keys: Some(true),
+        avoid_reuse: Some(true),
+        descriptors: Some(true),
+        ..Default::default()
+    };
+
+    let json: CreateWallet =
+        node.client.create_wallet_with_options("watch_only", &options).expect("createwallet");
+    let model: mtype::CreateWallet = json.into_model();
+    assert_eq!(model.name, "watch_only");
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    assert!(json.descriptors);
+    assert!(json.avoid_reuse);
+    assert!(!json.private_keys_enabled);
+}
+
 #[test]
 #[cfg(not(feature = "v27_and_below"))]
 fn wallet__create_wallet_descriptor() {
@@ -200,7 +341,7 @@ fn wallet__dump_priv_key__modelled() {
 }
 
 #[test]
-fn wallet__dump_wallet() {
+fn wallet__dump_wallet__modelled() {
     // As of Core v23 the default wallet is an native descriptor wallet which does not
     // support dumping private keys. Legacy wallets are supported upto v25 it seems.
     #[cfg(all(feature = "v25_and_below", not(feature = "v22_and_below")))]
@@ -210,7 +351,9 @@ fn wallet__dump_wallet() {
         node.client.create_legacy_wallet("legacy_wallet").expect("legacy create_wallet");
         let out = integration_test::random_tmp_file();
 
-        let _: DumpWallet = node.client.dump_wallet(&out).expect("dumpwallet");
+        let json: DumpWallet = node.client.dump_wallet(&out).expect("dumpwallet");
+        let model: mtype::DumpWallet = json.into_model();
+        assert_eq!(model.file_name, out);
     }
 
     #[cfg(feature = "v22_and_below")]
@@ -218,7 +361,9 @@ fn wallet__dump_wallet() {
         let node = Node::with_wallet(Wallet::Default, &[]);
         let out = integration_test::random_tmp_file();
 
-        let _: DumpWallet = node.client.dump_wallet(&out).expect("dumpwallet");
+        let json: DumpWallet = node.client.dump_wallet(&out).expect("dumpwallet");
+        let model: mtype::DumpWallet = json.into_model();
+        assert_eq!(model.file_name, out);
     }
 }
 
@@ -242,7 +387,7 @@ fn wallet__get_addresses_by_label__modelled() {
 
     // sanity checks.
     assert!(!map.0.is_empty());
-    assert!(map.0.contains_key(&addr));
+    assert_eq!(map.0.get(&addr), Some(&mtype::AddressPurpose::Receive));
 }
 
 #[test]
@@ -268,6 +413,16 @@ fn wallet__get_address_info__modelled() {
     assert_eq!(address_info.address.assume_checked(), addr_p2sh);
     assert_eq!(address_info.script.unwrap(), mtype::ScriptType::WitnessV0KeyHash);
     assert!(embedded.address.is_valid_for_network(Network::Regtest));
+    // The embedded object describes the P2WPKH redeem script wrapped by the P2SH address.
+    let embedded_addr = embedded.address.assume_checked();
+    assert_eq!(embedded.script_pubkey, embedded_addr.script_pubkey());
+    assert!(embedded.is_witness);
+    assert_eq!(embedded.witness_version, Some(WitnessVersion::V0));
+    let wrapped = Address::p2sh(&embedded.script_pubkey, Network::Regtest).expect("p2sh");
+    assert_eq!(wrapped, addr_p2sh);
+    let pubkey = address_info.pubkey.expect("pubkey");
+    let compressed = CompressedPublicKey::try_from(pubkey).expect("compressed");
+    assert_eq!(Address::p2wpkh(&compressed, KnownHrp::Regtest), embedded_addr);
 
     // Test a Bech32 address.
     let addr_bech32 = node.client.new_address_with_type(AddressType::Bech32).unwrap();
@@ -276,6 +431,28 @@ fn wallet__get_address_info__modelled() {
     let model: Result<mtype::GetAddressInfo, GetAddressInfoError> = json.into_model();
     let address_info = model.unwrap();
     assert_eq!(address_info.address.assume_checked(), addr_bech32);
+    assert_eq!(address_info.script_pubkey, addr_bech32.script_pubkey());
+    assert!(address_info.is_mine);
+    let pubkey = address_info.pubkey.expect("pubkey");
+    let compressed = CompressedPublicKey::try_from(pubkey).expect("compressed");
+    assert_eq!(Address::p2wpkh(&compressed, KnownHrp::Regtest), addr_bech32);
+
+    // Test an address not owned by the wallet, the private key related fields are absent.
+    let other = Node::with_wallet(Wallet::Default, &[]);
+    let addr_other = other.client.new_address_with_type(AddressType::Bech32).unwrap();
+    #[cfg(feature = "v22_and_below")]
+    node.client.import_address(&addr_other).expect("importaddress");
+
+    let json: GetAddressInfo =
+        node.client.get_address_info(&addr_other).expect("getaddressinfo watch-only");
+    let model: Result<mtype::GetAddressInfo, GetAddressInfoError> = json.into_model();
+    let address_info = model.unwrap();
+    assert_eq!(address_info.script_pubkey, addr_other.script_pubkey());
+    assert!(!address_info.is_mine);
+    assert!(address_info.pubkey.is_none());
+    assert!(address_info.hd_key_path.is_none());
+    #[cfg(feature = "v22_and_below")]
+    assert!(address_info.is_watch_only);
 }
 
 #[test]
@@ -301,7 +478,31 @@ fn wallet__get_balances() {
 
     let json: GetBalances = node.client.get_balances().expect("getbalances");
     let model: Result<mtype::GetBalances, GetBalancesError> = json.into_model();
-    model.unwrap();
+    let balances = model.unwrap();
+
+    // Of the 101 coinbase outputs only the first one is mature.
+    assert_eq!(balances.mine.trusted, Amount::from_int_btc(50));
+    assert_eq!(balances.mine.untrusted_pending, Amount::ZERO);
+    assert_eq!(balances.mine.immature, Amount::from_int_btc(50 * 100));
+    assert!(balances.watch_only.is_none());
+
+    // Watch-only addresses can only be imported into legacy wallets.
+    #[cfg(feature = "v22_and_below")]
+    {
+        let other = Node::with_wallet(Wallet::Default, &[]);
+        let addr_other = other.client.new_address().expect("failed to get new address");
+        node.client.import_address(&addr_other).expect("importaddress");
+        node.client.generate_to_address(1, &addr_other, None).expect("generatetoaddress");
+
+        let json: GetBalances = node.client.get_balances().expect("getbalances");
+        let model: Result<mtype::GetBalances, GetBalancesError> = json.into_model();
+        let balances = model.unwrap();
+
+        let watch_only = balances.watch_only.expect("watchonly");
+        assert_eq!(watch_only.trusted, Amount::ZERO);
+        assert_eq!(watch_only.untrusted_pending, Amount::ZERO);
+        assert_eq!(watch_only.immature, Amount::from_int_btc(50));
+    }
 }
 
 #[test]
@@ -324,10 +525,19 @@ fn wallet__get_new_address__modelled() {
     // Implicitly tests `getnewaddress`.
     let _ = node.client.new_address().unwrap();
 
-    // Exhaustively test address types with helper.
-    let _ = node.client.new_address_with_type(AddressType::Legacy).unwrap();
-    let _ = node.client.new_address_with_type(AddressType::P2shSegwit).unwrap();
-    let _ = node.client.new_address_with_type(AddressType::Bech32).unwrap();
+    // Exhaustively test address types with helper, checking the regtest prefix of each.
+    let legacy = node.client.new_address_with_type(AddressType::Legacy).unwrap().to_string();
+    assert!(legacy.starts_with('m') || legacy.starts_with('n'));
+    let p2sh = node.client.new_address_with_type(AddressType::P2shSegwit).unwrap().to_string();
+    assert!(p2sh.starts_with('2'));
+    let bech32 = node.client.new_address_with_type(AddressType::Bech32).unwrap().to_string();
+    assert!(bech32.starts_with("bcrt1q"));
+
+    #[cfg(not(feature = "v22_and_below"))]
+    {
+        let bech32m = node.client.new_address_with_type(AddressType::Bech32m).unwrap().to_string();
+        assert!(bech32m.starts_with("bcrt1p"));
+    }
 }
 
 #[test]
@@ -337,6 +547,13 @@ fn wallet__get_raw_change_address__modelled() {
         node.client.get_raw_change_address().expect("getrawchangeaddress");
     let model: Result<mtype::GetRawChangeAddress, address::ParseError> = json.into_model();
     model.unwrap();
+
+    let json: GetRawChangeAddress = node
+        .client
+        .get_raw_change_address_with_type(AddressType::Bech32)
+        .expect("getrawchangeaddress bech32");
+    let address = json.into_model().unwrap().0.assume_checked();
+    assert!(address.to_string().starts_with("bcrt1q"));
 }
 
 #[test]
@@ -352,11 +569,17 @@ fn wallet__get_received_by_address__modelled() {
     node.mine_a_block();
 
     let json: GetReceivedByAddress =
-        node.client.get_received_by_address(&address).expect("getreceivedbyaddress");
+        node.client.get_received_by_address(&address, None).expect("getreceivedbyaddress");
     let model: Result<mtype::GetReceivedByAddress, amount::ParseAmountError> = json.into_model();
     let received_by_address = model.unwrap();
 
     assert_eq!(received_by_address.0, amount);
+
+    // The transaction only has one confirmation.
+    let json: GetReceivedByAddress =
+        node.client.get_received_by_address(&address, Some(2)).expect("getreceivedbyaddress");
+    let model: Result<mtype::GetReceivedByAddress, amount::ParseAmountError> = json.into_model();
+    assert_eq!(model.unwrap().0, Amount::ZERO);
 }
 
 #[test]
@@ -373,10 +596,16 @@ fn wallet__get_received_by_label__modelled() {
     node.mine_a_block();
 
     let json: GetReceivedByLabel =
-        node.client.get_received_by_label(label).expect("getreceivedbylabel");
+        node.client.get_received_by_label(label, None).expect("getreceivedbylabel");
     let model: Result<mtype::GetReceivedByLabel, amount::ParseAmountError> = json.into_model();
     let received = model.unwrap();
     assert_eq!(received.0, amount);
+
+    // The transaction only has one confirmation.
+    let json: GetReceivedByLabel =
+        node.client.get_received_by_label(label, Some(2)).expect("getreceivedbylabel");
+    let model: Result<mtype::GetReceivedByLabel, amount::ParseAmountError> = json.into_model();
+    assert_eq!(model.unwrap().0, Amount::ZERO);
 }
 
 #[test]
@@ -394,16 +623,51 @@ fn wallet__get_transaction__modelled() {
 
     let json: GetTransaction = node.client.get_transaction(txid).expect("gettransaction");
     let model: Result<mtype::GetTransaction, GetTransactionError> = json.into_model();
-    model.unwrap();
+    let tx = model.unwrap();
+    assert!(tx.decoded.is_none());
+    // Sent from our own wallet so the fee is known and negative.
+    assert!(tx.fee.expect("fee").is_negative());
+    assert_eq!(tx.tx.compute_txid(), txid);
+
+    #[cfg(not(feature = "v18_and_below"))]
+    {
+        let json: GetTransaction = node
+            .client
+            .get_transaction_with_options(txid, Some(true), Some(true))
+            .expect("gettransaction verbose");
+        let model: Result<mtype::GetTransaction, GetTransactionError> = json.into_model();
+        let verbose = model.unwrap();
+        assert_eq!(verbose.decoded, Some(verbose.tx));
+    }
 }
 
 #[test]
 fn wallet__get_unconfirmed_balance__modelled() {
-    let node = Node::with_wallet(Wallet::Default, &[]);
+    // Funds sent from our own wallet are trusted, use a second node to get an untrusted balance.
+    let (node1, node2, _node3) = integration_test::three_node_network();
+    node1.fund_wallet();
+
     let json: GetUnconfirmedBalance =
-        node.client.get_unconfirmed_balance().expect("getunconfirmedbalance");
+        node2.client.get_unconfirmed_balance().expect("getunconfirmedbalance");
     let model: Result<mtype::GetUnconfirmedBalance, amount::ParseAmountError> = json.into_model();
-    model.unwrap();
+    assert_eq!(model.unwrap().0, Amount::ZERO);
+
+    let amount = Amount::from_sat(1_000_000);
+    let address = node2.client.new_address().expect("failed to get new address");
+    let _ = node1.client.send_to_address(&address, amount).expect("sendtoaddress");
+
+    // Wait for the transaction to be relayed to the second node.
+    let mut balance = Amount::ZERO;
+    for _ in 0..60 {
+        let json: GetUnconfirmedBalance =
+            node2.client.get_unconfirmed_balance().expect("getunconfirmedbalance");
+        balance = json.into_model().unwrap().0;
+        if balance != Amount::ZERO {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+    assert_eq!(balance, amount);
 }
 
 #[test]
@@ -416,6 +680,12 @@ fn wallet__get_wallet_info__modelled() {
     let wallet_info = model.unwrap();
 
     assert!(!wallet_info.wallet_name.is_empty());
+    // The coinbase of the mined block is immature.
+    assert_eq!(wallet_info.balance, Amount::ZERO);
+    assert_eq!(wallet_info.unconfirmed_balance, Amount::ZERO);
+    assert_eq!(wallet_info.immature_balance, Amount::from_int_btc(50));
+    assert_eq!(wallet_info.pay_tx_fee, Some(FeeRate::ZERO));
+    assert!(wallet_info.keypool_size > 0);
 
     #[cfg(not(feature = "v18_and_below"))]
     {
@@ -432,6 +702,44 @@ fn wallet__get_wallet_info__modelled() {
     }
 }
 
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn wallet__get_wallet_info__descriptor_and_legacy() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    // Descriptor wallets are the default from v23 onwards.
+    #[cfg(feature = "v22_and_below")]
+    node.client.create_descriptor_wallet("descriptor").expect("createwallet");
+    #[cfg(not(feature = "v22_and_below"))]
+    node.client.create_wallet("descriptor").expect("createwallet");
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    let model: Result<mtype::GetWalletInfo, GetWalletInfoError> = json.into_model();
+    let wallet_info = model.unwrap();
+
+    assert_eq!(wallet_info.descriptors, Some(true));
+    assert!(wallet_info.hd_seed_id.is_none());
+    assert!(wallet_info.keypool_size > 0);
+
+    let node = match () {
+        #[cfg(feature = "v22_and_below")]
+        () => Node::with_wallet(Wallet::Default, &[]),
+        #[cfg(not(feature = "v22_and_below"))]
+        () => {
+            let node = Node::with_wallet(Wallet::None, &["-deprecatedrpc=create_bdb"]);
+            node.client.create_legacy_wallet("legacy").expect("createlegacywallet");
+            node
+        }
+    };
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    let model: Result<mtype::GetWalletInfo, GetWalletInfoError> = json.into_model();
+    let wallet_info = model.unwrap();
+
+    assert_eq!(wallet_info.descriptors, Some(false));
+    assert!(wallet_info.hd_seed_id.is_some());
+    assert!(wallet_info.keypool_oldest > 0);
+}
+
 #[test]
 fn wallet__import_address() {
     let node = match () {
@@ -458,7 +766,7 @@ fn wallet__import_address() {
 
 #[test]
 #[cfg(not(feature = "v20_and_below"))]
-fn wallet__import_descriptors() {
+fn wallet__import_descriptors__modelled() {
     let node = Node::with_wallet(Wallet::None, &[]);
     let wallet_name = "desc_wallet";
 
@@ -491,14 +799,30 @@ fn wallet__import_descriptors() {
 
     // 4. Mine 100 blocks
     let mining_address = node.client.new_address().expect("failed to get mining address");
-    let _blocks = node.client.generate_to_address(100, &mining_address).expect("generatetoaddress");
+    let _blocks =
+        node.client.generate_to_address(100, &mining_address, None).expect("generatetoaddress");
 
     // 5. Scan for the descriptor using the time from (1)
     let request = ImportDescriptorsRequest::new(descriptor, start_time);
-    let result: ImportDescriptors =
-        node.client.import_descriptors(&[request]).expect("importdescriptors");
-    assert_eq!(result.0.len(), 1, "should have exactly one import result");
-    assert!(result.0[0].success);
+    // 6. Import an invalid descriptor along with the valid one.
+    let invalid = ImportDescriptorsRequest::new("wpkh(not_a_key)", start_time);
+    let json: ImportDescriptors =
+        node.client.import_descriptors(&[request, invalid]).expect("importdescriptors");
+    let model: mtype::ImportDescriptors = json.into_model();
+    let results = model.0;
+    assert_eq!(results.len(), 2, "should have one import result per request");
+
+    assert!(results[0].success);
+    assert!(results[0].error.is_none());
+
+    assert!(!results[1].success);
+    match results[1].error {
+        Some(mtype::ImportDescriptorsFailure::Rpc { code, ref message }) => {
+            assert_eq!(code, -5); // RPC_INVALID_ADDRESS_OR_KEY
+            assert!(!message.is_empty());
+        }
+        ref other => panic!("expected a JSON-RPC error, got {:?}", other),
+    }
 }
 
 #[test]
@@ -510,7 +834,7 @@ fn wallet__import_pruned_funds() {
     let txid = tx.compute_txid();
 
     let raw_tx = node.client.get_raw_transaction(txid).expect("getrawtransaction");
-    let tx_out_proof = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+    let tx_out_proof = node.client.get_tx_out_proof(&[txid], None).expect("gettxoutproof");
 
     let _: () =
         node.client.import_pruned_funds(&raw_tx.0, &tx_out_proof).expect("importprunedfunds");
@@ -538,11 +862,49 @@ fn wallet__import_wallet() {
     let _: () = node.client.import_wallet(&dump_file_path).expect("importwallet");
 }
 
+#[test]
+fn wallet__import_wallet__into_fresh_wallet() {
+    let node = legacy_wallet_node("original");
+    node.fund_wallet();
+    let original: GetBalance = node.client.get_balance().expect("getbalance");
+    let original = original.into_model().expect("GetBalance into model").0;
+    assert!(original > bitcoin::Amount::ZERO);
+
+    let dir = integration_test::random_tmp_file();
+    fs::create_dir(&dir).expect("failed to create temp dir");
+    let dump_file_path = dir.join("wallet.dump");
+    let json: DumpWallet = node.client.dump_wallet(&dump_file_path).expect("dumpwallet");
+    assert_eq!(json.into_model().file_name, dump_file_path);
+
+    replace_legacy_wallet(&node, "original", "fresh");
+    let _: () = node.client.import_wallet(&dump_file_path).expect("importwallet");
+
+    let imported: GetBalance = node.client.get_balance().expect("getbalance");
+    assert_eq!(imported.into_model().expect("GetBalance into model").0, original);
+}
+
 #[test]
 fn wallet__keypool_refill() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
-    let _: () = node.client.key_pool_refill().expect("keypoolrefill");
+    let _: () = node.client.keypool_refill(None).expect("keypoolrefill");
+}
+
+#[test]
+fn wallet__keypool_refill__new_size() {
+    // Larger than the default `-keypool` size of 1000.
+    const NEW_SIZE: u32 = 1500;
+
+    let node = legacy_wallet_node("legacy");
+
+    let keypool_size = || {
+        let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+        json.into_model().expect("GetWalletInfo into model").keypool_size
+    };
+    assert!(keypool_size() < NEW_SIZE);
+
+    let _: () = node.client.keypool_refill(Some(NEW_SIZE)).expect("keypoolrefill");
+    assert_eq!(keypool_size(), NEW_SIZE);
 }
 
 #[test]
@@ -550,9 +912,57 @@ fn wallet__list_address_groupings__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
-    let address = node.client.new_address().expect("failed to create new address");
-    let amount = Amount::from_sat(10_000);
-    node.client.send_to_address(&address, amount).expect("sendtoaddress").txid().unwrap();
+    // A labelled address and a change address (which has no label).
+    let label = "grouping-label";
+    let labelled = node.client.new_address_with_label(label).expect("newaddress").assume_checked();
+    let change = node
+        .client
+        .get_raw_change_address()
+        .expect("getrawchangeaddress")
+        .into_model()
+        .expect("GetRawChangeAddress into model")
+        .0
+        .assume_checked();
+
+    let amount = Amount::from_sat(100_000);
+    node.client.send_to_address(&labelled, amount).expect("sendtoaddress");
+    node.client.send_to_address(&change, amount).expect("sendtoaddress");
+    node.mine_a_block();
+
+    // Spend both outputs in a single transaction so Core groups the two addresses.
+    let utxos: mtype::ListUnspent = node
+        .client
+        .list_unspent(None, None, None, None, None)
+        .expect("listunspent")
+        .into_model()
+        .unwrap();
+    let inputs: Vec<Input> = utxos
+        .0
+        .iter()
+        .filter(|utxo| {
+            let address = utxo.address.clone().assume_checked();
+            address == labelled || address == change
+        })
+        .map(|utxo| Input { txid: utxo.txid, vout: utxo.vout.into(), sequence: None })
+        .collect();
+    assert_eq!(inputs.len(), 2);
+
+    let spend_address = node.client.new_address().expect("newaddress");
+    let outputs = vec![Output::new(spend_address, Amount::from_sat(199_000))];
+    let tx = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+    let signed = node
+        .client
+        .sign_raw_transaction_with_wallet(&tx, &[], None)
+        .expect("signrawtransactionwithwallet")
+        .into_model()
+        .expect("SignRawTransactionWithWallet into model")
+        .tx;
+    node.client.send_raw_transaction(&signed).expect("sendrawtransaction");
     node.mine_a_block();
 
     let json: ListAddressGroupings =
@@ -560,7 +970,23 @@ fn wallet__list_address_groupings__modelled() {
     let model: Result<mtype::ListAddressGroupings, ListAddressGroupingsError> = json.into_model();
     let groupings = model.unwrap();
 
-    assert!(!groupings.0.is_empty());
+    let group = groupings
+        .0
+        .iter()
+        .find(|group| group.iter().any(|item| item.address.clone().assume_checked() == labelled))
+        .expect("group containing the labelled address");
+
+    let labelled_item =
+        group.iter().find(|item| item.address.clone().assume_checked() == labelled).unwrap();
+    assert_eq!(labelled_item.amount, Amount::ZERO);
+    assert_eq!(labelled_item.label.as_deref(), Some(label));
+
+    let change_item = group
+        .iter()
+        .find(|item| item.address.clone().assume_checked() == change)
+        .expect("change address grouped with the labelled address");
+    assert_eq!(change_item.amount, Amount::ZERO);
+    assert!(change_item.label.is_none());
 }
 
 #[test]
@@ -569,9 +995,45 @@ fn wallet__list_labels__modelled() {
     let label = "list-label-test";
     let _ = node.client.new_address_with_label(label).expect("newaddress");
 
-    let json: ListLabels = node.client.list_labels().expect("listlabels");
+    let json: ListLabels = node.client.list_labels(None).expect("listlabels");
+    let model: mtype::ListLabels = json.into_model();
+
+    assert!(model.0.iter().any(|s| s == label));
+}
+
+#[test]
+fn wallet__list_labels__purpose() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let receive_label = "receive-label";
+    let send_label = "send-label";
+    let _ = node.client.new_address_with_label(receive_label).expect("newaddress");
+
+    // Core v0.17 has no `setlabel`, a multisig address is also given the `send` purpose.
+    #[cfg(feature = "v17")]
+    {
+        let addrs = vec![
+            node.client.new_address().expect("newaddress"),
+            node.client.new_address().expect("newaddress"),
+        ];
+        let _ = node
+            .client
+            .add_multisig_address_with_addresses(1, addrs, Some(send_label), None)
+            .expect("addmultisigaddress");
+    }
+    // Labelling an address that is not ours gives it the `send` purpose.
+    #[cfg(not(feature = "v17"))]
+    {
+        let other = Address::p2wsh(&bitcoin::ScriptBuf::new(), KnownHrp::Regtest);
+        node.client.set_label(&other, send_label).expect("setlabel");
+    }
+
+    let receive = node.client.list_labels(Some(AddressPurpose::Receive)).expect("listlabels");
+    let receive = receive.into_model().0;
+    assert!(receive.iter().any(|s| s == receive_label));
+    assert!(!receive.iter().any(|s| s == send_label));
 
-    assert!(json.0.iter().any(|s| s == label));
+    let send = node.client.list_labels(Some(AddressPurpose::Send)).expect("listlabels");
+    assert_eq!(send.into_model().0, vec![send_label.to_string()]);
 }
 
 #[test]
@@ -591,7 +1053,11 @@ fn wallet__list_received_by_label__modelled() {
         node.client.list_received_by_label().expect("listreceivedbylabel");
     let model: Result<mtype::ListReceivedByLabel, ListReceivedByLabelError> = json.into_model();
     let received_by_label = model.unwrap();
-    assert!(received_by_label.0.iter().any(|item| item.label == label));
+
+    let item = received_by_label.0.iter().find(|item| item.label == label).expect("label");
+    assert_eq!(item.amount, amount);
+    assert_eq!(item.confirmations, 1);
+    assert!(item.involves_watch_only.is_none());
 }
 
 #[test]
@@ -616,17 +1082,47 @@ fn wallet__list_received_by_address__modelled() {
 fn wallet__list_since_block__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
-    let addr = node.client.new_address().expect("newaddress");
-    let amount = Amount::from_sat(5_000);
-    node.client.send_to_address(&addr, amount).expect("sendtoaddress");
-    node.mine_a_block();
 
-    let json: ListSinceBlock = node.client.list_since_block().expect("listsinceblock");
+    let json: ListSinceBlock = node.client.list_since_block(None).expect("listsinceblock");
     let model: Result<mtype::ListSinceBlock, ListSinceBlockError> = json.into_model();
     let list_since_block = model.unwrap();
 
     let first_tx: mtype::TransactionItem = list_since_block.transactions[0].clone();
     assert_eq!(first_tx.txid.unwrap().to_string().len(), 64);
+
+    let start = node.client.best_block_hash().expect("bestblockhash");
+
+    let addr = node.client.new_address().expect("newaddress");
+    let amount = Amount::from_sat(5_000);
+    let txid =
+        node.client.send_to_address(&addr, amount).expect("sendtoaddress").txid().expect("txid");
+    // Mine to an address not in the wallet so the only wallet transaction in the block is ours.
+    let other = Address::p2wsh(&bitcoin::ScriptBuf::new(), KnownHrp::Regtest);
+    node.client.generate_to_address(1, &other, None).expect("generatetoaddress");
+    let mined = node.client.best_block_hash().expect("bestblockhash");
+
+    let json: ListSinceBlock = node.client.list_since_block(Some(start)).expect("listsinceblock");
+    let model: Result<mtype::ListSinceBlock, ListSinceBlockError> = json.into_model();
+    let list_since_block = model.unwrap();
+
+    assert_eq!(list_since_block.last_block, mined);
+    assert!(list_since_block.removed.is_empty());
+    assert!(!list_since_block.transactions.is_empty());
+    assert!(list_since_block.transactions.iter().all(|tx| tx.txid == Some(txid)));
+    assert!(list_since_block.transactions.iter().all(|tx| tx.block_hash == Some(mined)));
+
+    // Reorg the block out, the transaction gets mined again in the replacement block.
+    node.client.invalidate_block(mined).expect("invalidateblock");
+    node.client.generate_to_address(1, &other, None).expect("generatetoaddress");
+    let replacement = node.client.best_block_hash().expect("bestblockhash");
+
+    let json: ListSinceBlock = node.client.list_since_block(Some(mined)).expect("listsinceblock");
+    let model: Result<mtype::ListSinceBlock, ListSinceBlockError> = json.into_model();
+    let list_since_block = model.unwrap();
+
+    assert_eq!(list_since_block.last_block, replacement);
+    assert!(list_since_block.removed.iter().any(|tx| tx.txid == Some(txid)));
+    assert!(list_since_block.transactions.iter().any(|tx| tx.block_hash == Some(replacement)));
 }
 
 #[test]
@@ -639,12 +1135,63 @@ fn wallet__list_transactions__modelled() {
     node.client.send_to_address(&addr, amount).expect("sendtoaddress");
     node.mine_a_block();
 
-    let json: ListTransactions = node.client.list_transactions().expect("listtransactions");
+    let json: ListTransactions =
+        node.client.list_transactions(None, None, None, None).expect("listtransactions");
     let model: Result<mtype::ListTransactions, TransactionItemError> = json.into_model();
     let list_transactions = model.unwrap();
 
     let first_tx: mtype::TransactionItem = list_transactions.0[0].clone();
     assert_eq!(first_tx.txid.unwrap().to_string().len(), 64);
+
+    // Sending to ourselves creates a send and a receive item, the fee is on the send item.
+    for item in &list_transactions.0 {
+        if item.category == mtype::TransactionCategory::Send {
+            assert!(item.amount.is_negative());
+            assert!(item.fee.is_negative());
+        }
+    }
+}
+
+#[test]
+fn wallet__list_transactions__paging() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // More than the default count of 10.
+    let label = "paging";
+    let addr = node.client.new_address_with_label(label).expect("newaddress").assume_checked();
+    for _ in 0..12 {
+        node.client.send_to_address(&addr, Amount::from_sat(5_000)).expect("sendtoaddress");
+    }
+    node.mine_a_block();
+
+    let page = |skip: u32| -> Vec<mtype::TransactionItem> {
+        let json: ListTransactions = node
+            .client
+            .list_transactions(Some(label), Some(5), Some(skip), None)
+            .expect("listtransactions");
+        let model: Result<mtype::ListTransactions, TransactionItemError> = json.into_model();
+        model.unwrap().0
+    };
+
+    let pages: Vec<_> = [0, 5, 10].iter().map(|skip| page(*skip)).collect();
+    assert_eq!(pages[0].len(), 5);
+    assert_eq!(pages[1].len(), 5);
+    assert_eq!(pages[2].len(), 2);
+
+    let mut seen = std::collections::BTreeSet::new();
+    for item in pages.iter().flatten() {
+        assert_eq!(item.label.as_deref(), Some(label));
+        assert_eq!(item.category, mtype::TransactionCategory::Receive);
+        assert!(seen.insert((item.txid.expect("txid"), item.vout)), "duplicate item");
+    }
+
+    // Each page is ordered oldest first and skipping goes further back in time.
+    for page in &pages {
+        assert!(page.windows(2).all(|w| w[0].time <= w[1].time));
+    }
+    assert!(pages[1].last().unwrap().time <= pages[0].first().unwrap().time);
+    assert!(pages[2].last().unwrap().time <= pages[1].first().unwrap().time);
 }
 
 #[test]
@@ -690,7 +1237,17 @@ fn wallet__import_multi() {
         timestamp: ImportMultiTimestamp::Time(1_700_000_000),
     };
 
-    let json: ImportMulti = node.client.import_multi(&[req1, req2, req3]).expect("importmulti");
+    // Uses a malformed descriptor: success - false, with JSON-RPC error.
+    // NOTE: Works only for v18 onwards, as v17 doesn't support descriptors.
+    let req4 = ImportMultiRequest {
+        descriptor: Some("pkh(not_a_key)".to_string()),
+        script_pubkey: None,
+        timestamp: ImportMultiTimestamp::Now,
+    };
+
+    let json: ImportMulti =
+        node.client.import_multi(&[req1, req2, req3, req4]).expect("importmulti");
+    assert_eq!(json.0.len(), 4);
 
     #[cfg(not(feature = "v17"))]
     {
@@ -703,6 +1260,11 @@ fn wallet__import_multi() {
         assert!(json.0[2].success);
         assert!(json.0[2].error.is_none());
         assert!(json.0[2].warnings.is_some());
+
+        // result of req4: should fail, the rest of the batch is still imported.
+        assert!(!json.0[3].success);
+        let error = json.0[3].error.as_ref().expect("malformed descriptor error");
+        assert_eq!(error.code, -5); // RPC_INVALID_ADDRESS_OR_KEY
     }
 
     // result of req2: should fail with error (wallet already contains privkey for address/script)
@@ -726,7 +1288,29 @@ fn wallet__import_privkey() {
     let privkey =
         PrivateKey::from_wif("cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy").unwrap();
 
-    let _: () = node.client.import_privkey(&privkey).expect("importprivkey");
+    let _: () = node.client.import_privkey(&privkey, None, None).expect("importprivkey");
+}
+
+#[test]
+fn wallet__import_privkey__dumped_key() {
+    let node = legacy_wallet_node("source");
+    let label = "imported";
+
+    let address = node.client.new_address_with_type(AddressType::Legacy).expect("newaddress");
+    let json: DumpPrivKey = node.client.dump_priv_key(&address).expect("dumpprivkey");
+    let privkey = json.into_model().expect("DumpPrivKey into model").0;
+
+    replace_legacy_wallet(&node, "source", "destination");
+    let _: () =
+        node.client.import_privkey(&privkey, Some(label), Some(false)).expect("importprivkey");
+
+    let secp = secp256k1::Secp256k1::new();
+    let derived = Address::p2pkh(privkey.public_key(&secp), Network::Regtest);
+    assert_eq!(derived, address);
+
+    let json: GetAddressesByLabel =
+        node.client.get_addresses_by_label(label).expect("getaddressesbylabel");
+    assert!(json.into_model().unwrap().0.contains_key(address.as_unchecked()));
 }
 
 #[test]
@@ -775,17 +1359,17 @@ fn wallet__list_lock_unspent__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
-    let json: ListUnspent = node.client.list_unspent().expect("listunspent");
+    let json: ListUnspent =
+        node.client.list_unspent(None, None, None, None, None).expect("listunspent");
     let utxos: mtype::ListUnspent = json.into_model().unwrap();
-    let txid = utxos.0[0].txid;
-    let vout = utxos.0[0].vout;
-    node.client.lock_unspent(&[(txid, vout)]).expect("lockunspent");
+    let outpoint = OutPoint { txid: utxos.0[0].txid, vout: utxos.0[0].vout };
+    node.client.lock_unspent(false, &[outpoint]).expect("lockunspent");
 
     let json: ListLockUnspent = node.client.list_lock_unspent().expect("listlockunspent");
     let model: Result<mtype::ListLockUnspent, ListLockUnspentItemError> = json.into_model();
     let lock_unspent = model.unwrap();
 
-    assert!(lock_unspent.0.iter().any(|o| o.txid == txid && o.vout == vout));
+    assert_eq!(lock_unspent.0, vec![outpoint]);
 }
 
 #[test]
@@ -799,22 +1383,53 @@ fn wallet__list_unspent__modelled() {
 
     node.fund_wallet();
 
-    let json: ListUnspent = node.client.list_unspent().expect("listunspent");
+    let json: ListUnspent =
+        node.client.list_unspent(None, None, None, None, None).expect("listunspent");
     let model: Result<mtype::ListUnspent, ListUnspentItemError> = json.into_model();
     model.unwrap();
+
+    // Create a dust sized UTXO and filter it out using `minimumAmount`.
+    let address = node.client.new_address().expect("newaddress");
+    let dust = Amount::from_sat(1_000);
+    node.client.send_to_address(&address, dust).expect("sendtoaddress");
+    node.mine_a_block();
+
+    let addresses = [address];
+    let json: ListUnspent =
+        node.client.list_unspent(None, None, Some(&addresses), None, None).expect("listunspent");
+    let model: Result<mtype::ListUnspent, ListUnspentItemError> = json.into_model();
+    let utxos = model.unwrap();
+    assert_eq!(utxos.0.len(), 1);
+    assert_eq!(utxos.0[0].amount, dust.to_signed().unwrap());
+
+    let query_options = ListUnspentQueryOptions {
+        minimum_amount: Some(Amount::from_sat(10_000)),
+        ..Default::default()
+    };
+    let json: ListUnspent = node
+        .client
+        .list_unspent(Some(1), None, None, None, Some(query_options))
+        .expect("listunspent");
+    let model: Result<mtype::ListUnspent, ListUnspentItemError> = json.into_model();
+    let utxos = model.unwrap();
+    assert!(!utxos.0.is_empty());
+    assert!(utxos
+        .0
+        .iter()
+        .all(|utxo| utxo.amount >= Amount::from_sat(10_000).to_signed().unwrap()));
 }
 
 #[test]
 #[cfg(not(feature = "v17"))]
-fn wallet__list_wallet_dir() {
+fn wallet__list_wallet_dir__modelled() {
     let wallet_name = "test-wallet";
     let node = Node::with_wallet(Wallet::None, &[]);
     node.client.create_wallet(wallet_name).expect("failed to create wallet");
 
-    let wallet_dir = node.client.list_wallet_dir().expect("listwalletdir");
-    let wallet_names: Vec<_> = wallet_dir.wallets.iter().map(|w| &w.name).collect();
+    let json: ListWalletDir = node.client.list_wallet_dir().expect("listwalletdir");
+    let model: mtype::ListWalletDir = json.into_model();
 
-    assert!(wallet_names.iter().any(|w| *w == wallet_name));
+    assert!(model.wallets.iter().any(|w| w == wallet_name));
 }
 
 #[test]
@@ -835,21 +1450,55 @@ fn wallet__list_wallets__modelled() {
 #[test]
 fn wallet__load_wallet__modelled() { create_load_unload_wallet(); }
 
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn wallet__load_wallet__load_on_startup() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+    let settings = node.workdir().join("regtest").join("settings.json");
+
+    let wallet = format!("wallet-{}", rand::random::<u32>());
+    node.client.create_wallet(&wallet).expect("createwallet");
+    let _ = node.client.unload_wallet(&wallet, None).expect("unloadwallet");
+
+    let json: LoadWallet = node.client.load_wallet(&wallet, Some(true)).expect("loadwallet");
+    let model: mtype::LoadWallet = json.into_model();
+    assert_eq!(model.name, wallet);
+    assert!(model.warnings.is_empty());
+
+    let contents = fs::read_to_string(&settings).expect("failed to read settings.json");
+    assert!(contents.contains(&wallet), "wallet not listed in settings: {}", contents);
+
+    let _ = node.client.unload_wallet(&wallet, Some(false)).expect("unloadwallet");
+    let contents = fs::read_to_string(&settings).expect("failed to read settings.json");
+    assert!(!contents.contains(&wallet), "wallet still listed in settings: {}", contents);
+}
+
 #[test]
 fn wallet__lock_unspent() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
 
-    let json: ListUnspent = node.client.list_unspent().expect("listunspent");
-    let utxos: mtype::ListUnspent = json.into_model().unwrap();
-    let txid = utxos.0[0].txid;
-    let vout = utxos.0[0].vout;
+    let unspent = || -> Vec<OutPoint> {
+        let json: ListUnspent =
+            node.client.list_unspent(None, None, None, None, None).expect("listunspent");
+        let utxos: mtype::ListUnspent = json.into_model().unwrap();
+        utxos.0.iter().map(|utxo| OutPoint { txid: utxo.txid, vout: utxo.vout }).collect()
+    };
+    let locked = || -> Vec<OutPoint> {
+        let json: ListLockUnspent = node.client.list_lock_unspent().expect("listlockunspent");
+        json.into_model().unwrap().0
+    };
+    let outpoint = unspent()[0];
 
-    let locked: LockUnspent = node.client.lock_unspent(&[(txid, vout)]).expect("lockunspent");
-    assert!(locked.0, "lock_unspent");
+    let json: LockUnspent = node.client.lock_unspent(false, &[outpoint]).expect("lockunspent");
+    assert!(json.0, "lock_unspent");
+    assert_eq!(locked(), vec![outpoint]);
+    assert!(!unspent().contains(&outpoint));
 
-    let unlocked: LockUnspent = node.client.unlock_unspent(&[(txid, vout)]).expect("unlockunspent");
-    assert!(unlocked.0, "unlock_unspent");
+    let json: LockUnspent = node.client.lock_unspent(true, &[outpoint]).expect("lockunspent");
+    assert!(json.0, "unlock_unspent");
+    assert!(locked().is_empty());
+    assert!(unspent().contains(&outpoint));
 }
 
 #[test]
@@ -877,7 +1526,7 @@ fn wallet__new_keypool() {
 fn wallet__psbt_bump_fee__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
     let address = node.client.new_address().expect("failed to create new address");
-    let _ = node.client.generate_to_address(101, &address).expect("generatetoaddress");
+    let _ = node.client.generate_to_address(101, &address, None).expect("generatetoaddress");
 
     let txid = node
         .client
@@ -888,7 +1537,19 @@ fn wallet__psbt_bump_fee__modelled() {
 
     let json: PsbtBumpFee = node.client.psbt_bump_fee(&txid).expect("psbtbumpfee");
     let model: Result<mtype::PsbtBumpFee, PsbtBumpFeeError> = json.into_model();
-    model.unwrap();
+    let bump = model.unwrap();
+
+    assert!(bump.fee > bump.original_fee);
+    assert!(bump.errors.is_empty());
+    // The replacement spends the same inputs as the original transaction.
+    let original = node.client.get_transaction(txid).expect("gettransaction");
+    let original = original.into_model().unwrap().tx;
+    let inputs = |tx: &bitcoin::Transaction| {
+        let mut outpoints: Vec<_> = tx.input.iter().map(|txin| txin.previous_output).collect();
+        outpoints.sort();
+        outpoints
+    };
+    assert_eq!(inputs(&bump.psbt.unsigned_tx), inputs(&original));
 }
 
 #[test]
@@ -900,7 +1561,7 @@ fn wallet__remove_pruned_funds() {
     let txid = tx.compute_txid();
 
     let raw_tx = node.client.get_raw_transaction(txid).expect("getrawtransaction");
-    let tx_out_proof = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+    let tx_out_proof = node.client.get_tx_out_proof(&[txid], None).expect("gettxoutproof");
 
     let _: () =
         node.client.import_pruned_funds(&raw_tx.0, &tx_out_proof).expect("importprunedfunds");
@@ -913,13 +1574,26 @@ fn wallet__rescan_blockchain__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
     let mining_addr = node.client.new_address().expect("newaddress");
-    let _ = node.client.generate_to_address(3, &mining_addr).expect("generatetoaddress");
+    let _ = node.client.generate_to_address(3, &mining_addr, None).expect("generatetoaddress");
 
-    let json: RescanBlockchain = node.client.rescan_blockchain().expect("rescanblockchain");
+    let json: RescanBlockchain =
+        node.client.rescan_blockchain(None, None).expect("rescanblockchain");
     let model: Result<mtype::RescanBlockchain, NumericError> = json.into_model();
     let rescan = model.unwrap();
-
-    assert!(rescan.stop_height >= rescan.start_height);
+    assert_eq!(rescan.start_height, 0);
+    assert_eq!(rescan.stop_height, 3);
+
+    let json: RescanBlockchain =
+        node.client.rescan_blockchain(Some(1), Some(2)).expect("rescanblockchain range");
+    let rescan = json.into_model().unwrap();
+    assert_eq!(rescan.start_height, 1);
+    assert_eq!(rescan.stop_height, 2);
+
+    let json: RescanBlockchain =
+        node.client.rescan_blockchain(Some(2), None).expect("rescanblockchain start only");
+    let rescan = json.into_model().unwrap();
+    assert_eq!(rescan.start_height, 2);
+    assert_eq!(rescan.stop_height, 3);
 }
 
 // This is tested in `backup_and_restore_wallet()`, called by wallet__backup_wallet()
@@ -927,7 +1601,8 @@ fn wallet__rescan_blockchain__modelled() {
 #[cfg(not(feature = "v22_and_below"))]
 fn wallet__restore_wallet() {}
 
-// This is tested in raw_transactions.rs `create_sign_send()`.
+// This is tested in raw_transactions.rs `create_sign_send()` and
+// `raw_transactions__sign_raw_transaction_with_wallet__prev_txs()`.
 #[test]
 fn wallet__sign_raw_transaction_with_wallet__modelled() {}
 
@@ -943,12 +1618,46 @@ fn wallet__send_many__modelled() {
     let addr2 = node.client.new_address().expect("newaddress");
 
     let mut amounts = BTreeMap::new();
-    amounts.insert(addr1, Amount::from_sat(100_000));
-    amounts.insert(addr2, Amount::from_sat(100_000));
+    amounts.insert(addr1.clone(), Amount::from_sat(100_000));
+    amounts.insert(addr2.clone(), Amount::from_sat(200_000));
 
-    let json: SendMany = node.client.send_many(amounts.clone()).expect("sendmany");
+    let json: SendMany =
+        node.client.send_many(amounts.clone(), None, &[], None, None, None).expect("sendmany");
     let model: Result<mtype::SendMany, hex::HexToArrayError> = json.into_model();
-    model.unwrap();
+    let txid = model.unwrap().0;
+    node.mine_a_block();
+
+    let tx = node.client.get_transaction(txid).expect("gettransaction").into_model().unwrap();
+    assert_eq!(tx.confirmations, 1);
+    let received = |addr| node.client.get_received_by_address(addr, None).unwrap();
+    assert_eq!(received(&addr1).into_model().unwrap().0, Amount::from_sat(100_000));
+    assert_eq!(received(&addr2).into_model().unwrap().0, Amount::from_sat(200_000));
+
+    // Subtract the fee from one of the outputs, the other receives the exact amount.
+    let addr3 = node.client.new_address().expect("newaddress");
+    let addr4 = node.client.new_address().expect("newaddress");
+    let mut amounts_fee = BTreeMap::new();
+    amounts_fee.insert(addr3.clone(), Amount::from_sat(100_000));
+    amounts_fee.insert(addr4.clone(), Amount::from_sat(200_000));
+
+    let json: SendMany = node
+        .client
+        .send_many(
+            amounts_fee,
+            Some("a comment"),
+            std::slice::from_ref(&addr4),
+            Some(true),
+            Some(6),
+            Some(EstimateMode::Economical),
+        )
+        .expect("sendmany with options");
+    let txid = json.into_model().unwrap().0;
+    node.mine_a_block();
+
+    let tx = node.client.get_transaction(txid).expect("gettransaction").into_model().unwrap();
+    assert_eq!(tx.confirmations, 1);
+    assert_eq!(received(&addr3).into_model().unwrap().0, Amount::from_sat(100_000));
+    assert!(received(&addr4).into_model().unwrap().0 < Amount::from_sat(200_000));
 
     #[cfg(not(feature = "v20_and_below"))]
     {
@@ -970,11 +1679,21 @@ fn wallet__send__modelled() {
     let address = node.client.new_address().expect("failed to create new address");
 
     let mut outputs = BTreeMap::new();
-    outputs.insert(address.to_string(), 0.001);
+    outputs.insert(address.clone(), Amount::from_sat(100_001));
 
     let json: Send = node.client.send(&outputs).expect("send");
     let model: Result<mtype::Send, SendError> = json.into_model();
-    model.unwrap();
+    let send = model.unwrap();
+    assert!(send.complete);
+    assert!(send.txid.is_some());
+    node.mine_a_block();
+
+    let json = node.client.get_received_by_address(&address, None).expect("getreceivedbyaddress");
+    assert_eq!(json.into_model().unwrap().0, Amount::from_sat(100_001));
+
+    // A complete transaction must come with either a txid or the raw transaction.
+    let json = Send { complete: true, txid: None, hex: None, psbt: None };
+    assert!(matches!(json.into_model(), Err(SendError::Incomplete)));
 }
 
 #[test]
@@ -1001,13 +1720,81 @@ fn wallet__send_to_address__modelled() {
     model.unwrap();
 }
 
+#[test]
+fn wallet__send_to_address__estimate_mode() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let address = node.client.new_address().expect("failed to create new address");
+
+    for mode in [EstimateMode::Unset, EstimateMode::Economical, EstimateMode::Conservative] {
+        let json: SendToAddress = node
+            .client
+            .send_to_address_with_conf_target(
+                &address,
+                Amount::from_sat(10_000),
+                Some(6),
+                Some(mode),
+            )
+            .expect("sendtoaddress");
+        json.into_model().unwrap();
+    }
+}
+
+#[test]
+fn wallet__send_to_address__satoshi_precision() {
+    // A single satoshi is dust and rejected by the wallet, use the smallest non-dust value that
+    // still needs every decimal place of the BTC amount.
+    let amount = Amount::from_sat(1_001);
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let address = node.client.new_address().expect("failed to create new address");
+
+    let _ = node.client.send_to_address(&address, amount).expect("sendtoaddress");
+    let _ = node.client.send_to_address_rbf(&address, amount).expect("sendtoaddress rbf");
+    node.mine_a_block();
+
+    let json = node.client.get_received_by_address(&address, None).expect("getreceivedbyaddress");
+    assert_eq!(json.into_model().unwrap().0, amount * 2);
+}
+
+#[test]
+#[cfg(not(feature = "v17"))]
+fn wallet__set_label() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let label = "set-label-test";
+
+    let own = node.client.new_address().expect("newaddress");
+    let other = Address::p2wsh(&bitcoin::ScriptBuf::new(), KnownHrp::Regtest);
+    let _: () = node.client.set_label(&own, label).expect("setlabel");
+    let _: () = node.client.set_label(&other, label).expect("setlabel");
+
+    let json: GetAddressesByLabel =
+        node.client.get_addresses_by_label(label).expect("getaddressesbylabel");
+    let map = json.into_model().unwrap().0;
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(own.as_unchecked()), Some(&mtype::AddressPurpose::Receive));
+    assert_eq!(map.get(other.as_unchecked()), Some(&mtype::AddressPurpose::Send));
+}
+
 #[test]
 fn wallet__set_tx_fee() {
+    use node::client::client_sync;
+
     let node = Node::with_wallet(Wallet::Default, &[]);
     let fee_rate = FeeRate::from_sat_per_vb(2).expect("2 sat/vb is valid");
 
-    let json: SetTxFee = node.client.set_tx_fee(fee_rate).expect("settxfee");
-    assert!(json.0);
+    let set: bool = node.client.set_tx_fee(fee_rate).expect("settxfee");
+    assert!(set);
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    let model = json.into_model().expect("GetWalletInfo into model");
+    assert_eq!(model.pay_tx_fee, Some(fee_rate));
+
+    // A zero fee rate is rejected before calling Core.
+    let err = node.client.set_tx_fee(FeeRate::ZERO).expect_err("settxfee should fail");
+    assert!(matches!(err, client_sync::Error::InvalidArgument(_)));
 }
 
 #[test]
@@ -1015,9 +1802,15 @@ fn wallet__set_tx_fee() {
 fn wallet__set_wallet_flag() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
-    let json: SetWalletFlag = node.client.set_wallet_flag("avoid_reuse").expect("setwalletflag");
+    let json: SetWalletFlag =
+        node.client.set_wallet_flag("avoid_reuse", None).expect("setwalletflag");
     assert_eq!(json.flag_name, "avoid_reuse");
     assert!(json.flag_state);
+
+    let json: SetWalletFlag =
+        node.client.set_wallet_flag("avoid_reuse", Some(false)).expect("setwalletflag");
+    assert_eq!(json.flag_name, "avoid_reuse");
+    assert!(!json.flag_state);
 }
 
 #[test]
@@ -1035,7 +1828,26 @@ fn wallet__set_hd_seed() {
 
     node.fund_wallet();
 
-    let _: () = node.client.set_hd_seed().expect("sethdseed");
+    let _: () = node.client.set_hd_seed(None, None).expect("sethdseed");
+
+    let json: GetWalletInfo = node.client.get_wallet_info().expect("getwalletinfo");
+    let before = json.hd_seed_id.expect("hdseedid").parse::<hash160::Hash>().unwrap();
+
+    let new_id = node
+        .client
+        .set_hd_seed_and_get_id(Some(false), None)
+        .expect("sethdseed")
+        .expect("hdseedid");
+    assert_ne!(new_id, before);
+
+    let seed =
+        PrivateKey::from_wif("cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy").unwrap();
+    let seed_id = node
+        .client
+        .set_hd_seed_and_get_id(Some(true), Some(&seed))
+        .expect("sethdseed")
+        .expect("hdseedid");
+    assert_ne!(seed_id, new_id);
 }
 
 #[test]
@@ -1090,7 +1902,7 @@ fn wallet__wallet_create_funded_psbt__modelled() {
     let outputs = BTreeMap::from([(addr, Amount::from_sat(100_000))]);
     let json: WalletCreateFundedPsbt = node
         .client
-        .wallet_create_funded_psbt(vec![], vec![outputs])
+        .wallet_create_funded_psbt(vec![], vec![outputs], None)
         .expect("walletcreatefundedpsbt");
 
     let model: Result<mtype::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> =
@@ -1100,6 +1912,88 @@ fn wallet__wallet_create_funded_psbt__modelled() {
     assert!(!psbt.psbt.inputs.is_empty());
 }
 
+#[test]
+fn wallet__wallet_create_funded_psbt__satoshi_precision() {
+    // 0.12345678 BTC has no exact f64 representation.
+    let amount = Amount::from_sat(12_345_678);
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let addr = node.client.new_address().expect("newaddress");
+    let outputs = BTreeMap::from([(addr.clone(), amount)]);
+    let json: WalletCreateFundedPsbt = node
+        .client
+        .wallet_create_funded_psbt(vec![], vec![outputs], None)
+        .expect("walletcreatefundedpsbt");
+    let funded = json.into_model().unwrap();
+
+    let output = funded
+        .psbt
+        .unsigned_tx
+        .output
+        .iter()
+        .find(|output| output.script_pubkey == addr.script_pubkey())
+        .expect("output to addr");
+    assert_eq!(output.value, amount);
+}
+
+#[test]
+fn wallet__wallet_create_funded_psbt__options() {
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // Put the change at the front and lock the selected inputs.
+    let addr = node.client.new_address().expect("newaddress");
+    let change_address = node.client.new_address().expect("newaddress");
+    let outputs = BTreeMap::from([(addr, Amount::from_sat(100_000))]);
+    let options = WalletCreateFundedPsbtOptions {
+        change_address: Some(change_address.clone()),
+        change_position: Some(0),
+        lock_unspents: Some(true),
+        ..Default::default()
+    };
+    let json: WalletCreateFundedPsbt = node
+        .client
+        .wallet_create_funded_psbt(vec![], vec![outputs], Some(&options))
+        .expect("walletcreatefundedpsbt");
+    let model: Result<mtype::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> =
+        json.into_model();
+    let funded = model.unwrap();
+
+    assert_eq!(funded.change_position, Some(0));
+    let tx = &funded.psbt.unsigned_tx;
+    assert_eq!(tx.output[0].script_pubkey, change_address.script_pubkey());
+    assert!(funded.fee > Amount::ZERO);
+
+    let json: ListLockUnspent = node.client.list_lock_unspent().expect("listlockunspent");
+    let locked = json.into_model().unwrap();
+    for input in &tx.input {
+        assert!(locked.0.contains(&input.previous_output));
+    }
+
+    // Spending a whole UTXO with the fee taken from the output leaves no change.
+    let outpoint = tx.input[0].previous_output;
+    node.client.lock_unspent(true, &[outpoint]).expect("lockunspent");
+    let value = funded.psbt.inputs[0].witness_utxo.as_ref().expect("witness_utxo").value;
+    let addr = node.client.new_address().expect("newaddress");
+    let inputs = vec![WalletCreateFundedPsbtInput::new(outpoint.txid, outpoint.vout)];
+    let outputs = BTreeMap::from([(addr, value)]);
+    let options =
+        WalletCreateFundedPsbtOptions { subtract_fee_from_outputs: vec![0], ..Default::default() };
+    let json: WalletCreateFundedPsbt = node
+        .client
+        .wallet_create_funded_psbt(inputs, vec![outputs], Some(&options))
+        .expect("walletcreatefundedpsbt");
+    let model: Result<mtype::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> =
+        json.into_model();
+    let funded = model.unwrap();
+
+    assert_eq!(funded.change_position, None);
+    assert_eq!(funded.psbt.unsigned_tx.output.len(), 1);
+    assert_eq!(funded.psbt.unsigned_tx.output[0].value, value - funded.fee);
+}
+
 #[test]
 fn wallet__wallet_process_psbt__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
@@ -1109,21 +2003,39 @@ fn wallet__wallet_process_psbt__modelled() {
     let outputs = BTreeMap::from([(addr, Amount::from_sat(50_000))]);
     let funded_psbt: WalletCreateFundedPsbt = node
         .client
-        .wallet_create_funded_psbt(vec![], vec![outputs])
+        .wallet_create_funded_psbt(vec![], vec![outputs], None)
         .expect("walletcreatefundedpsbt");
     let model: Result<mtype::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> =
         funded_psbt.into_model();
     let funded_psbt_model = model.unwrap();
 
-    let json: WalletProcessPsbt =
-        node.client.wallet_process_psbt(&funded_psbt_model.psbt).expect("walletprocesspsbt");
     #[cfg(feature = "v25_and_below")]
     type WalletProcessPsbtError = psbt::PsbtParseError;
 
+    // Without signing the PSBT is updated but no signatures are added.
+    let json: WalletProcessPsbt = node
+        .client
+        .wallet_process_psbt(&funded_psbt_model.psbt, Some(false))
+        .expect("walletprocesspsbt");
+    assert!(!json.complete);
+    json.psbt.parse::<bitcoin::Psbt>().expect("valid base64 PSBT");
+
     let model: Result<mtype::WalletProcessPsbt, WalletProcessPsbtError> = json.into_model();
-    let processed = model.unwrap();
+    let unsigned = model.unwrap();
+    assert_eq!(unsigned.psbt.inputs.len(), funded_psbt_model.psbt.inputs.len());
+    assert!(unsigned.psbt.inputs.iter().all(|input| input.final_script_witness.is_none()));
+
+    // Signing (the default) finalizes all inputs owned by the wallet.
+    let json: WalletProcessPsbt =
+        node.client.wallet_process_psbt(&funded_psbt_model.psbt, None).expect("walletprocesspsbt");
+    assert!(json.complete);
+    json.psbt.parse::<bitcoin::Psbt>().expect("valid base64 PSBT");
 
-    assert_eq!(processed.psbt.inputs.len(), funded_psbt_model.psbt.inputs.len());
+    let model: Result<mtype::WalletProcessPsbt, WalletProcessPsbtError> = json.into_model();
+    let signed = model.unwrap();
+    assert!(signed.complete);
+    assert_eq!(signed.psbt.inputs.len(), funded_psbt_model.psbt.inputs.len());
+    assert!(signed.psbt.inputs.iter().all(|input| input.final_script_witness.is_some()));
 }
 
 #[test]
@@ -1143,10 +2055,38 @@ fn wallet__wallet_passphrase() {
     node.client.create_wallet("wallet_name").expect("createwallet");
     node.client.encrypt_wallet("passphrase").expect("encryptwallet");
 
-    let timeout = 60u64;
+    let timeout = Duration::from_secs(60);
     let _: () = node.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
 }
 
+#[test]
+fn wallet__wallet_passphrase__lock_unlock_sign() {
+    use node::client::RpcErrorCode;
+
+    let node = Node::with_wallet(Wallet::Default, &[]);
+    let address = node.client.new_address_with_type(AddressType::Legacy).expect("newaddress");
+    let message = "integration test message";
+
+    let _: EncryptWallet = node.client.encrypt_wallet("passphrase").expect("encryptwallet");
+
+    // A newly encrypted wallet starts out locked.
+    let err = node.client.sign_message(&address, message).expect_err("signmessage while locked");
+    assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::WalletUnlockNeeded));
+
+    let timeout = Duration::from_secs(60);
+    node.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
+    let _: SignMessage = node.client.sign_message(&address, message).expect("signmessage");
+
+    node.client.wallet_lock().expect("walletlock");
+    let err = node.client.sign_message(&address, message).expect_err("signmessage while locked");
+    assert_eq!(err.rpc_error_code(), Some(RpcErrorCode::WalletUnlockNeeded));
+
+    // A sub-second timeout is rounded up rather than down to zero.
+    let timeout = Duration::from_millis(500);
+    node.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
+    let _: SignMessage = node.client.sign_message(&address, message).expect("signmessage");
+}
+
 #[test]
 fn wallet__wallet_passphrase_change() {
     let node = Node::with_wallet(Wallet::Default, &[]);
@@ -1160,6 +2100,40 @@ fn wallet__wallet_passphrase_change() {
         .expect("walletpassphrasechange");
 }
 
+/// Returns a node with only the legacy wallet `wallet` loaded.
+fn legacy_wallet_node(wallet: &str) -> Node {
+    let args: &[&str] = match () {
+        #[cfg(feature = "v22_and_below")]
+        () => &[],
+        #[cfg(not(feature = "v22_and_below"))]
+        () => &["-deprecatedrpc=create_bdb"],
+    };
+    let node = Node::with_wallet(Wallet::None, args);
+    create_legacy_wallet(&node, wallet);
+    node
+}
+
+/// Unloads `wallet` and loads a new legacy wallet `new_wallet` in its place.
+///
+/// Only one wallet can be loaded at a time since the client uses the base URL.
+fn replace_legacy_wallet(node: &Node, wallet: &str, new_wallet: &str) {
+    #[cfg(feature = "v20_and_below")]
+    node.client.unload_wallet(wallet).expect("unloadwallet");
+    #[cfg(not(feature = "v20_and_below"))]
+    node.client.unload_wallet(wallet, None).expect("unloadwallet");
+
+    create_legacy_wallet(node, new_wallet);
+}
+
+/// Creates a wallet that supports `dumpwallet` and `importwallet`.
+fn create_legacy_wallet(node: &Node, wallet: &str) {
+    // As of Core v23 `createwallet` defaults to a native descriptor wallet.
+    #[cfg(feature = "v22_and_below")]
+    node.client.create_wallet(wallet).expect("createwallet");
+    #[cfg(not(feature = "v22_and_below"))]
+    node.client.create_legacy_wallet(wallet).expect("createlegacywallet");
+}
+
 fn create_load_unload_wallet() {
     let node = Node::with_wallet(Wallet::None, &[]);
 
@@ -1173,17 +2147,31 @@ fn create_load_unload_wallet() {
     // From version 21 Core returns warnings for `unloadwallet`.
     #[cfg(not(feature = "v20_and_below"))]
     {
-        let json: UnloadWallet = node.client.unload_wallet(&wallet).expect("unloadwallet");
+        let json: UnloadWallet = node.client.unload_wallet(&wallet, None).expect("unloadwallet");
         let _: mtype::UnloadWallet = json.into_model();
     }
 
+    // From version 21 `loadwallet` takes an optional `load_on_startup` argument.
+    #[cfg(feature = "v20_and_below")]
     let _: LoadWallet = node.client.load_wallet(&wallet).expect("loadwallet");
+    #[cfg(not(feature = "v20_and_below"))]
+    let _: LoadWallet = node.client.load_wallet(&wallet, None).expect("loadwallet");
 }
 
 #[test]
 #[cfg(not(feature = "v20_and_below"))]
-fn wallet__upgrade_wallet() {
+fn wallet__upgrade_wallet__modelled() {
     let node = Node::with_wallet(Wallet::Default, &[]);
 
-    let _: UpgradeWallet = node.client.upgrade_wallet().expect("upgradewallet");
+    // New wallets are always created at the latest version so there is nothing to upgrade.
+    let json: UpgradeWallet = node.client.upgrade_wallet(None).expect("upgradewallet");
+    let model: Result<mtype::UpgradeWallet, UpgradeWalletError> = json.into_model();
+    let upgrade = model.unwrap();
+    assert_eq!(upgrade.previous_version, upgrade.current_version);
+    assert!(upgrade.result.is_some());
+
+    // Downgrading is not allowed, the error is surfaced by the conversion.
+    let json: UpgradeWallet = node.client.upgrade_wallet(Some(10_500)).expect("upgradewallet");
+    let model: Result<mtype::UpgradeWallet, UpgradeWalletError> = json.into_model();
+    assert!(matches!(model, Err(UpgradeWalletError::Failed(_))));
 }
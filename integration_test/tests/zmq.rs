@@ -6,6 +6,7 @@
 #![allow(unused_imports)] // Because of feature gated tests.
 
 use integration_test::{Node, NodeExt as _, Wallet};
+use node::mtype;
 use node::vtype::*; // All the version specific types.
 
 #[test]
@@ -20,4 +21,19 @@ fn zmq__get_zmq_notifications__modelled() {
     let zmq_notification = &list[0];
     assert_eq!(zmq_notification.type_, "pubhashblock");
     assert_eq!(zmq_notification.address, "tcp://127.0.0.1:29000");
+
+    let model: Result<Vec<mtype::GetZmqNotifications>, GetZmqNotificationsError> =
+        list.into_iter().map(|n| n.into_model()).collect();
+    let model = model.unwrap();
+    assert_eq!(model[0].address, "tcp://127.0.0.1:29000");
+    assert_eq!(model[0].hwm, Some(1000));
+}
+
+#[test]
+fn zmq__get_zmq_notifications__not_configured() {
+    let node = Node::with_wallet(Wallet::None, &[]);
+
+    let list: Vec<GetZmqNotifications> =
+        node.client.get_zmq_notifications().expect("getzmqnotifications");
+    assert!(list.is_empty());
 }
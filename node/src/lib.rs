@@ -509,10 +509,15 @@ impl Node {
         auth: &Auth,
         wallet: &str,
     ) -> anyhow::Result<Client> {
+        // From Core v0.21 `loadwallet` takes an optional `load_on_startup` argument.
+        #[cfg(feature = "0_21_2")]
+        let load_wallet = |wallet| client_base.load_wallet(wallet, None);
+        #[cfg(not(feature = "0_21_2"))]
+        let load_wallet = |wallet| client_base.load_wallet(wallet);
+
         for _ in 0..10 {
             // Try to create the wallet, or if that fails it might already exist so try to load it.
-            if client_base.create_wallet(wallet).is_ok() || client_base.load_wallet(wallet).is_ok()
-            {
+            if client_base.create_wallet(wallet).is_ok() || load_wallet(wallet).is_ok() {
                 let url = format!("{}/wallet/{}", rpc_url, wallet);
                 return Client::new_with_auth(&url, auth.clone())
                     .map_err(|e| Error::NoBitcoindInstance(e.to_string()).into());
@@ -683,7 +688,7 @@ mod test {
 
         assert_eq!(0, info.blocks);
         let address = node.client.new_address().unwrap();
-        let _ = node.client.generate_to_address(1, &address).unwrap();
+        let _ = node.client.generate_to_address(1, &address, None).unwrap();
         let info = node.client.get_blockchain_info().unwrap();
         assert_eq!(1, info.blocks);
     }
@@ -733,7 +738,7 @@ mod test {
         // Wallet balance should be 50
         let node = Node::with_conf(exe_path().unwrap(), &conf).unwrap();
         let core_addrs = node.client.new_address().unwrap();
-        node.client.generate_to_address(101, &core_addrs).unwrap();
+        node.client.generate_to_address(101, &core_addrs, None).unwrap();
         let wallet_balance_1 = node.client.get_balance().unwrap();
         let best_block_1 = node.client.get_best_block_hash().unwrap();
 
@@ -791,8 +796,8 @@ mod test {
         let alice_address = alice.new_address().unwrap();
         let bob = node.create_wallet("bob").unwrap();
         let bob_address = bob.new_address().unwrap();
-        node.client.generate_to_address(1, &alice_address).unwrap();
-        node.client.generate_to_address(101, &bob_address).unwrap();
+        node.client.generate_to_address(1, &alice_address, None).unwrap();
+        node.client.generate_to_address(101, &bob_address, None).unwrap();
 
         let balances = alice.get_balances().unwrap();
         let alice_balances: vtype::GetBalances = balances;
@@ -878,11 +883,26 @@ mod test {
         assert_eq!(0, info.blocks);
 
         let address = client.new_address().unwrap();
-        let _ = client.generate_to_address(1, &address).unwrap();
+        let _ = client.generate_to_address(1, &address, None).unwrap();
         let info = node.client.get_blockchain_info().unwrap();
         assert_eq!(1, info.blocks);
     }
 
+    #[test]
+    fn test_client_reloads_cookie_file() {
+        let exe = init();
+        let node = Node::new(exe).unwrap();
+
+        // A stale cookie, as left behind by a previous run of bitcoind.
+        let cookie_file = node.workdir().join("stale.cookie");
+        std::fs::write(&cookie_file, "__cookie__:stale").unwrap();
+        let client = Client::new_from_cookie_file(&node.rpc_url(), &cookie_file).unwrap();
+
+        std::fs::copy(&node.params.cookie_file, &cookie_file).unwrap();
+        let info = client.get_blockchain_info().unwrap();
+        assert_eq!(0, info.blocks);
+    }
+
     #[test]
     fn test_get_cookie_user_and_pass() {
         let exe = init();
@@ -60,6 +60,13 @@ pub fn to_u32(value: i64, field: &str) -> Result<u32, NumericError> {
     u32::try_from(value).map_err(|_| NumericError::Overflow { value, field: field.to_owned() })
 }
 
+/// Converts an `i64` numeric type to a `u64`.
+///
+/// See [`to_u32`] for why the version specific JSON types use `i64`.
+pub fn to_u64(value: i64, field: &str) -> Result<u64, NumericError> {
+    u64::try_from(value).map_err(|_| NumericError::Negative { value, field: field.to_owned() })
+}
+
 /// Error converting an `i64` to a `u32`.
 ///
 /// If we expect a numeric value to sanely fit inside a `u32` we use that type in the `model`
@@ -88,14 +95,81 @@ impl fmt::Display for NumericError {
 impl std::error::Error for NumericError {}
 
 /// Converts `fee_rate` in BTC/kB to `FeeRate`.
+///
+/// Errors if `btc_per_kb` is negative. The result is rounded to the nearest sat/kwu, rounding
+/// down to whole sat/vB would turn any rate below 1 sat/vB into zero.
 fn btc_per_kb(btc_per_kb: f64) -> Result<Option<FeeRate>, ParseAmountError> {
     let sats_per_kb = Amount::from_btc(btc_per_kb)?;
-    let sats_per_byte = sats_per_kb.to_sat() / 1000;
 
-    // Virtual bytes equal bytes before segwit.
-    let rate = FeeRate::from_sat_per_vb(sats_per_byte);
+    // Virtual bytes equal bytes before segwit and one kvB is four kwu.
+    let sats_per_kwu = sats_per_kb.to_sat().saturating_add(2) / 4;
+    let rate = FeeRate::from_sat_per_kwu(sats_per_kwu);
+
+    Ok(Some(rate))
+}
+
+/// Converts a node `address` and `port` into a `NodeAddressKind`.
+///
+/// Onion and I2P addresses are not IP addresses, these are kept as strings.
+fn node_address_kind(address: String, port: u16) -> model::NodeAddressKind {
+    match address.parse::<std::net::IpAddr>() {
+        Ok(ip) => model::NodeAddressKind::Socket(std::net::SocketAddr::new(ip, port)),
+        Err(_) => model::NodeAddressKind::Other { address, port },
+    }
+}
+
+/// Converts a peer address of the form "host:port" into a `NodeAddressKind`.
+///
+/// Errors if the port is missing or not a valid port number.
+fn peer_address(address: &str) -> Result<model::NodeAddressKind, core::num::ParseIntError> {
+    if let Ok(socket) = address.parse::<std::net::SocketAddr>() {
+        return Ok(model::NodeAddressKind::Socket(socket));
+    }
+    let (host, port) = address.rsplit_once(':').unwrap_or((address, ""));
+    let port = port.parse::<u16>()?;
+    Ok(node_address_kind(host.to_owned(), port))
+}
+
+/// Converts a banned subnet as returned by `listbanned` (e.g. "192.0.2.0/24") into a `BannedSubnet`.
+///
+/// Subnets that are not in CIDR notation (e.g. onion addresses or non-contiguous netmasks) are kept
+/// as strings.
+fn banned_subnet(subnet: String) -> model::BannedSubnet {
+    let cidr = subnet.split_once('/').and_then(|(address, prefix_len)| {
+        let address = address.parse::<std::net::IpAddr>().ok()?;
+        let prefix_len = prefix_len.parse::<u8>().ok()?;
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        (prefix_len <= max).then_some((address, prefix_len))
+    });
+    match cidr {
+        Some((address, prefix_len)) => model::BannedSubnet::Ip { address, prefix_len },
+        None => match subnet.parse::<std::net::IpAddr>() {
+            Ok(address) => {
+                let prefix_len = if address.is_ipv4() { 32 } else { 128 };
+                model::BannedSubnet::Ip { address, prefix_len }
+            }
+            Err(_) => model::BannedSubnet::Other(subnet),
+        },
+    }
+}
+
+/// Converts a `warning` field into an option, Core returns an empty string if there is no warning.
+fn warning_to_option(warning: String) -> Option<String> {
+    if warning.is_empty() {
+        None
+    } else {
+        Some(warning)
+    }
+}
+
+/// Returns `true` if `checksum` is a valid output descriptor checksum.
+///
+/// Bitcoin Core descriptor checksums are 8 characters long and use the charset defined in
+/// `src/script/descriptor.cpp`.
+fn is_descriptor_checksum(checksum: &str) -> bool {
+    const CHECKSUM_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
 
-    Ok(rate)
+    checksum.len() == 8 && checksum.chars().all(|c| CHECKSUM_CHARSET.contains(c))
 }
 
 // TODO: Remove this function if a new `Witness` constructor gets added.
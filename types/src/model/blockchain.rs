@@ -10,8 +10,8 @@ use alloc::collections::BTreeMap;
 use bitcoin::address::NetworkUnchecked;
 use bitcoin::hashes::sha256;
 use bitcoin::{
-    block, Address, Amount, Block, BlockHash, CompactTarget, FeeRate, Network, OutPoint, ScriptBuf,
-    Target, TxMerkleNode, TxOut, Txid, Weight, Work, Wtxid,
+    bip158, block, Address, Amount, Block, BlockHash, CompactTarget, FeeRate, Network, OutPoint,
+    ScriptBuf, Target, Transaction, TxMerkleNode, TxOut, Txid, Weight, Work, Wtxid,
 };
 use serde::{Deserialize, Serialize};
 
@@ -89,6 +89,60 @@ pub struct GetBlockVerboseOne {
     pub next_block_hash: Option<BlockHash>,
 }
 
+/// Models the result of JSON-RPC method `getblock` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockVerboseTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: BlockHash,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: u32,
+    /// The block size excluding witness data.
+    pub stripped_size: Option<u32>,
+    /// The block weight as defined in BIP-141.
+    pub weight: Weight,
+    /// The block height or index.
+    pub height: u32,
+    /// The block version.
+    pub version: block::Version,
+    /// The merkle root.
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<GetBlockVerboseTwoTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: u32,
+    /// The median block time expressed in UNIX epoch time.
+    pub median_time: Option<u32>,
+    /// The nonce.
+    pub nonce: u32,
+    /// The bits.
+    pub bits: CompactTarget,
+    /// The difficulty target.
+    pub target: Option<Target>, // Only from v29 onwards
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    pub chain_work: Work,
+    /// The number of transactions in the block.
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    pub previous_block_hash: Option<BlockHash>,
+    /// The hash of the next block (if available).
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// A transaction. Part of `getblock` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockVerboseTwoTransaction {
+    /// The transaction.
+    pub tx: Transaction,
+    /// The transaction fee, `None` if block undo data is not available (always `None` for the coinbase).
+    pub fee: Option<Amount>,
+}
+
 /// Models the result of JSON-RPC method `getblockchaininfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -173,9 +227,11 @@ pub struct Bip9SoftforkInfo {
     /// The bit (0-28) in the block version field used to signal this softfork (only for "started" status).
     pub bit: Option<u8>,
     /// The minimum median time past of a block at which the bit gains its meaning.
-    pub start_time: u32,
+    ///
+    /// Core uses -1 for "always active" and -2 for "never active".
+    pub start_time: i64,
     /// The median time past of a block at which the deployment is considered failed if not yet locked in.
-    pub timeout: u32,
+    pub timeout: i64,
     /// Height of the first block to which the status applies.
     pub since: u32,
     /// Minimum height of blocks for which the rules may be enforced. v0.21 and later only.
@@ -221,13 +277,13 @@ pub struct Bip9SoftforkStatistics {
 pub struct GetBlockCount(pub u64);
 
 /// Models the result of JSON-RPC method `getblockfilter`.
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(deny_unknown_fields)]
+// TODO: Support serde (currently not supported by `BlockFilter`).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetBlockFilter {
-    /// The filter data.
-    pub filter: Vec<u8>,
-    /// The hex-encoded filter header.
-    pub header: bitcoin::bip158::FilterHash,
+    /// The BIP-158 filter data.
+    pub filter: bip158::BlockFilter,
+    /// The filter header.
+    pub header: bip158::FilterHeader,
 }
 
 /// Models the result of JSON-RPC method `getblockhash`.
@@ -277,67 +333,69 @@ pub struct GetBlockHeaderVerbose {
 }
 
 /// Models the result of JSON-RPC method `getblockstats`.
+///
+/// Fields not selected with the `stats` argument are `None`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetBlockStats {
     /// Average fee in the block.
-    pub average_fee: Amount,
+    pub average_fee: Option<Amount>,
     /// Average feerate.
     pub average_fee_rate: Option<FeeRate>,
     /// Average transaction size.
-    pub average_tx_size: u32,
+    pub average_tx_size: Option<u32>,
     /// The block hash (to check for potential reorgs).
-    pub block_hash: BlockHash,
+    pub block_hash: Option<BlockHash>,
     /// Feerates at the 10th, 25th, 50th, 75th, and 90th percentile weight unit (in satoshis per virtual byte).
-    pub fee_rate_percentiles: Vec<Option<FeeRate>>,
+    pub fee_rate_percentiles: Option<Vec<FeeRate>>,
     /// The height of the block.
-    pub height: u32,
+    pub height: Option<u32>,
     /// The number of inputs (excluding coinbase).
-    pub inputs: u32,
+    pub inputs: Option<u32>,
     /// Maximum fee in the block.
-    pub max_fee: Amount,
+    pub max_fee: Option<Amount>,
     /// Maximum feerate (in satoshis per virtual byte).
     pub max_fee_rate: Option<FeeRate>,
     /// Maximum transaction size.
-    pub max_tx_size: u32,
+    pub max_tx_size: Option<u32>,
     /// Truncated median fee in the block.
-    pub median_fee: Amount,
+    pub median_fee: Option<Amount>,
     /// The block median time past.
-    pub median_time: u32,
+    pub median_time: Option<u32>,
     /// Truncated median transaction size
-    pub median_tx_size: u32,
+    pub median_tx_size: Option<u32>,
     /// Minimum fee in the block.
-    pub minimum_fee: Amount,
+    pub minimum_fee: Option<Amount>,
     /// Minimum feerate (in satoshis per virtual byte).
     pub minimum_fee_rate: Option<FeeRate>,
     /// Minimum transaction size.
-    pub minimum_tx_size: u32,
+    pub minimum_tx_size: Option<u32>,
     /// The number of outputs.
-    pub outputs: u32,
+    pub outputs: Option<u32>,
     /// The block subsidy.
-    pub subsidy: Amount,
+    pub subsidy: Option<Amount>,
     /// Total size of all segwit transactions.
-    pub segwit_total_size: u32,
+    pub segwit_total_size: Option<u32>,
     /// Total weight of all segwit transactions divided by segwit scale factor (4).
     pub segwit_total_weight: Option<Weight>,
     /// The number of segwit transactions.
-    pub segwit_txs: u32,
+    pub segwit_txs: Option<u32>,
     /// The block time.
-    pub time: u32,
+    pub time: Option<u32>,
     /// Total amount in all outputs (excluding coinbase and thus reward [ie subsidy + totalfee]).
-    pub total_out: Amount,
+    pub total_out: Option<Amount>,
     /// Total size of all non-coinbase transactions.
-    pub total_size: u32,
+    pub total_size: Option<u32>,
     /// Total weight of all non-coinbase transactions divided by segwit scale factor (4).
     pub total_weight: Option<Weight>,
     /// The fee total.
-    pub total_fee: Amount,
+    pub total_fee: Option<Amount>,
     /// The number of transactions (excluding coinbase).
-    pub txs: u32,
+    pub txs: Option<u32>,
     /// The increase/decrease in the number of unspent outputs.
-    pub utxo_increase: i32,
+    pub utxo_increase: Option<i32>,
     /// The increase/decrease in size for the utxo index (not discounting op_return and similar).
-    pub utxo_size_increase: i32,
+    pub utxo_size_increase: Option<i32>,
     /// The increase/decrease in the number of unspent outputs, not counting unspendables.
     /// v25 and later only.
     pub utxo_increase_actual: Option<i32>,
@@ -436,7 +494,7 @@ pub struct GetChainTxStats {
     /// The elapsed time in the window in seconds. Only returned if "window_block_count" is > 0.
     pub window_interval: Option<u32>,
     /// The average rate of transactions per second in the window. Only returned if "window_interval" is > 0.
-    pub tx_rate: Option<u32>,
+    pub tx_rate: Option<f64>,
 }
 
 /// Models the result of JSON-RPC method `getdeploymentinfo`.
@@ -767,6 +825,13 @@ pub struct LoadTxOutSet {
     pub path: String,
 }
 
+/// Models the result of JSON-RPC method `pruneblockchain`.
+///
+/// The height of the last block pruned, `None` if no blocks have been pruned (Core returns -1).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PruneBlockchain(pub Option<u32>);
+
 /// Models the result of the JSON-RPC method `scanblocks` whan `action = start`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ScanBlocksStart {
@@ -778,6 +843,42 @@ pub struct ScanBlocksStart {
     pub relevant_blocks: Vec<BlockHash>,
 }
 
+/// Models the result of JSON-RPC method `scantxoutset` with `action = start`.
+#[cfg(feature = "experimental-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScanTxOutSet {
+    /// Whether the scan was completed.
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    pub tx_outs: u32,
+    /// The current block height (index).
+    pub height: u32,
+    /// The hash of the block at the tip of the chain.
+    pub best_block: BlockHash,
+    /// The unspent transaction outputs that matched the scan objects.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs.
+    pub total_amount: Amount,
+}
+
+/// An unspent transaction output. Part of `scantxoutset`.
+#[cfg(feature = "experimental-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScanTxOutSetUnspent {
+    /// The outpoint of the unspent output.
+    pub outpoint: OutPoint,
+    /// The script key.
+    pub script_pubkey: ScriptBuf,
+    /// A specialized descriptor for the matched scriptPubKey.
+    pub descriptor: String,
+    /// The amount of the unspent output.
+    pub amount: Amount,
+    /// Height of the unspent transaction output.
+    pub height: u32,
+}
+
 /// Models the result of JSON-RPC method `verifytxoutproof`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
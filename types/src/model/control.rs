@@ -5,4 +5,35 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
-// Control types currently have no need for model equivalents.
+use alloc::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `logging`.
+///
+/// Map of logging category to whether debug logging is active for that category.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Logging(pub BTreeMap<String, bool>);
+
+/// Models the result of JSON-RPC method `getrpcinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRpcInfo {
+    /// All active commands.
+    pub active_commands: Vec<ActiveCommand>,
+    /// The complete file path to the debug log. v0.19 and later only.
+    pub log_path: Option<PathBuf>,
+}
+
+/// Information about an active command. Part of `getrpcinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActiveCommand {
+    /// The name of the RPC command.
+    pub method: String,
+    /// The running time of the command.
+    pub duration: Duration,
+}
@@ -8,7 +8,8 @@
 use std::collections::BTreeMap;
 
 use bitcoin::{
-    block, Amount, BlockHash, CompactTarget, SignedAmount, Target, Transaction, Txid, Weight, Wtxid,
+    block, Amount, BlockHash, CompactTarget, Network, SignedAmount, Target, Transaction, Txid,
+    Weight, Wtxid,
 };
 use serde::{Deserialize, Serialize};
 
@@ -43,7 +44,7 @@ pub struct GetBlockTemplate {
     /// An id to include with a request to longpoll on an update to this template.
     pub long_poll_id: Option<String>,
     /// The hash target.
-    pub target: Vec<u8>,
+    pub target: Target,
     /// The minimum timestamp appropriate for next block time in seconds since epoch (Jan 1 1970 GMT).
     pub min_time: u32,
     /// List of ways the block template may be changed.
@@ -115,11 +116,11 @@ pub struct GetMiningInfo {
     /// The current target (v29 onwards).
     pub target: Option<Target>,
     /// The network hashes per second.
-    pub network_hash_ps: i64,
+    pub network_hash_ps: f64,
     /// The size of the mempool.
-    pub pooled_tx: i64,
-    /// Current network name as defined in BIP70 (main, test, regtest).
-    pub chain: String,
+    pub pooled_tx: u64,
+    /// Current network.
+    pub chain: Network,
     /// The block challenge (aka. block script).
     ///
     /// Only present if the current network is a signet (v29 onwards).
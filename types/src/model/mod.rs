@@ -18,50 +18,63 @@ mod util;
 mod wallet;
 mod zmq;
 
+#[cfg(feature = "experimental-rpc")]
+#[doc(inline)]
+pub use self::blockchain::{ScanTxOutSet, ScanTxOutSetUnspent};
 #[doc(inline)]
 pub use self::{
     blockchain::{
         ActivityEntry, Bip9Info, Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus,
         Bip9Statistics, ChainState, ChainTips, ChainTipsStatus, DeploymentInfo, DumpTxOutSet,
         GetBestBlockHash, GetBlockCount, GetBlockFilter, GetBlockHash, GetBlockHeader,
-        GetBlockHeaderVerbose, GetBlockStats, GetBlockVerboseOne, GetBlockVerboseZero,
-        GetBlockchainInfo, GetChainStates, GetChainTips, GetChainTxStats, GetDeploymentInfo,
-        GetDescriptorActivity, GetDifficulty, GetMempoolAncestors, GetMempoolAncestorsVerbose,
-        GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo,
-        GetRawMempool, GetRawMempoolVerbose, GetTxOut, GetTxOutSetInfo, GetTxSpendingPrevout,
-        GetTxSpendingPrevoutItem, LoadTxOutSet, MempoolEntry, MempoolEntryFees, ReceiveActivity,
-        ScanBlocksStart, Softfork, SoftforkType, SpendActivity, VerifyTxOutProof,
+        GetBlockHeaderVerbose, GetBlockStats, GetBlockVerboseOne, GetBlockVerboseTwo,
+        GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetBlockchainInfo, GetChainStates,
+        GetChainTips, GetChainTxStats, GetDeploymentInfo, GetDescriptorActivity, GetDifficulty,
+        GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
+        GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo, GetRawMempool,
+        GetRawMempoolVerbose, GetTxOut, GetTxOutSetInfo, GetTxSpendingPrevout,
+        GetTxSpendingPrevoutItem, LoadTxOutSet, MempoolEntry, MempoolEntryFees, PruneBlockchain,
+        ReceiveActivity, ScanBlocksStart, Softfork, SoftforkType, SpendActivity, VerifyTxOutProof,
     },
+    control::{ActiveCommand, GetRpcInfo, Logging},
     generating::{Generate, GenerateBlock, GenerateToAddress, GenerateToDescriptor},
     mining::{
         BlockTemplateTransaction, GetBlockTemplate, GetMiningInfo, GetPrioritisedTransactions,
         NextBlockInfo, PrioritisedTransaction,
     },
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
+    network::{
+        AddedNode, AddedNodeAddress, Banned, BannedSubnet, GetAddedNodeInfo, GetNetTotals,
+        GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNodeAddresses,
+        GetPeerInfo, ListBanned, NodeAddress, NodeAddressKind, PeerInfo, UploadTarget,
+    },
     raw_transactions::{
         AnalyzePsbt, AnalyzePsbtInput, AnalyzePsbtInputMissing, CombinePsbt, CombineRawTransaction,
         ConvertToPsbt, CreatePsbt, CreateRawTransaction, DecodePsbt, DecodeRawTransaction,
         DecodeScript, DescriptorProcessPsbt, FinalizePsbt, FundRawTransaction, GetRawTransaction,
-        GetRawTransactionVerbose, JoinPsbts, MempoolAcceptance, MempoolAcceptanceFees,
-        SendRawTransaction, SignFail, SignRawTransaction, SignRawTransactionWithKey, SubmitPackage,
+        GetRawTransactionVerbose, GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoPrevout,
+        JoinPsbts, MempoolAcceptance, MempoolAcceptanceFees, PsbtRole, SendRawTransaction,
+        SignFail, SignRawTransaction, SignRawTransactionWithKey, SubmitPackage,
         SubmitPackageTxResult, SubmitPackageTxResultFees, TestMempoolAccept, UtxoUpdatePsbt,
     },
     util::{
         CreateMultisig, DeriveAddresses, DeriveAddressesMultipath, EstimateSmartFee,
-        SignMessageWithPrivKey, ValidateAddress,
+        GetDescriptorInfo, SignMessageWithPrivKey, ValidateAddress,
     },
     wallet::{
-        AddMultisigAddress, AddressInformation, AddressPurpose, Bip125Replaceable, BumpFee,
-        CreateWallet, DumpPrivKey, GetAddressInfo, GetAddressInfoEmbedded, GetAddressesByLabel,
-        GetBalance, GetBalances, GetBalancesMine, GetBalancesWatchOnly, GetHdKeys, GetNewAddress,
+        AddMultisigAddress, AddressPurpose, Bip125Replaceable, BumpFee, CreateWallet, DumpPrivKey,
+        DumpWallet, GetAddressInfo, GetAddressInfoEmbedded, GetAddressesByLabel, GetBalance,
+        GetBalances, GetBalancesMine, GetBalancesWatchOnly, GetHdKeys, GetNewAddress,
         GetRawChangeAddress, GetReceivedByAddress, GetReceivedByLabel, GetTransaction,
         GetTransactionDetail, GetUnconfirmedBalance, GetWalletInfo, GetWalletInfoScanning, HdKey,
-        HdKeyDescriptor, LastProcessedBlock, ListAddressGroupings, ListAddressGroupingsItem,
-        ListLockUnspent, ListLockUnspentItem, ListReceivedByAddress, ListReceivedByAddressItem,
-        ListReceivedByLabel, ListReceivedByLabelItem, ListSinceBlock, ListTransactions,
-        ListUnspent, ListUnspentItem, ListWallets, LoadWallet, PsbtBumpFee, RescanBlockchain,
-        ScriptType, Send, SendAll, SendMany, SendManyVerbose, SendToAddress, SignMessage,
+        HdKeyDescriptor, ImportDescriptors, ImportDescriptorsFailure, ImportDescriptorsResult,
+        LastProcessedBlock, ListAddressGroupings, ListAddressGroupingsItem, ListLabels,
+        ListLockUnspent, ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
+        ListReceivedByLabelItem, ListSinceBlock, ListTransactions, ListUnspent, ListUnspentItem,
+        ListWalletDir, ListWallets, LoadWallet, PsbtBumpFee, RescanBlockchain, ScriptType, Send,
+        SendAll, SendMany, SendManyVerbose, SendToAddress, SignMessage,
         SignRawTransactionWithWallet, SimulateRawTransaction, TransactionCategory, TransactionItem,
-        UnloadWallet, WalletCreateFundedPsbt, WalletDisplayAddress, WalletProcessPsbt,
+        UnloadWallet, UpgradeWallet, WalletCreateFundedPsbt, WalletDisplayAddress,
+        WalletProcessPsbt,
     },
+    zmq::GetZmqNotifications,
 };
@@ -5,9 +5,110 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use bitcoin::p2p::ServiceFlags;
 use bitcoin::FeeRate;
 use serde::{Deserialize, Serialize};
 
+/// Models the result of JSON-RPC method `getaddednodeinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetAddedNodeInfo(pub Vec<AddedNode>);
+
+/// An added node item. Part of `getaddednodeinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddedNode {
+    /// The node IP address or name (as provided to addnode).
+    pub added_node: String,
+    /// If connected.
+    pub connected: bool,
+    /// Only when connected = true.
+    pub addresses: Vec<AddedNodeAddress>,
+}
+
+/// An added node address item. Part of `getaddednodeinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddedNodeAddress {
+    /// The bitcoin server IP and port we're connected to.
+    pub address: NodeAddressKind,
+    /// Connection, inbound or outbound.
+    pub connected: String,
+}
+
+/// Models the result of JSON-RPC method `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListBanned(pub Vec<Banned>);
+
+/// A banned item. Part of `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Banned {
+    /// The IP/Subnet of the banned node.
+    pub address: BannedSubnet,
+    /// When the ban expires, as a duration since the UNIX epoch.
+    pub banned_until: Duration,
+    /// When the ban was created, as a duration since the UNIX epoch.
+    pub ban_created: Duration,
+    /// The reason for the ban. v0.17 to v0.19 only.
+    pub ban_reason: Option<String>,
+    /// The ban duration. v22 and later only.
+    pub ban_duration: Option<Duration>,
+    /// The time remaining until the ban expires. v22 and later only.
+    pub time_remaining: Option<Duration>,
+}
+
+/// A banned subnet. Part of `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BannedSubnet {
+    /// An IPv4 or IPv6 subnet in CIDR notation, a single address has the full prefix length.
+    Ip {
+        /// The network address.
+        address: IpAddr,
+        /// The number of leading bits in the netmask.
+        prefix_len: u8,
+    },
+    /// A subnet that is not an IP CIDR subnet e.g., an onion address.
+    Other(String),
+}
+
+/// Models the result of JSON-RPC method `getnettotals`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetNetTotals {
+    /// Total bytes received.
+    pub total_bytes_received: u64,
+    /// Total bytes sent.
+    pub total_bytes_sent: u64,
+    /// Current UNIX time, as a duration since the UNIX epoch.
+    pub time: Duration,
+    /// Upload target totals.
+    pub upload_target: UploadTarget,
+}
+
+/// The upload target totals. Part of `getnettotals`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UploadTarget {
+    /// Length of the measuring timeframe.
+    pub timeframe: Duration,
+    /// Target in bytes.
+    pub target: u64,
+    /// True if target is reached.
+    pub target_reached: bool,
+    /// True if serving historical blocks.
+    pub serve_historical_blocks: bool,
+    /// Bytes left in current time cycle.
+    pub bytes_left_in_cycle: u64,
+    /// Time left in current time cycle.
+    pub time_left_in_cycle: Duration,
+}
+
 /// Models the result of JSON-RPC method `getnetworkinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -73,3 +174,134 @@ pub struct GetNetworkInfoAddress {
     /// Relative score.
     pub score: u32,
 }
+
+/// Models the result of JSON-RPC method `getnodeaddresses`.
+// TODO: Support serde (currently not supported by `ServiceFlags`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetNodeAddresses(pub Vec<NodeAddress>);
+
+/// An node address item. Part of `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeAddress {
+    /// Timestamp in seconds since epoch (Jan 1 1970 GMT) when the node was last seen.
+    pub time: u64,
+    /// The services offered.
+    pub services: ServiceFlags,
+    /// The address and port of the node.
+    pub address: NodeAddressKind,
+    /// The network (ipv4, ipv6, onion, i2p, cjdns) the node connected through. v22 and later only.
+    pub network: Option<String>,
+}
+
+/// The address of a node. Part of `getaddednodeinfo`, `getnodeaddresses` and `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum NodeAddressKind {
+    /// An IPv4 or IPv6 (including CJDNS) address.
+    Socket(SocketAddr),
+    /// An address that is not an IP address e.g., an onion or I2P address.
+    Other {
+        /// The address of the node.
+        address: String,
+        /// The port of the node.
+        port: u16,
+    },
+}
+
+/// Models the result of JSON-RPC method `getpeerinfo`.
+// TODO: Support serde (currently not supported by `ServiceFlags`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// A peer info item. Part of `getpeerinfo`.
+///
+/// Fields that are not returned by all versions of Core are optional.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u32,
+    /// The IP address and port of the peer.
+    pub address: NodeAddressKind,
+    /// Bind address of the connection to the peer.
+    pub address_bind: Option<NodeAddressKind>,
+    /// Local address as reported by the peer.
+    pub address_local: Option<NodeAddressKind>,
+    /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable) the peer connected through.
+    pub network: Option<String>,
+    /// The AS in the BGP route to the peer used for diversifying peer selection.
+    pub mapped_as: Option<u32>,
+    /// The services offered.
+    pub services: ServiceFlags,
+    /// The services offered, in human-readable form.
+    pub services_names: Option<Vec<String>>,
+    /// Whether peer has asked us to relay transactions to it.
+    pub relay_transactions: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    pub last_send: i64,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    pub last_received: i64,
+    /// The UNIX epoch time of the last valid transaction received from this peer.
+    pub last_transaction: Option<i64>,
+    /// The UNIX epoch time of the last block received from this peer.
+    pub last_block: Option<i64>,
+    /// The total bytes sent.
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    pub bytes_received: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    pub connection_time: i64,
+    /// The time offset in seconds.
+    pub time_offset: i64,
+    /// Ping time (if available).
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time (if any at all).
+    pub minimum_ping: Option<f64>,
+    /// Ping wait (if non-zero).
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version (e.g. "/Satoshi:0.8.5/").
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether we selected peer as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_to: Option<bool>,
+    /// Whether peer selected us as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_from: Option<bool>,
+    /// Whether connection was due to addnode/-connect or if it was an automatic/inbound connection.
+    pub add_node: Option<bool>,
+    /// The starting height (block) of the peer.
+    pub starting_height: Option<i64>,
+    /// The current height of header pre-synchronization with this peer, or -1 if no low-work sync is
+    /// in progress.
+    pub presynced_headers: Option<i64>,
+    /// The ban score.
+    pub ban_score: Option<i64>,
+    /// The last header we have in common with this peer.
+    pub synced_headers: Option<i64>,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: Option<i64>,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Option<Vec<u64>>,
+    /// Whether we participate in address relay with this peer.
+    pub addresses_relay_enabled: Option<bool>,
+    /// The total number of addresses processed, excluding those dropped due to rate limiting.
+    pub addresses_processed: Option<usize>,
+    /// The total number of addresses dropped due to rate limiting.
+    pub addresses_rate_limited: Option<usize>,
+    /// Any special permissions that have been granted to this peer.
+    pub permissions: Option<Vec<String>>,
+    /// Whether the peer is whitelisted.
+    pub whitelisted: Option<bool>,
+    /// The minimum fee rate for transactions this peer accepts.
+    pub minimum_fee_filter: Option<FeeRate>,
+    /// The total bytes sent aggregated by message type.
+    pub bytes_sent_per_message: BTreeMap<String, u64>,
+    /// The total bytes received aggregated by message type.
+    pub bytes_received_per_message: BTreeMap<String, u64>,
+    /// Type of connection.
+    pub connection_type: Option<String>,
+    /// Type of transport protocol (detecting, v1, v2).
+    pub transport_protocol_type: Option<String>,
+    /// The session ID for this connection, or "" if none (v2 transport protocol only).
+    pub session_id: Option<String>,
+}
@@ -27,7 +27,7 @@ pub struct AnalyzePsbt {
     /// The transaction fee paid. Shown only if all UTXO slots in the PSBT have been filled.
     pub fee: Option<Amount>,
     /// Role of the next person that this psbt needs to go to.
-    pub next: String,
+    pub next: PsbtRole,
 }
 
 /// An input in a PSBT operation. Part of `analyzepsbt`.
@@ -41,7 +41,24 @@ pub struct AnalyzePsbtInput {
     /// Things that are missing that are required to complete this input.
     pub missing: Option<AnalyzePsbtInputMissing>,
     /// Role of the next person that this input needs to go to.
-    pub next: Option<String>,
+    pub next: Option<PsbtRole>,
+}
+
+/// The BIP-174 role that a PSBT needs to go to next. Part of `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PsbtRole {
+    /// Creates the PSBT.
+    Creator,
+    /// Adds UTXO and script information to the PSBT.
+    Updater,
+    /// Adds signatures to the PSBT.
+    Signer,
+    /// Builds the final scripts from the signatures.
+    Finalizer,
+    /// Extracts the network transaction.
+    Extractor,
+    /// A role not known to this library.
+    Unknown(String),
 }
 
 /// Missing elements required to complete an input. Part of `analyzepsbt`.
@@ -152,8 +169,8 @@ pub struct FundRawTransaction {
     pub tx: Transaction,
     /// Fee the resulting transaction pays.
     pub fee: Amount,
-    /// The position of the added change output, or -1.
-    pub change_position: i64,
+    /// The position of the added change output, `None` if no change output was added.
+    pub change_position: Option<u32>,
 }
 
 /// Models the result of JSON-RPC method `getrawtransaction` with verbose set to `false`.
@@ -180,6 +197,44 @@ pub struct GetRawTransactionVerbose {
     pub block_time: Option<u64>,
 }
 
+/// Models the result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRawTransactionVerboseTwo {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The transaction fee (`None` if block undo data is not available).
+    pub fee: Option<Amount>,
+    /// The output spent by each input, in input order.
+    ///
+    /// `None` for a coinbase input or if block undo data is not available.
+    pub prevouts: Vec<Option<GetRawTransactionVerboseTwoPrevout>>,
+    /// The block hash (`None` for mempool transactions).
+    pub block_hash: Option<BlockHash>,
+    /// The confirmations (`None` for mempool transactions).
+    pub confirmations: Option<u64>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub transaction_time: Option<u64>,
+    /// The block time in seconds since epoch (Jan 1 1970 GMT).
+    pub block_time: Option<u64>,
+}
+
+/// The output spent by a transaction input. Part of `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRawTransactionVerboseTwoPrevout {
+    /// Coinbase or not.
+    pub generated: bool,
+    /// The height of the block containing the previous output.
+    pub height: u32,
+    /// The value of the previous output.
+    pub value: Amount,
+    /// The script pubkey of the previous output.
+    pub script_pubkey: ScriptBuf,
+}
+
 /// Models the result of JSON-RPC method `joinpsbts`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
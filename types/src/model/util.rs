@@ -56,6 +56,25 @@ pub struct EstimateSmartFee {
     pub blocks: u32,
 }
 
+/// Models the result of JSON-RPC method `getdescriptorinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetDescriptorInfo {
+    /// The descriptor in canonical form, without private keys. For a multipath descriptor, only the
+    /// first will be returned.
+    pub descriptor: String,
+    /// All descriptors produced by expanding multipath derivation elements. v29 and later only.
+    pub multipath_expansion: Option<Vec<String>>,
+    /// The checksum for the input descriptor. v0.19 and later only.
+    pub checksum: Option<String>,
+    /// Whether the descriptor is ranged.
+    pub is_range: bool,
+    /// Whether the descriptor is solvable.
+    pub is_solvable: bool,
+    /// Whether the input descriptor contained at least one private key.
+    pub has_private_keys: bool,
+}
+
 /// Models the result of JSON-RPC method `signmessagewithprivkey`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SignMessageWithPrivKey(pub sign_message::MessageSignature);
@@ -65,18 +84,22 @@ pub struct SignMessageWithPrivKey(pub sign_message::MessageSignature);
 // https://github.com/rust-bitcoin/rust-bitcoin/issues/3513
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ValidateAddress {
-    /// If the address is valid or not. If not, this is the only property returned.
+    /// If the address is valid or not.
     pub is_valid: bool,
-    /// The bitcoin address validated.
-    pub address: Address<NetworkUnchecked>,
-    /// The hex encoded scriptPubKey generated by the address.
-    pub script_pubkey: ScriptBuf,
-    /// If the key is a script.
-    pub is_script: bool,
-    /// If the address is a witness address.
-    pub is_witness: bool,
+    /// The bitcoin address validated. `None` if the address is not valid.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The hex encoded scriptPubKey generated by the address. `None` if the address is not valid.
+    pub script_pubkey: Option<ScriptBuf>,
+    /// If the key is a script. `None` if the address is not valid.
+    pub is_script: Option<bool>,
+    /// If the address is a witness address. `None` if the address is not valid.
+    pub is_witness: Option<bool>,
     /// The version number of the witness program.
     pub witness_version: Option<WitnessVersion>,
     /// The hex value of the witness program.
     pub witness_program: Option<WitnessProgram>,
+    /// Error message if the address is not valid. v22 and later only.
+    pub error: Option<String>,
+    /// Indices of likely error locations in the address, if known. v22 and later only.
+    pub error_locations: Option<Vec<u32>>,
 }
@@ -6,13 +6,14 @@
 //! and are not specific to a specific version of Bitcoin Core.
 
 use alloc::collections::BTreeMap;
+use std::path::PathBuf;
 
 use bitcoin::address::NetworkUnchecked;
 use bitcoin::bip32::{Xpriv, Xpub};
 use bitcoin::hashes::hash160;
 use bitcoin::{
-    bip32, sign_message, Address, Amount, BlockHash, FeeRate, PrivateKey, Psbt, PublicKey,
-    ScriptBuf, SignedAmount, Transaction, Txid, WitnessProgram, WitnessVersion,
+    bip32, sign_message, Address, Amount, BlockHash, FeeRate, OutPoint, PrivateKey, Psbt,
+    PublicKey, ScriptBuf, SignedAmount, Transaction, Txid, WitnessProgram, WitnessVersion,
 };
 use serde::{Deserialize, Serialize};
 
@@ -99,18 +100,18 @@ pub struct CreateWallet {
 #[serde(deny_unknown_fields)]
 pub struct DumpPrivKey(pub PrivateKey);
 
-/// Models the result of JSON-RPC method `getaddressesbylabel`.
+/// Models the result of JSON-RPC method `dumpwallet`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
-pub struct GetAddressesByLabel(pub BTreeMap<Address<NetworkUnchecked>, AddressInformation>);
+pub struct DumpWallet {
+    /// The filename with full absolute path.
+    pub file_name: PathBuf,
+}
 
-/// Address information. Part of `getaddressesbylabel`.
+/// Models the result of JSON-RPC method `getaddressesbylabel`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
-pub struct AddressInformation {
-    /// Purpose of address.
-    pub purpose: AddressPurpose,
-}
+pub struct GetAddressesByLabel(pub BTreeMap<Address<NetworkUnchecked>, AddressPurpose>);
 
 /// Models the result of JSON-RPC method `getaddressinfo`.
 // TODO: Support serde (currently not supported by `WitnessProgram` or `WitnessVersion`)
@@ -280,7 +281,7 @@ pub struct GetBalancesMine {
     pub used: Option<Amount>,
 }
 
-/// Hash and height of the block this information was generated on. Part of `getbalances`.
+/// Balances from outputs the wallet is watching but cannot sign. Part of `getbalances`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetBalancesWatchOnly {
@@ -513,6 +514,37 @@ pub enum GetWalletInfoScanning {
     NotScanning(bool),
 }
 
+/// Models the result of JSON-RPC method `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+/// Result for each descriptor import. Part of `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportDescriptorsResult {
+    /// Whether the import was successful.
+    pub success: bool,
+    /// Warnings, if any.
+    pub warnings: Vec<String>,
+    /// The reason the import failed, if any.
+    pub error: Option<ImportDescriptorsFailure>,
+}
+
+/// The reason a descriptor import failed. Part of `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum ImportDescriptorsFailure {
+    /// A JSON-RPC error object as returned by Core.
+    Rpc {
+        /// The JSON-RPC error code e.g., `-5` for an invalid descriptor.
+        code: i32,
+        /// The error message.
+        message: String,
+    },
+    /// An error that does not have the shape of a JSON-RPC error object.
+    Other(serde_json::Value),
+}
+
 /// Models the result of JSON-RPC method `listaddressgroupings`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -531,20 +563,15 @@ pub struct ListAddressGroupingsItem {
     pub label: Option<String>,
 }
 
-/// Models the result of JSON-RPC method `listlockunspent`.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+/// Models the result of JSON-RPC method `listlabels`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
-pub struct ListLockUnspent(pub Vec<ListLockUnspentItem>);
+pub struct ListLabels(pub Vec<String>);
 
-/// List lock unspent item. Part of of `listlockunspent`.
+/// Models the result of JSON-RPC method `listlockunspent`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
-pub struct ListLockUnspentItem {
-    /// The transaction id locked.
-    pub txid: Txid,
-    /// The vout value.
-    pub vout: u32,
-}
+pub struct ListLockUnspent(pub Vec<OutPoint>);
 
 /// Models the result of JSON-RPC method `listreceivedbyaddress`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -740,6 +767,14 @@ pub struct ListUnspentItem {
 #[serde(deny_unknown_fields)]
 pub struct ListWallets(pub Vec<String>);
 
+/// Models the result of JSON-RPC method `listwalletdir`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListWalletDir {
+    /// The names of the wallets in the wallet directory.
+    pub wallets: Vec<String>,
+}
+
 /// Models the result of JSON-RPC method `loadwallet`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -856,6 +891,20 @@ pub struct UnloadWallet {
     pub warnings: Vec<String>,
 }
 
+/// Models the result of JSON-RPC method `upgradewallet`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpgradeWallet {
+    /// Name of wallet this operation was performed on.
+    pub wallet_name: String,
+    /// Version of wallet before this operation.
+    pub previous_version: u32,
+    /// Version of wallet after this operation.
+    pub current_version: u32,
+    /// Description of result.
+    pub result: Option<String>,
+}
+
 /// Models the result of JSON-RPC method `walletcreatefundedpsbt`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -863,10 +912,9 @@ pub struct WalletCreateFundedPsbt {
     /// The resulting PSBT.
     pub psbt: Psbt,
     /// Fee the resulting transaction pays.
-    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
-    pub fee: SignedAmount,
-    /// The position of the added change output, or -1.
-    pub change_position: u32,
+    pub fee: Amount,
+    /// The position of the added change output, `None` if no change output was added.
+    pub change_position: Option<u32>,
 }
 
 /// Models the result of JSON-RPC method `walletdisplayaddress`.
@@ -4,3 +4,17 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `getzmqnotifications`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetZmqNotifications {
+    /// Type of notification.
+    pub type_: String,
+    /// Address of the publisher, validated to be a well formed endpoint.
+    pub address: String,
+    /// Outbound message high water mark (v18 onwards).
+    pub hwm: Option<u64>,
+}
@@ -68,6 +68,68 @@ impl From<NumericError> for GetBlockVerboseOneError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
 
+/// Error when converting a `GetBlockVerboseTwo` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockVerboseTwoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of a transaction `hex` field failed.
+    Tx(encode::FromHexError),
+    /// Conversion of a transaction `fee` field failed.
+    Fee(ParseAmountError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetBlockVerboseTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockVerboseTwoError::*;
+
+        match *self {
+            Numeric(ref e) => write_err!(f, "numeric"; e),
+            Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            Tx(ref e) => write_err!(f, "conversion of a transaction `hex` field failed"; e),
+            Fee(ref e) => write_err!(f, "conversion of a transaction `fee` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetBlockVerboseTwoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockVerboseTwoError::*;
+
+        match *self {
+            Numeric(ref e) => Some(e),
+            Hash(ref e) => Some(e),
+            Tx(ref e) => Some(e),
+            Fee(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            ChainWork(ref e) => Some(e),
+            PreviousBlockHash(ref e) => Some(e),
+            NextBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockVerboseTwoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetBlockchainInfoError {
@@ -210,6 +272,8 @@ pub enum GetBlockStatsError {
     Numeric(NumericError),
     /// Conversion of the `block_hash` field failed.
     BlockHash(hex::HexToArrayError),
+    /// A fee rate field value in sat/vB overflows a `FeeRate`.
+    FeeRateOverflow { field: String, value: u64 },
 }
 
 impl fmt::Display for GetBlockStatsError {
@@ -219,6 +283,8 @@ impl fmt::Display for GetBlockStatsError {
         match *self {
             Numeric(ref e) => write_err!(f, "numeric"; e),
             BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+            FeeRateOverflow { ref field, value } =>
+                write!(f, "fee rate overflows `FeeRate` (field name: {} value: {})", field, value),
         }
     }
 }
@@ -231,6 +297,7 @@ impl std::error::Error for GetBlockStatsError {
         match *self {
             Numeric(ref e) => Some(e),
             BlockHash(ref e) => Some(e),
+            FeeRateOverflow { .. } => None,
         }
     }
 }
@@ -81,6 +81,69 @@ impl GetBlockVerboseOne {
     }
 }
 
+impl GetBlockVerboseTwo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerboseTwo, GetBlockVerboseTwoError> {
+        use GetBlockVerboseTwoError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let tx = self.tx.into_iter().map(|t| t.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerboseTwo {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root: self.merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            target: None,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+impl GetBlockVerboseTwoTransaction {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetBlockVerboseTwoTransaction, GetBlockVerboseTwoError> {
+        use GetBlockVerboseTwoError as E;
+
+        let tx = encode::deserialize_hex(&self.hex).map_err(E::Tx)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::GetBlockVerboseTwoTransaction { tx, fee })
+    }
+}
+
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
@@ -94,7 +157,14 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let mut softforks = self
+            .softforks
+            .into_iter()
+            .map(|softfork| (softfork.id.clone(), softfork.into_model()))
+            .collect::<BTreeMap<_, _>>();
+        for (name, softfork) in self.bip9_softforks {
+            softforks.insert(name, softfork.into_model()?);
+        }
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -121,6 +191,56 @@ impl GetBlockchainInfo {
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    ///
+    /// These are the IsSuperMajority softforks, `reject.status` is `true` once they are enforced.
+    pub fn into_model(self) -> model::Softfork {
+        model::Softfork {
+            type_: model::SoftforkType::Buried,
+            bip9: None,
+            height: None,
+            active: self.reject.status,
+        }
+    }
+}
+
+impl Bip9Softfork {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Softfork, crate::NumericError> {
+        let status = self.status.into_model();
+        let bip9 = model::Bip9SoftforkInfo {
+            status,
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: crate::to_u32(self.since, "since")?,
+            min_activation_height: None, // v0.21 and later only.
+            statistics: self.statistics.map(|s| s.into_model()).transpose()?,
+        };
+
+        Ok(model::Softfork {
+            type_: model::SoftforkType::Bip9,
+            bip9: Some(bip9),
+            height: None,
+            active: status == model::Bip9SoftforkStatus::Active,
+        })
+    }
+}
+
+impl Bip9SoftforkStatistics {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkStatistics, crate::NumericError> {
+        Ok(model::Bip9SoftforkStatistics {
+            period: crate::to_u32(self.period, "period")?,
+            threshold: Some(crate::to_u32(self.threshold, "threshold")?),
+            elapsed: crate::to_u32(self.elapsed, "elapsed")?,
+            count: crate::to_u32(self.count, "count")?,
+            possible: Some(self.possible),
+        })
+    }
+}
+
 impl Bip9SoftforkStatus {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> model::Bip9SoftforkStatus {
@@ -216,49 +336,62 @@ impl GetBlockStats {
     pub fn into_model(self) -> Result<model::GetBlockStats, GetBlockStatsError> {
         use GetBlockStatsError as E;
 
-        // `FeeRate::sat_per_vb` returns an option if value overflows.
-        let average_fee_rate = FeeRate::from_sat_per_vb(self.average_fee_rate);
-        let block_hash = self.block_hash.parse::<BlockHash>().map_err(E::BlockHash)?;
+        let to_u32 = |value: Option<i64>, field| value.map(|v| crate::to_u32(v, field)).transpose();
+
+        // `FeeRate::from_sat_per_vb` returns `None` if the value overflows.
+        let fee_rate = |value: u64, field: &str| {
+            FeeRate::from_sat_per_vb(value)
+                .ok_or_else(|| E::FeeRateOverflow { field: field.to_owned(), value })
+        };
+        let average_fee_rate =
+            self.average_fee_rate.map(|v| fee_rate(v, "average_fee_rate")).transpose()?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
         let fee_rate_percentiles = self
             .fee_rate_percentiles
-            .iter()
-            .map(|vb| FeeRate::from_sat_per_vb(*vb))
-            .collect::<Vec<Option<FeeRate>>>();
-        let max_fee_rate = FeeRate::from_sat_per_vb(self.max_fee_rate);
-        let minimum_fee_rate = FeeRate::from_sat_per_vb(self.minimum_fee_rate);
+            .map(|percentiles| {
+                percentiles
+                    .iter()
+                    .map(|vb| fee_rate(*vb, "fee_rate_percentiles"))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let max_fee_rate = self.max_fee_rate.map(|v| fee_rate(v, "max_fee_rate")).transpose()?;
+        let minimum_fee_rate =
+            self.minimum_fee_rate.map(|v| fee_rate(v, "minimum_fee_rate")).transpose()?;
 
         // FIXME: Double check that these values are virtual bytes and not weight units.
-        let segwit_total_weight = Weight::from_vb(self.segwit_total_weight);
-        let total_weight = Weight::from_vb(self.total_weight);
+        let segwit_total_weight = self.segwit_total_weight.and_then(Weight::from_vb);
+        let total_weight = self.total_weight.and_then(Weight::from_vb);
 
         Ok(model::GetBlockStats {
-            average_fee: Amount::from_sat(self.average_fee),
+            average_fee: self.average_fee.map(Amount::from_sat),
             average_fee_rate,
-            average_tx_size: crate::to_u32(self.average_tx_size, "average_tx_size")?,
+            average_tx_size: to_u32(self.average_tx_size, "average_tx_size")?,
             block_hash,
             fee_rate_percentiles,
-            height: crate::to_u32(self.height, "height")?,
-            inputs: crate::to_u32(self.inputs, "inputs")?,
-            max_fee: Amount::from_sat(self.max_fee),
+            height: to_u32(self.height, "height")?,
+            inputs: to_u32(self.inputs, "inputs")?,
+            max_fee: self.max_fee.map(Amount::from_sat),
             max_fee_rate,
-            max_tx_size: crate::to_u32(self.max_tx_size, "max_tx_size")?,
-            median_fee: Amount::from_sat(self.median_fee),
-            median_time: crate::to_u32(self.median_time, "median_time")?,
-            median_tx_size: crate::to_u32(self.median_tx_size, "median_tx_size")?,
-            minimum_fee: Amount::from_sat(self.minimum_fee),
+            max_tx_size: to_u32(self.max_tx_size, "max_tx_size")?,
+            median_fee: self.median_fee.map(Amount::from_sat),
+            median_time: to_u32(self.median_time, "median_time")?,
+            median_tx_size: to_u32(self.median_tx_size, "median_tx_size")?,
+            minimum_fee: self.minimum_fee.map(Amount::from_sat),
             minimum_fee_rate,
-            minimum_tx_size: crate::to_u32(self.minimum_tx_size, "minimum_tx_size")?,
-            outputs: crate::to_u32(self.outputs, "outputs")?,
-            subsidy: Amount::from_sat(self.subsidy),
-            segwit_total_size: crate::to_u32(self.segwit_total_size, "segwit_total_size")?,
+            minimum_tx_size: to_u32(self.minimum_tx_size, "minimum_tx_size")?,
+            outputs: to_u32(self.outputs, "outputs")?,
+            subsidy: self.subsidy.map(Amount::from_sat),
+            segwit_total_size: to_u32(self.segwit_total_size, "segwit_total_size")?,
             segwit_total_weight,
-            segwit_txs: crate::to_u32(self.segwit_txs, "segwit_txs")?,
-            time: crate::to_u32(self.time, "time")?,
-            total_out: Amount::from_sat(self.total_out),
-            total_size: crate::to_u32(self.total_size, "total_size")?,
+            segwit_txs: to_u32(self.segwit_txs, "segwit_txs")?,
+            time: to_u32(self.time, "time")?,
+            total_out: self.total_out.map(Amount::from_sat),
+            total_size: to_u32(self.total_size, "total_size")?,
             total_weight,
-            total_fee: Amount::from_sat(self.total_fee),
-            txs: crate::to_u32(self.txs, "txs")?,
+            total_fee: self.total_fee.map(Amount::from_sat),
+            txs: to_u32(self.txs, "txs")?,
             utxo_increase: self.utxo_increase,
             utxo_size_increase: self.utxo_size_increase,
             utxo_increase_actual: None,      // v25 and later only.
@@ -315,7 +448,6 @@ impl GetChainTxStats {
             self.window_tx_count.map(|h| crate::to_u32(h, "window_tx_count")).transpose()?;
         let window_interval =
             self.window_interval.map(|h| crate::to_u32(h, "window_interval")).transpose()?;
-        let tx_rate = self.tx_rate.map(|h| crate::to_u32(h, "tx_rate")).transpose()?;
 
         Ok(model::GetChainTxStats {
             time: crate::to_u32(self.time, "time")?,
@@ -325,7 +457,7 @@ impl GetChainTxStats {
             window_block_count: crate::to_u32(self.window_block_count, "window_block_count")?,
             window_tx_count,
             window_interval,
-            tx_rate,
+            tx_rate: self.tx_rate,
         })
     }
 }
@@ -550,6 +682,17 @@ impl GetTxOutSetInfo {
     }
 }
 
+impl PruneBlockchain {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PruneBlockchain, crate::NumericError> {
+        let height = match self.0 {
+            -1 => None,
+            height => Some(crate::to_u32(height, "height")?),
+        };
+        Ok(model::PruneBlockchain(height))
+    }
+}
+
 impl VerifyTxOutProof {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::VerifyTxOutProof, hex::HexToArrayError> {
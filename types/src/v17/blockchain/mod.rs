@@ -15,7 +15,8 @@ use serde::{Deserialize, Serialize};
 
 // TODO: Remove wildcard, use explicit types.
 pub use self::error::*;
-use crate::{model, ScriptPubkey};
+use crate::psbt::RawTransactionOutput;
+use crate::{model, ScriptPubkey, ScriptSig};
 
 /// Result of JSON-RPC method `getbestblockhash`.
 ///
@@ -96,6 +97,111 @@ pub struct GetBlockVerboseOne {
     pub next_block_hash: Option<String>,
 }
 
+/// Result of JSON-RPC method `getblock` with verbosity set to 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockVerboseTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: i64,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<i64>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: i64,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<GetBlockVerboseTwoTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: i64,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<i64>,
+    /// The nonce (this should be only 4 bytes).
+    pub nonce: i64,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: i64,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+/// A transaction. Part of `getblock` with verbosity set to 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockVerboseTwoTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The version number.
+    pub version: i32,
+    /// The transaction size in bytes.
+    pub size: u64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: u64,
+    /// The transaction's weight (between vsize*4 - 3 and vsize*4).
+    pub weight: u64,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: u32,
+    /// Array of transaction inputs.
+    #[serde(rename = "vin")]
+    pub inputs: Vec<GetBlockVerboseTwoInput>,
+    /// Array of transaction outputs.
+    #[serde(rename = "vout")]
+    pub outputs: Vec<RawTransactionOutput>,
+    /// The transaction fee in BTC, omitted if block undo data is not available.
+    pub fee: Option<f64>,
+    /// The serialized, hex-encoded data for the transaction.
+    pub hex: String,
+}
+
+/// A transaction input. Part of `getblock` with verbosity set to 2.
+///
+/// Unlike the inputs returned by `decoderawtransaction` this includes the coinbase input.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockVerboseTwoInput {
+    /// The transaction id, omitted for the coinbase input.
+    pub txid: Option<String>,
+    /// The output number, omitted for the coinbase input.
+    pub vout: Option<u32>,
+    /// The script, omitted for the coinbase input.
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<ScriptSig>,
+    /// The hex-encoded coinbase script, only present for the coinbase input.
+    pub coinbase: Option<String>,
+    /// Hex-encoded witness data (if any).
+    #[serde(rename = "txinwitness")]
+    pub txin_witness: Option<Vec<String>>,
+    /// The script sequence number.
+    pub sequence: u32,
+}
+
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
 /// > getblockchaininfo
@@ -181,6 +287,24 @@ pub struct Bip9Softfork {
     pub timeout: i64,
     /// Height of the first block to which the status applies.
     pub since: i64,
+    /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
+    pub statistics: Option<Bip9SoftforkStatistics>,
+}
+
+/// BIP-9 softfork statistics. Part of `getblockchaininfo`.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bip9SoftforkStatistics {
+    /// The length in blocks of the BIP9 signalling period.
+    pub period: i64,
+    /// The number of blocks with the version bit set required to activate the feature.
+    pub threshold: i64,
+    /// The number of blocks elapsed since the beginning of the current period.
+    pub elapsed: i64,
+    /// The number of blocks with the version bit set in the current period.
+    pub count: i64,
+    /// `false` if there are not enough blocks left in this period to pass activation threshold.
+    pub possible: bool,
 }
 
 /// BIP-9 softfork status. Part of `getblockchaininfo`.
@@ -291,90 +415,93 @@ pub struct GetBlockHeaderVerbose {
 /// >
 /// > Arguments:
 /// > 1. "hash_or_height"     (string or numeric, required) The block hash or height of the target block
+/// > 2. "stats"              (array,  optional) Values to plot, by default all values (see result below)
+///
+/// All fields are optional because only the fields selected with `stats` are returned.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetBlockStats {
     /// Average fee in the block.
     #[serde(rename = "avgfee")]
-    pub average_fee: u64,
+    pub average_fee: Option<u64>,
     // FIXME: Remember these docs will become silently stale when unit changes in a later version of Core.
     /// Average feerate (in satoshis per virtual byte).
     #[serde(rename = "avgfeerate")]
-    pub average_fee_rate: u64,
+    pub average_fee_rate: Option<u64>,
     /// Average transaction size.
     #[serde(rename = "avgtxsize")]
-    pub average_tx_size: i64,
+    pub average_tx_size: Option<i64>,
     /// The block hash (to check for potential reorgs).
     #[serde(rename = "blockhash")]
-    pub block_hash: String,
+    pub block_hash: Option<String>,
     /// Feerates at the 10th, 25th, 50th, 75th, and 90th percentile weight unit (in satoshis per
     /// virtual byte).
     #[serde(rename = "feerate_percentiles")]
-    pub fee_rate_percentiles: [u64; 5],
+    pub fee_rate_percentiles: Option<[u64; 5]>,
     /// The height of the block.
-    pub height: i64,
+    pub height: Option<i64>,
     /// The number of inputs (excluding coinbase).
     #[serde(rename = "ins")]
-    pub inputs: i64,
+    pub inputs: Option<i64>,
     /// Maximum fee in the block.
     #[serde(rename = "maxfee")]
-    pub max_fee: u64,
+    pub max_fee: Option<u64>,
     /// Maximum feerate (in satoshis per virtual byte).
     #[serde(rename = "maxfeerate")]
-    pub max_fee_rate: u64,
+    pub max_fee_rate: Option<u64>,
     /// Maximum transaction size.
     #[serde(rename = "maxtxsize")]
-    pub max_tx_size: i64,
+    pub max_tx_size: Option<i64>,
     /// Truncated median fee in the block.
     #[serde(rename = "medianfee")]
-    pub median_fee: u64,
+    pub median_fee: Option<u64>,
     /// The block median time past.
     #[serde(rename = "mediantime")]
-    pub median_time: i64,
+    pub median_time: Option<i64>,
     /// Truncated median transaction size
     #[serde(rename = "mediantxsize")]
-    pub median_tx_size: i64,
+    pub median_tx_size: Option<i64>,
     /// Minimum fee in the block.
     #[serde(rename = "minfee")]
-    pub minimum_fee: u64,
+    pub minimum_fee: Option<u64>,
     /// Minimum feerate (in satoshis per virtual byte).
     #[serde(rename = "minfeerate")]
-    pub minimum_fee_rate: u64,
+    pub minimum_fee_rate: Option<u64>,
     /// Minimum transaction size.
     #[serde(rename = "mintxsize")]
-    pub minimum_tx_size: i64,
+    pub minimum_tx_size: Option<i64>,
     /// The number of outputs.
     #[serde(rename = "outs")]
-    pub outputs: i64,
+    pub outputs: Option<i64>,
     /// The block subsidy.
-    pub subsidy: u64,
+    pub subsidy: Option<u64>,
     /// Total size of all segwit transactions.
     #[serde(rename = "swtotal_size")]
-    pub segwit_total_size: i64,
+    pub segwit_total_size: Option<i64>,
     /// Total weight of all segwit transactions divided by segwit scale factor (4).
     #[serde(rename = "swtotal_weight")]
-    pub segwit_total_weight: u64,
+    pub segwit_total_weight: Option<u64>,
     /// The number of segwit transactions.
     #[serde(rename = "swtxs")]
-    pub segwit_txs: i64,
+    pub segwit_txs: Option<i64>,
     /// The block time.
-    pub time: i64,
+    pub time: Option<i64>,
     /// Total amount in all outputs (excluding coinbase and thus reward [ie subsidy + totalfee]).
-    pub total_out: u64,
+    pub total_out: Option<u64>,
     /// Total size of all non-coinbase transactions.
-    pub total_size: i64,
+    pub total_size: Option<i64>,
     /// Total weight of all non-coinbase transactions divided by segwit scale factor (4).
-    pub total_weight: u64,
+    pub total_weight: Option<u64>,
     /// The fee total.
     #[serde(rename = "totalfee")]
-    pub total_fee: u64,
+    pub total_fee: Option<u64>,
     /// The number of transactions (excluding coinbase).
-    pub txs: i64,
+    pub txs: Option<i64>,
     /// The increase/decrease in the number of unspent outputs.
-    pub utxo_increase: i32,
+    pub utxo_increase: Option<i32>,
     /// The increase/decrease in size for the utxo index (not discounting op_return and similar).
     #[serde(rename = "utxo_size_inc")]
-    pub utxo_size_increase: i32,
+    pub utxo_size_increase: Option<i32>,
 }
 
 /// Result of JSON-RPC method `getchaintips`.
@@ -438,7 +565,7 @@ pub struct GetChainTxStats {
     pub window_interval: Option<i64>,
     /// The average rate of transactions per second in the window. Only returned if "window_interval" is > 0.
     #[serde(rename = "txrate")]
-    pub tx_rate: Option<i64>,
+    pub tx_rate: Option<f64>,
 }
 
 /// Result of JSON-RPC method `getdifficulty`.
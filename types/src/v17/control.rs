@@ -8,9 +8,11 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-/// Result of JSON-RPC method `getmemoryinfo`.
+use crate::model;
+
+/// Result of JSON-RPC method `getmemoryinfo` with `mode` set to "stats".
 ///
-/// We only support the default "stats" mode.
+/// The "mallocinfo" mode returns an XML string.
 ///
 /// > getmemoryinfo ("mode")
 ///
@@ -77,3 +79,33 @@ pub struct Logging {
     pub tor: bool,
     pub zmq: bool,
 }
+
+impl Logging {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let categories = [
+            ("addrman", self.addrman),
+            ("bench", self.bench),
+            ("cmpctblock", self.cmpctblock),
+            ("coindb", self.coindb),
+            ("db", self.db),
+            ("estimatefee", self.estimatefee),
+            ("http", self.http),
+            ("leveldb", self.leveldb),
+            ("libevent", self.libevent),
+            ("mempool", self.mempool),
+            ("mempoolrej", self.mempoolrej),
+            ("net", self.net),
+            ("prune", self.prune),
+            ("proxy", self.proxy),
+            ("qt", self.qt),
+            ("rand", self.rand),
+            ("reindex", self.reindex),
+            ("rpc", self.rpc),
+            ("selectcoins", self.selectcoins),
+            ("tor", self.tor),
+            ("zmq", self.zmq),
+        ];
+        model::Logging(categories.into_iter().map(|(c, active)| (c.to_string(), active)).collect())
+    }
+}
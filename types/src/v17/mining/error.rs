@@ -4,8 +4,7 @@ use core::fmt;
 
 use bitcoin::amount::ParseAmountError;
 use bitcoin::error::UnprefixedHexError;
-use bitcoin::hex::HexToBytesError;
-use bitcoin::{consensus, hex};
+use bitcoin::{consensus, hex, network};
 
 use crate::error::write_err;
 use crate::NumericError;
@@ -17,10 +16,10 @@ pub enum GetBlockTemplateError {
     Numeric(NumericError),
     /// Conversion of the `previous_block_hash` field failed.
     PreviousBlockHash(hex::HexToArrayError),
-    /// Conversion of the `transactions` field failed.
-    Transactions(BlockTemplateTransactionError),
+    /// Conversion of the transaction at the given index in the `transactions` field failed.
+    Transactions(usize, BlockTemplateTransactionError),
     /// Conversion of the `target` field failed.
-    Target(HexToBytesError),
+    Target(UnprefixedHexError),
     /// Conversion of the `bits` field failed.
     Bits(UnprefixedHexError),
 }
@@ -33,8 +32,8 @@ impl fmt::Display for GetBlockTemplateError {
             E::Numeric(ref e) => write_err!(f, "numeric"; e),
             E::PreviousBlockHash(ref e) =>
                 write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
-            E::Transactions(ref e) =>
-                write_err!(f, "conversion of the `transactions` field failed"; e),
+            E::Transactions(index, ref e) =>
+                write_err!(f, "conversion of the transaction at index {} failed", index; e),
             E::Target(ref e) => write_err!(f, "conversion of the `target` field failed"; e),
             E::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
         }
@@ -49,7 +48,7 @@ impl std::error::Error for GetBlockTemplateError {
         match *self {
             E::Numeric(ref e) => Some(e),
             E::PreviousBlockHash(ref e) => Some(e),
-            E::Transactions(ref e) => Some(e),
+            E::Transactions(_, ref e) => Some(e),
             E::Target(ref e) => Some(e),
             E::Bits(ref e) => Some(e),
         }
@@ -107,3 +106,39 @@ impl std::error::Error for BlockTemplateTransactionError {
 impl From<NumericError> for BlockTemplateTransactionError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
+
+/// Error when converting a `GetMiningInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMiningInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `chain` field failed.
+    UnknownChain(network::ParseNetworkError),
+}
+
+impl fmt::Display for GetMiningInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMiningInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::UnknownChain(ref e) => write_err!(f, "conversion of the `chain` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetMiningInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMiningInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::UnknownChain(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetMiningInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
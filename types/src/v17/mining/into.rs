@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::hex::FromHex as _;
 use bitcoin::{
-    block, consensus, BlockHash, CompactTarget, SignedAmount, Transaction, Txid, Weight, Wtxid,
+    block, consensus, BlockHash, CompactTarget, Network, SignedAmount, Target, Transaction, Txid,
+    Weight, Wtxid,
 };
 
 use super::{
     BlockTemplateTransaction, BlockTemplateTransactionError, GetBlockTemplate,
-    GetBlockTemplateError, GetMiningInfo,
+    GetBlockTemplateError, GetMiningInfo, GetMiningInfoError,
 };
 use crate::model;
 
@@ -24,11 +24,11 @@ impl GetBlockTemplate {
         let transactions = self
             .transactions
             .into_iter()
-            .map(|t| t.into_model())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(E::Transactions)?;
+            .enumerate()
+            .map(|(i, t)| t.into_model().map_err(|e| E::Transactions(i, e)))
+            .collect::<Result<Vec<_>, _>>()?;
         let coinbase_value = SignedAmount::from_sat(self.coinbase_value);
-        let target = Vec::from_hex(&self.target).map_err(E::Target)?;
+        let target = Target::from_unprefixed_hex(&self.target).map_err(E::Target)?;
         let sigop_limit = crate::to_u32(self.sigop_limit, "sigop_limit")?;
         let weight_limit = crate::to_u32(self.weight_limit, "weight_limit")?;
         let size_limit = crate::to_u32(self.size_limit, "size_limit")?;
@@ -88,10 +88,14 @@ impl BlockTemplateTransaction {
 
 impl GetMiningInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> model::GetMiningInfo {
+    pub fn into_model(self) -> Result<model::GetMiningInfo, GetMiningInfoError> {
+        use GetMiningInfoError as E;
+
         let current_block_weight = self.current_block_weight.map(Weight::from_wu);
+        let pooled_tx = crate::to_u64(self.pooled_tx, "pooled_tx")?;
+        let chain = Network::from_core_arg(&self.chain).map_err(E::UnknownChain)?;
 
-        model::GetMiningInfo {
+        Ok(model::GetMiningInfo {
             blocks: self.blocks,
             current_block_weight,
             current_block_tx: self.current_block_tx,
@@ -99,11 +103,11 @@ impl GetMiningInfo {
             difficulty: self.difficulty,
             target: None,
             network_hash_ps: self.network_hash_ps,
-            pooled_tx: self.pooled_tx,
-            chain: self.chain,
+            pooled_tx,
+            chain,
             signet_challenge: None,
             next: None,
             warnings: vec![self.warnings],
-        }
+        })
     }
 }
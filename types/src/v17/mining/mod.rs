@@ -11,7 +11,7 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-pub use self::error::{BlockTemplateTransactionError, GetBlockTemplateError};
+pub use self::error::{BlockTemplateTransactionError, GetBlockTemplateError, GetMiningInfoError};
 
 /// Result of the JSON-RPC method `getblocktemplate`.
 ///
@@ -157,7 +157,7 @@ pub struct GetMiningInfo {
     pub difficulty: f64,
     /// The network hashes per second.
     #[serde(rename = "networkhashps")]
-    pub network_hash_ps: i64,
+    pub network_hash_ps: f64,
     /// The size of the mempool.
     #[serde(rename = "pooledtx")]
     pub pooled_tx: i64,
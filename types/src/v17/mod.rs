@@ -45,7 +45,7 @@
 //! | gettxoutproof                      | returns string  |                                        |
 //! | gettxoutsetinfo                    | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
-//! | pruneblockchain                    | version         |                                        |
+//! | pruneblockchain                    | version + model |                                        |
 //! | savemempool                        | returns nothing |                                        |
 //! | scantxoutset                       | omitted         | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
@@ -60,7 +60,7 @@
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getmemoryinfo                      | version         |                                        |
 //! | help                               | returns string  |                                        |
-//! | logging                            | version         |                                        |
+//! | logging                            | version + model |                                        |
 //! | stop                               | returns string  |                                        |
 //! | uptime                             | returns numeric |                                        |
 //!
@@ -83,7 +83,7 @@
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getblocktemplate                   | version + model |                                        |
 //! | getmininginfo                      | version + model |                                        |
-//! | getnetworkhashps                   | returns boolean |                                        |
+//! | getnetworkhashps                   | returns numeric |                                        |
 //! | prioritisetransaction              | returns boolean |                                        |
 //! | submitblock                        | returns nothing |                                        |
 //!
@@ -97,15 +97,15 @@
 //! | addnode                            | returns nothing |                                        |
 //! | clearbanned                        | returns nothing |                                        |
 //! | disconnectnode                     | returns nothing |                                        |
-//! | getaddednodeinfo                   | version         |                                        |
+//! | getaddednodeinfo                   | version + model |                                        |
 //! | getconnectioncount                 | version         |                                        |
-//! | getnettotals                       | version         |                                        |
+//! | getnettotals                       | version + model |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
-//! | getpeerinfo                        | version         |                                        |
-//! | listbanned                         | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
+//! | listbanned                         | version + model |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
-//! | setnetworkactive                   | version         |                                        |
+//! | setnetworkactive                   | returns boolean |                                        |
 //!
 //! </details>
 //!
@@ -157,7 +157,7 @@
 //! | bumpfee                            | version + model |                                        |
 //! | createwallet                       | version + model |                                        |
 //! | dumpprivkey                        | version + model |                                        |
-//! | dumpwallet                         | version         |                                        |
+//! | dumpwallet                         | version + model |                                        |
 //! | encryptwallet                      | version         |                                        |
 //! | getaccount                         | returns nothing |                                        |
 //! | getaccountaddress                  | returns nothing |                                        |
@@ -181,7 +181,7 @@
 //! | keypoolrefill                      | returns nothing |                                        |
 //! | listaccounts                       | returns nothing |                                        |
 //! | listaddressgroupings               | version + model |                                        |
-//! | listlabels                         | version         |                                        |
+//! | listlabels                         | version + model |                                        |
 //! | listlockunspent                    | version + model |                                        |
 //! | listreceivedbyaccount              | returns nothing |                                        |
 //! | listreceivedbyaddress              | version + model |                                        |
@@ -199,7 +199,7 @@
 //! | sendtoaddress                      | version + model |                                        |
 //! | setaccount                         | returns nothing |                                        |
 //! | sethdseed                          | returns nothing |                                        |
-//! | settxfee                           | version         |                                        |
+//! | settxfee                           | returns boolean |                                        |
 //! | signmessage                        | version + model |                                        |
 //! | signrawtransactionwithwallet       | version + model |                                        |
 //! | unloadwallet                       | returns nothing |                                        |
@@ -216,7 +216,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -229,33 +229,35 @@ mod network;
 pub(crate) mod raw_transactions;
 mod util;
 mod wallet;
-mod zmq;
+pub(crate) mod zmq;
 
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9Softfork, Bip9SoftforkStatus, ChainTips, ChainTipsError, ChainTipsStatus,
-        GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
-        GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
-        GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseZero, GetBlockchainInfo,
-        GetBlockchainInfoError, GetChainTips, GetChainTxStats, GetChainTxStatsError, GetDifficulty,
-        GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
-        GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError,
-        GetRawMempool, GetRawMempoolVerbose, GetTxOut, GetTxOutError, GetTxOutSetInfo,
-        GetTxOutSetInfoError, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
-        MempoolEntryFees, MempoolEntryFeesError, PruneBlockchain, Softfork, SoftforkReject,
-        VerifyChain, VerifyTxOutProof,
+        Bip9Softfork, Bip9SoftforkStatistics, Bip9SoftforkStatus, ChainTips, ChainTipsError,
+        ChainTipsStatus, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
+        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats,
+        GetBlockStatsError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo,
+        GetBlockVerboseTwoError, GetBlockVerboseTwoInput, GetBlockVerboseTwoTransaction,
+        GetBlockVerboseZero, GetBlockchainInfo, GetBlockchainInfoError, GetChainTips,
+        GetChainTxStats, GetChainTxStatsError, GetDifficulty, GetMempoolAncestors,
+        GetMempoolAncestorsVerbose, GetMempoolDescendants, GetMempoolDescendantsVerbose,
+        GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError, GetRawMempool, GetRawMempoolVerbose,
+        GetTxOut, GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError, MapMempoolEntryError,
+        MempoolEntry, MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError, PruneBlockchain,
+        Softfork, SoftforkReject, VerifyChain, VerifyTxOutProof,
     },
     control::{GetMemoryInfoStats, Locked, Logging},
     generating::{Generate, GenerateToAddress},
     mining::{
         BlockTemplateTransaction, BlockTemplateTransactionError, GetBlockTemplate,
-        GetBlockTemplateError, GetMiningInfo,
+        GetBlockTemplateError, GetMiningInfo, GetMiningInfoError,
     },
     network::{
-        AddedNode, AddedNodeAddress, Banned, GetAddedNodeInfo, GetConnectionCount, GetNetTotals,
-        GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork,
-        GetPeerInfo, ListBanned, PeerInfo, SetNetworkActive, UploadTarget,
+        AddedNode, AddedNodeAddress, Banned, GetAddedNodeInfo, GetAddedNodeInfoError,
+        GetConnectionCount, GetNetTotals, GetNetworkInfo, GetNetworkInfoAddress,
+        GetNetworkInfoError, GetNetworkInfoNetwork, GetPeerInfo, ListBanned, PeerInfo,
+        PeerInfoError, UploadTarget,
     },
     raw_transactions::{
         CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreatePsbt, CreateRawTransaction,
@@ -272,22 +274,22 @@ pub use self::{
     },
     wallet::{
         AbortRescan, AddMultisigAddress, AddMultisigAddressError, AddressInformation,
-        Bip125Replaceable, BumpFee, BumpFeeError, CreateWallet, DumpPrivKey, DumpWallet,
-        EncryptWallet, GetAddressInfo, GetAddressInfoEmbedded, GetAddressInfoEmbeddedError,
-        GetAddressInfoError, GetAddressInfoLabel, GetAddressesByLabel, GetBalance, GetNewAddress,
-        GetRawChangeAddress, GetReceivedByAddress, GetTransaction, GetTransactionDetail,
-        GetTransactionDetailError, GetTransactionError, GetUnconfirmedBalance, GetWalletInfo,
-        GetWalletInfoError, ImportMulti, ImportMultiEntry, JsonRpcError, ListAddressGroupings,
-        ListAddressGroupingsError, ListAddressGroupingsItem, ListLabels, ListLockUnspent,
-        ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddress,
-        ListReceivedByAddressError, ListReceivedByAddressItem, ListSinceBlock, ListSinceBlockError,
-        ListTransactions, ListUnspent, ListUnspentItem, ListUnspentItemError, ListWallets,
-        LoadWallet, LockUnspent, RescanBlockchain, ScriptType, SendMany, SendToAddress, SetTxFee,
-        SignMessage, SignRawTransactionWithWallet, TransactionCategory, TransactionItem,
-        TransactionItemError, WalletCreateFundedPsbt, WalletCreateFundedPsbtError,
+        AddressPurpose, Bip125Replaceable, BumpFee, BumpFeeError, CreateWallet, DumpPrivKey,
+        DumpWallet, EncryptWallet, GetAddressInfo, GetAddressInfoEmbedded,
+        GetAddressInfoEmbeddedError, GetAddressInfoError, GetAddressInfoLabel, GetAddressesByLabel,
+        GetBalance, GetNewAddress, GetRawChangeAddress, GetReceivedByAddress, GetTransaction,
+        GetTransactionDetail, GetTransactionDetailError, GetTransactionError,
+        GetUnconfirmedBalance, GetWalletInfo, GetWalletInfoError, ImportMulti, ImportMultiEntry,
+        JsonRpcError, ListAddressGroupings, ListAddressGroupingsError, ListAddressGroupingsItem,
+        ListLabels, ListLockUnspent, ListLockUnspentItem, ListLockUnspentItemError,
+        ListReceivedByAddress, ListReceivedByAddressError, ListReceivedByAddressItem,
+        ListSinceBlock, ListSinceBlockError, ListTransactions, ListUnspent, ListUnspentItem,
+        ListUnspentItemError, ListWallets, LoadWallet, LockUnspent, RescanBlockchain, ScriptType,
+        SendMany, SendToAddress, SignMessage, SignRawTransactionWithWallet, TransactionCategory,
+        TransactionItem, TransactionItemError, WalletCreateFundedPsbt, WalletCreateFundedPsbtError,
         WalletProcessPsbt,
     },
-    zmq::GetZmqNotifications,
+    zmq::{GetZmqNotifications, GetZmqNotificationsError},
 };
 #[doc(inline)]
 pub use crate::psbt::{
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use core::fmt;
+use core::{fmt, num};
 
 use bitcoin::amount::ParseAmountError;
 
@@ -38,3 +38,74 @@ impl std::error::Error for GetNetworkInfoError {
         }
     }
 }
+
+/// Error when converting a `PeerInfo` type into the model type.
+#[derive(Debug)]
+pub enum PeerInfoError {
+    /// Conversion of the `address` field failed.
+    Address(num::ParseIntError),
+    /// Conversion of the `address_bind` field failed.
+    AddressBind(num::ParseIntError),
+    /// Conversion of the `address_local` field failed.
+    AddressLocal(num::ParseIntError),
+    /// Conversion of the `services` field failed.
+    Services(num::ParseIntError),
+}
+
+impl fmt::Display for PeerInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PeerInfoError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::AddressBind(ref e) =>
+                write_err!(f, "conversion of the `address_bind` field failed"; e),
+            E::AddressLocal(ref e) =>
+                write_err!(f, "conversion of the `address_local` field failed"; e),
+            E::Services(ref e) => write_err!(f, "conversion of the `services` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PeerInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PeerInfoError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::AddressBind(ref e) => Some(e),
+            E::AddressLocal(ref e) => Some(e),
+            E::Services(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `GetAddedNodeInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetAddedNodeInfoError {
+    /// Conversion of the address entry (with address string) failed.
+    Address(String, num::ParseIntError),
+}
+
+impl fmt::Display for GetAddedNodeInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetAddedNodeInfoError as E;
+
+        match *self {
+            E::Address(ref address, ref e) =>
+                write_err!(f, "conversion of the address entry {} failed", address; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetAddedNodeInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetAddedNodeInfoError as E;
+
+        match *self {
+            E::Address(_, ref e) => Some(e),
+        }
+    }
+}
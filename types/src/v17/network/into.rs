@@ -1,9 +1,68 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use super::error::GetNetworkInfoError;
-use super::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork};
+use core::time::Duration;
+
+use bitcoin::p2p::ServiceFlags;
+
+use super::error::{GetAddedNodeInfoError, GetNetworkInfoError, PeerInfoError};
+use super::{
+    AddedNode, AddedNodeAddress, Banned, GetAddedNodeInfo, GetNetTotals, GetNetworkInfo,
+    GetNetworkInfoAddress, GetNetworkInfoNetwork, GetPeerInfo, ListBanned, PeerInfo, UploadTarget,
+};
 use crate::model;
 
+impl GetAddedNodeInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddedNodeInfo, GetAddedNodeInfoError> {
+        let nodes = self.0.into_iter().map(|n| n.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetAddedNodeInfo(nodes))
+    }
+}
+
+impl AddedNode {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AddedNode, GetAddedNodeInfoError> {
+        let addresses =
+            self.addresses.into_iter().map(|a| a.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::AddedNode { added_node: self.added_node, connected: self.connected, addresses })
+    }
+}
+
+impl AddedNodeAddress {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AddedNodeAddress, GetAddedNodeInfoError> {
+        let address = crate::peer_address(&self.address)
+            .map_err(|e| GetAddedNodeInfoError::Address(self.address, e))?;
+        Ok(model::AddedNodeAddress { address, connected: self.connected })
+    }
+}
+
+impl GetNetTotals {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNetTotals {
+        model::GetNetTotals {
+            total_bytes_received: self.total_bytes_received,
+            total_bytes_sent: self.total_bytes_sent,
+            time: Duration::from_millis(self.time_millis),
+            upload_target: self.upload_target.into_model(),
+        }
+    }
+}
+
+impl UploadTarget {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::UploadTarget {
+        model::UploadTarget {
+            timeframe: Duration::from_secs(self.timeframe),
+            target: self.target,
+            target_reached: self.target_reached,
+            serve_historical_blocks: self.serve_historical_blocks,
+            bytes_left_in_cycle: self.bytes_left_in_cycle,
+            time_left_in_cycle: Duration::from_secs(self.time_left_in_cycle),
+        }
+    }
+}
+
 impl GetNetworkInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetNetworkInfo, GetNetworkInfoError> {
@@ -52,3 +111,97 @@ impl GetNetworkInfoAddress {
         model::GetNetworkInfoAddress { address: self.address, port: self.port, score: self.score }
     }
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        use PeerInfoError as E;
+
+        let address = crate::peer_address(&self.address).map_err(E::Address)?;
+        let address_bind = Some(crate::peer_address(&self.address_bind).map_err(E::AddressBind)?);
+        let address_local = self
+            .address_local
+            .map(|a| crate::peer_address(&a))
+            .transpose()
+            .map_err(E::AddressLocal)?;
+        let services = u64::from_str_radix(&self.services, 16).map_err(E::Services)?;
+        let services = ServiceFlags::from(services);
+        let minimum_fee_filter = None;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            address,
+            address_bind,
+            address_local,
+            network: None,
+            mapped_as: None,
+            services,
+            services_names: None,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: None,
+            last_block: None,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: None,
+            bip152_hb_from: None,
+            add_node: self.add_node,
+            starting_height: Some(self.starting_height),
+            presynced_headers: None,
+            ban_score: self.ban_score,
+            synced_headers: Some(self.synced_headers),
+            synced_blocks: Some(self.synced_blocks),
+            inflight: Some(self.inflight),
+            addresses_relay_enabled: None,
+            addresses_processed: None,
+            addresses_rate_limited: None,
+            permissions: None,
+            whitelisted: self.whitelisted,
+            minimum_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: None,
+            transport_protocol_type: None,
+            session_id: None,
+        })
+    }
+}
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            address: crate::banned_subnet(self.address),
+            banned_until: Duration::from_secs(self.banned_until.into()),
+            ban_created: Duration::from_secs(self.ban_created.into()),
+            ban_reason: Some(self.ban_reason),
+            ban_duration: None,
+            time_remaining: None,
+        }
+    }
+}
@@ -283,12 +283,3 @@ pub struct Banned {
     /// The reason for the ban.
     pub ban_reason: String,
 }
-
-/// Result of JSON-RPC method `setnetworkactive`.
-///
-/// > setnetworkactive
-/// >
-/// > Disable/enable all p2p network activity.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-#[serde(deny_unknown_fields)]
-pub struct SetNetworkActive(pub bool);
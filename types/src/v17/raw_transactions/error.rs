@@ -12,6 +12,7 @@ use crate::psbt::{
     Bip32DerivError, PartialSignatureError, RawTransactionError, RawTransactionInputError,
     RawTransactionOutputError, WitnessUtxoError,
 };
+use crate::NumericError;
 
 /// Error when converting a `DecodePsbt` type into the model type.
 #[derive(Debug)]
@@ -20,10 +21,12 @@ pub enum DecodePsbtError {
     Tx(RawTransactionError),
     /// Conversion of one the map items in the `unknown` field failed.
     Unknown(hex::HexToBytesError),
-    /// Conversion of one of the PSBT inputs failed.
-    Inputs(PsbtInputError),
-    /// Conversion of one of the PSBT outputs failed.
-    Outputs(PsbtOutputError),
+    /// Conversion of the PSBT input at the given index failed.
+    Inputs(usize, PsbtInputError),
+    /// Conversion of the PSBT output at the given index failed.
+    Outputs(usize, PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
 }
 
 impl fmt::Display for DecodePsbtError {
@@ -34,8 +37,11 @@ impl fmt::Display for DecodePsbtError {
             E::Tx(ref e) => write_err!(f, "conversion of raw transaction data field failed"; e),
             E::Unknown(ref e) =>
                 write_err!(f, "conversion of one the map items in the `unknown` field failed"; e),
-            E::Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
-            E::Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            E::Inputs(index, ref e) =>
+                write_err!(f, "conversion of the PSBT input at index {} failed", index; e),
+            E::Outputs(index, ref e) =>
+                write_err!(f, "conversion of the PSBT output at index {} failed", index; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
         }
     }
 }
@@ -48,8 +54,9 @@ impl std::error::Error for DecodePsbtError {
         match *self {
             E::Tx(ref e) => Some(e),
             E::Unknown(ref e) => Some(e),
-            E::Inputs(ref e) => Some(e),
-            E::Outputs(ref e) => Some(e),
+            E::Inputs(_, ref e) => Some(e),
+            E::Outputs(_, ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
         }
     }
 }
@@ -212,6 +219,10 @@ pub enum FinalizePsbtError {
     Psbt(PsbtParseError),
     /// Conversion of the transaction `hex` field failed.
     Hex(encode::FromHexError),
+    /// The PSBT is complete but neither the `hex` nor the `psbt` field is present.
+    MissingHex,
+    /// The PSBT is not complete but the `psbt` field is missing.
+    MissingPsbt,
 }
 
 impl fmt::Display for FinalizePsbtError {
@@ -221,6 +232,8 @@ impl fmt::Display for FinalizePsbtError {
         match *self {
             E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
             E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::MissingHex => write!(f, "complete PSBT but the `hex` field is missing"),
+            E::MissingPsbt => write!(f, "incomplete PSBT but the `psbt` field is missing"),
         }
     }
 }
@@ -233,6 +246,7 @@ impl std::error::Error for FinalizePsbtError {
         match *self {
             E::Psbt(ref e) => Some(e),
             E::Hex(ref e) => Some(e),
+            E::MissingHex | E::MissingPsbt => None,
         }
     }
 }
@@ -240,6 +254,8 @@ impl std::error::Error for FinalizePsbtError {
 /// Error when converting a `FundRawTransaction` type into the model type.
 #[derive(Debug)]
 pub enum FundRawTransactionError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
     /// Conversion of the transaction `hex` field failed.
     Hex(encode::FromHexError),
     /// Conversion of the transaction `fee` field failed.
@@ -251,6 +267,7 @@ impl fmt::Display for FundRawTransactionError {
         use FundRawTransactionError as E;
 
         match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
             E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
         }
@@ -263,12 +280,17 @@ impl std::error::Error for FundRawTransactionError {
         use FundRawTransactionError as E;
 
         match *self {
+            E::Numeric(ref e) => Some(e),
             E::Hex(ref e) => Some(e),
             E::Fee(ref e) => Some(e),
         }
     }
 }
 
+impl From<NumericError> for FundRawTransactionError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Error when converting a `GetRawTransactionVerbose` type into the model type.
 #[derive(Debug)]
 pub enum GetRawTransactionVerboseError {
@@ -107,15 +107,15 @@ impl DecodePsbt {
         let inputs = self
             .inputs
             .into_iter()
-            .map(|input| input.into_input())
-            .collect::<Result<_, _>>()
-            .map_err(E::Inputs)?;
+            .enumerate()
+            .map(|(i, input)| input.into_input().map_err(|e| E::Inputs(i, e)))
+            .collect::<Result<_, _>>()?;
         let outputs = self
             .outputs
             .into_iter()
-            .map(|output| output.into_output())
-            .collect::<Result<_, _>>()
-            .map_err(E::Outputs)?;
+            .enumerate()
+            .map(|(i, output)| output.into_output().map_err(|e| E::Outputs(i, e)))
+            .collect::<Result<_, _>>()?;
 
         // These fields do not appear until Core v23.
         let version = 0;
@@ -124,7 +124,7 @@ impl DecodePsbt {
 
         let psbt =
             bitcoin::Psbt { unsigned_tx, version, xpub, proprietary, unknown, inputs, outputs };
-        let fee = self.fee.map(Amount::from_sat);
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
 
         Ok(model::DecodePsbt { psbt, fee })
     }
@@ -319,6 +319,17 @@ impl FinalizePsbt {
     pub fn into_model(self) -> Result<model::FinalizePsbt, FinalizePsbtError> {
         use FinalizePsbtError as E;
 
+        // Core returns the extracted transaction only if the PSBT is complete and `extract` was
+        // set, in all other cases it returns the PSBT.
+        if self.psbt.is_none() {
+            if self.complete && self.hex.is_none() {
+                return Err(E::MissingHex);
+            }
+            if !self.complete {
+                return Err(E::MissingPsbt);
+            }
+        }
+
         let psbt = self.psbt.map(|s| s.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
         let tx = match self.hex {
             Some(hex) => Some(consensus::encode::deserialize_hex(&hex).map_err(E::Hex)?),
@@ -336,8 +347,13 @@ impl FundRawTransaction {
 
         let tx: Transaction = consensus::encode::deserialize_hex(&self.hex).map_err(E::Hex)?;
         let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        // Core uses -1 to signal that no change output was added.
+        let change_position = match self.change_position {
+            -1 => None,
+            pos => Some(crate::to_u32(pos, "change_position")?),
+        };
 
-        Ok(model::FundRawTransaction { tx, fee, change_position: self.change_position })
+        Ok(model::FundRawTransaction { tx, fee, change_position })
     }
 
     /// Converts json straight to a `bitcoin::Transaction`.
@@ -5,6 +5,8 @@ use core::fmt;
 use bitcoin::blockdata::script::{witness_program, witness_version};
 use bitcoin::{address, hex};
 
+use crate::NumericError;
+
 /// Error when converting a `CreateMultisig` type into the model type.
 #[derive(Debug)]
 pub enum CreateMultisigError {
@@ -53,6 +55,8 @@ pub enum ValidateAddressError {
     WitnessProgramBytes(hex::HexToBytesError),
     /// Conversion of the `witness_program` field failed.
     WitnessProgram(witness_program::Error),
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
 }
 
 impl fmt::Display for ValidateAddressError {
@@ -73,6 +77,7 @@ impl fmt::Display for ValidateAddressError {
             ),
             E::WitnessProgram(ref e) =>
                 write!(f, "conversion of the `witness_program` field failed: {}", e),
+            E::Numeric(ref e) => write!(f, "numeric: {}", e),
         }
     }
 }
@@ -89,6 +94,11 @@ impl std::error::Error for ValidateAddressError {
             E::WitnessVersion(ref e) => Some(e),
             E::WitnessProgramBytes(ref e) => Some(e),
             E::WitnessProgram(ref e) => Some(e),
+            E::Numeric(ref e) => Some(e),
         }
     }
 }
+
+impl From<NumericError> for ValidateAddressError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
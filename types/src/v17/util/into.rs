@@ -47,8 +47,13 @@ impl ValidateAddress {
     pub fn into_model(self) -> Result<model::ValidateAddress, ValidateAddressError> {
         use ValidateAddressError as E;
 
-        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
-        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let script_pubkey = self
+            .script_pubkey
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::ScriptPubkey)?;
         let (witness_version, witness_program) = match (self.witness_version, self.witness_program)
         {
             (Some(v), Some(hex)) => {
@@ -75,6 +80,8 @@ impl ValidateAddress {
             is_witness: self.is_witness,
             witness_version,
             witness_program,
+            error: None,
+            error_locations: None,
         })
     }
 }
@@ -100,16 +100,16 @@ pub struct ValidateAddress {
     #[serde(rename = "isvalid")]
     pub is_valid: bool,
     /// The bitcoin address validated.
-    pub address: String,
+    pub address: Option<String>,
     /// The hex encoded scriptPubKey generated by the address.
     #[serde(rename = "scriptPubKey")]
-    pub script_pubkey: String,
+    pub script_pubkey: Option<String>,
     /// If the key is a script.
     #[serde(rename = "isscript")]
-    pub is_script: bool,
+    pub is_script: Option<bool>,
     /// If the address is a witness address.
     #[serde(rename = "iswitness")]
-    pub is_witness: bool,
+    pub is_witness: Option<bool>,
     /// The version number of the witness program.
     pub witness_version: Option<i64>,
     /// The hex value of the witness program.
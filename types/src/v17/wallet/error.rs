@@ -8,6 +8,7 @@ use bitcoin::psbt::PsbtParseError;
 use bitcoin::{address, bip32, hex, key, witness_program, witness_version};
 
 use crate::error::write_err;
+use crate::psbt::RawTransactionError;
 use crate::NumericError;
 
 /// Error when converting a `AddMultisigAddress` type into the model type.
@@ -255,6 +256,8 @@ pub enum GetTransactionError {
     Tx(encode::FromHexError),
     /// Conversion of the `details` field failed.
     Details(GetTransactionDetailError),
+    /// Conversion of the `decoded` field failed.
+    Decoded(RawTransactionError),
 }
 
 impl fmt::Display for GetTransactionError {
@@ -271,6 +274,7 @@ impl fmt::Display for GetTransactionError {
                 write_err!(f, "conversion of the `wallet_conflicts` field failed"; e),
             E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Details(ref e) => write_err!(f, "conversion of the `details` field failed"; e),
+            E::Decoded(ref e) => write_err!(f, "conversion of the `decoded` field failed"; e),
         }
     }
 }
@@ -289,6 +293,7 @@ impl std::error::Error for GetTransactionError {
             E::WalletConflicts(ref e) => Some(e),
             E::Tx(ref e) => Some(e),
             E::Details(ref e) => Some(e),
+            E::Decoded(ref e) => Some(e),
         }
     }
 }
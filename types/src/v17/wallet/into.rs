@@ -8,8 +8,8 @@ use bitcoin::hex::FromHex;
 use bitcoin::key::{self, PrivateKey, PublicKey};
 use bitcoin::psbt::PsbtParseError;
 use bitcoin::{
-    address, bip32, sign_message, Address, Amount, BlockHash, Psbt, ScriptBuf, SignedAmount,
-    Transaction, Txid, WitnessProgram, WitnessVersion,
+    address, bip32, sign_message, Address, Amount, BlockHash, OutPoint, Psbt, ScriptBuf,
+    SignedAmount, Transaction, Txid, WitnessProgram, WitnessVersion,
 };
 
 // TODO: Use explicit imports?
@@ -91,7 +91,8 @@ impl BumpFee {
 impl CreateWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> model::CreateWallet {
-        model::CreateWallet { name: self.name, warnings: vec![self.warning] }
+        let warnings = crate::warning_to_option(self.warning).into_iter().collect();
+        model::CreateWallet { name: self.name, warnings }
     }
 }
 
@@ -103,10 +104,10 @@ impl DumpPrivKey {
     }
 }
 
-impl AddressInformation {
+impl DumpWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> model::AddressInformation {
-        model::AddressInformation { purpose: self.purpose.into_model() }
+    pub fn into_model(self) -> model::DumpWallet {
+        model::DumpWallet { file_name: self.file_name.into() }
     }
 }
 
@@ -117,8 +118,7 @@ impl GetAddressesByLabel {
 
         for (k, v) in self.0.into_iter() {
             let address = k.parse::<Address<_>>()?;
-            let info = v.into_model();
-            map.insert(address, info);
+            map.insert(address, v.purpose.into_model());
         }
 
         Ok(model::GetAddressesByLabel(map))
@@ -493,6 +493,11 @@ impl ListAddressGroupingsItem {
     }
 }
 
+impl ListLabels {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListLabels { model::ListLabels(self.0) }
+}
+
 impl ListLockUnspent {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::ListLockUnspent, ListLockUnspentItemError> {
@@ -504,13 +509,13 @@ impl ListLockUnspent {
 
 impl ListLockUnspentItem {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> Result<model::ListLockUnspentItem, ListLockUnspentItemError> {
+    pub fn into_model(self) -> Result<OutPoint, ListLockUnspentItemError> {
         use ListLockUnspentItemError as E;
 
         let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
         let vout = crate::to_u32(self.vout, "vout")?;
 
-        Ok(model::ListLockUnspentItem { txid, vout })
+        Ok(OutPoint { txid, vout })
     }
 }
 
@@ -692,7 +697,8 @@ impl ListWallets {
 impl LoadWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> model::LoadWallet {
-        model::LoadWallet { name: self.name, warnings: vec![self.warning] }
+        let warnings = crate::warning_to_option(self.warning).into_iter().collect();
+        model::LoadWallet { name: self.name, warnings }
     }
 }
 
@@ -736,8 +742,12 @@ impl WalletCreateFundedPsbt {
         use WalletCreateFundedPsbtError as E;
 
         let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
-        let fee = SignedAmount::from_btc(self.fee).map_err(E::Fee)?;
-        let change_position = crate::to_u32(self.change_position, "change_position")?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        // Core uses -1 to signal that no change output was added.
+        let change_position = match self.change_position {
+            -1 => None,
+            pos => Some(crate::to_u32(pos, "change_position")?),
+        };
         Ok(model::WalletCreateFundedPsbt { psbt, fee, change_position })
     }
 }
@@ -507,6 +507,8 @@ pub enum Bip125Replaceable {
 ///
 /// > getunconfirmedbalance
 /// > Returns the server's total unconfirmed balance
+///
+/// Deprecated from Core v0.19, identical to `getbalances().mine.untrusted_pending`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetUnconfirmedBalance(pub f64); // Core docs are missing so this is just a guess.
@@ -937,15 +939,6 @@ impl SendToAddress {
     pub fn txid(self) -> Result<Txid, hex::HexToArrayError> { Ok(self.into_model()?.txid) }
 }
 
-/// Result of JSON-RPC method `settxfee`.
-///
-/// > settxfee
-/// >
-/// > Arguments:
-/// > 1. amount         (numeric or string, required) The transaction fee in BTC/kB
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub struct SetTxFee(pub bool);
-
 /// Result of the JSON-RPC method `signmessage`.
 ///
 /// > signmessage "address" "message"
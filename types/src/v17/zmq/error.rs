@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use core::{fmt, num};
+
+use crate::error::write_err;
+
+/// Error when converting a `GetZmqNotifications` type into the model type.
+#[derive(Debug)]
+pub enum GetZmqNotificationsError {
+    /// Conversion of the `address` field (with address string) failed.
+    Address(String, num::ParseIntError),
+}
+
+impl fmt::Display for GetZmqNotificationsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetZmqNotificationsError as E;
+
+        match *self {
+            E::Address(ref address, ref e) =>
+                write_err!(f, "conversion of the `address` field {} failed", address; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetZmqNotificationsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetZmqNotificationsError as E;
+
+        match *self {
+            E::Address(_, ref e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use super::{GetZmqNotifications, GetZmqNotificationsError};
+use crate::model;
+
+impl GetZmqNotifications {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetZmqNotifications, GetZmqNotificationsError> {
+        zmq_address(&self.address)?;
+
+        Ok(model::GetZmqNotifications { type_: self.type_, address: self.address, hwm: None })
+    }
+}
+
+/// Validates a ZMQ publisher endpoint as returned by `getzmqnotifications`.
+///
+/// TCP endpoints (e.g. "tcp://127.0.0.1:28332") must have a valid port, other transports (e.g.
+/// "ipc://") are accepted as is.
+pub(crate) fn zmq_address(address: &str) -> Result<(), GetZmqNotificationsError> {
+    if let Some(endpoint) = address.strip_prefix("tcp://") {
+        crate::peer_address(endpoint)
+            .map_err(|e| GetZmqNotificationsError::Address(address.to_owned(), e))?;
+    }
+    Ok(())
+}
@@ -4,8 +4,14 @@
 //!
 //! Types for methods found under the `== Zmq ==` section of the API docs.
 
+mod error;
+mod into;
+
 use serde::{Deserialize, Serialize};
 
+pub use self::error::GetZmqNotificationsError;
+pub(crate) use self::into::zmq_address;
+
 /// Result of JSON-RPC method `getzmqnotifications`.
 ///
 ///> getzmqnotifications
@@ -4,8 +4,12 @@
 //!
 //! Types for methods found under the `== Control ==` section of the API docs.
 
+use core::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+
 /// Result of JSON-RPC method `getrpcinfo`.
 ///
 /// > getrpcinfo
@@ -14,7 +18,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetRpcInfo {
-    active_commands: Vec<ActiveCommand>,
+    /// All active commands.
+    pub active_commands: Vec<ActiveCommand>,
 }
 
 /// Information about an active command. Part of `getrpcinfo`.
@@ -26,3 +31,18 @@ pub struct ActiveCommand {
     /// The running time in microseconds.
     pub duration: u64,
 }
+
+impl GetRpcInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetRpcInfo {
+        let active_commands = self.active_commands.into_iter().map(|c| c.into_model()).collect();
+        model::GetRpcInfo { active_commands, log_path: None }
+    }
+}
+
+impl ActiveCommand {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ActiveCommand {
+        model::ActiveCommand { method: self.method, duration: Duration::from_micros(self.duration) }
+    }
+}
@@ -45,7 +45,7 @@
 //! | gettxoutproof                      | returns string  |                                        |
 //! | gettxoutsetinfo                    | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
-//! | pruneblockchain                    | version         |                                        |
+//! | pruneblockchain                    | version + model |                                        |
 //! | savemempool                        | returns nothing |                                        |
 //! | scantxoutset                       | omitted         | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
@@ -59,9 +59,9 @@
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getmemoryinfo                      | version         |                                        |
-//! | getrpcinfo                         | version         |                                        |
+//! | getrpcinfo                         | version + model |                                        |
 //! | help                               | returns string  |                                        |
-//! | logging                            | version         |                                        |
+//! | logging                            | version + model |                                        |
 //! | stop                               | returns string  |                                        |
 //! | uptime                             | returns numeric |                                        |
 //!
@@ -84,7 +84,7 @@
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getblocktemplate                   | version + model |                                        |
 //! | getmininginfo                      | version + model |                                        |
-//! | getnetworkhashps                   | returns boolean |                                        |
+//! | getnetworkhashps                   | returns numeric |                                        |
 //! | prioritisetransaction              | returns boolean |                                        |
 //! | submitblock                        | returns nothing |                                        |
 //! | submitheader                       | returns nothing |                                        |
@@ -99,16 +99,16 @@
 //! | addnode                            | returns nothing |                                        |
 //! | clearbanned                        | returns nothing |                                        |
 //! | disconnectnode                     | returns nothing |                                        |
-//! | getaddednodeinfo                   | version         |                                        |
+//! | getaddednodeinfo                   | version + model |                                        |
 //! | getconnectioncount                 | version         |                                        |
-//! | getnettotals                       | version         |                                        |
+//! | getnettotals                       | version + model |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
-//! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
-//! | listbanned                         | version         |                                        |
+//! | getnodeaddresses                   | version + model |                                        |
+//! | getpeerinfo                        | version + model |                                        |
+//! | listbanned                         | version + model |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
-//! | setnetworkactive                   | version         |                                        |
+//! | setnetworkactive                   | returns boolean |                                        |
 //!
 //! </details>
 //!
@@ -145,7 +145,7 @@
 //! | createmultisig                     | version + model |                                        |
 //! | deriveaddresses                    | version + model |                                        |
 //! | estimatesmartfee                   | version + model |                                        |
-//! | getdescriptorinfo                  | version         |                                        |
+//! | getdescriptorinfo                  | version + model |                                        |
 //! | signmessagewithprivkey             | version + model |                                        |
 //! | validateaddress                    | version + model |                                        |
 //! | verifymessage                      | version         |                                        |
@@ -164,7 +164,7 @@
 //! | bumpfee                            | version + model |                                        |
 //! | createwallet                       | version + model |                                        |
 //! | dumpprivkey                        | version + model |                                        |
-//! | dumpwallet                         | version         |                                        |
+//! | dumpwallet                         | version + model |                                        |
 //! | encryptwallet                      | version         |                                        |
 //! | getaddressesbylabel                | version + model |                                        |
 //! | getaddressinfo                     | version + model |                                        |
@@ -184,14 +184,14 @@
 //! | importwallet                       | returns nothing |                                        |
 //! | keypoolrefill                      | returns nothing |                                        |
 //! | listaddressgroupings               | version + model |                                        |
-//! | listlabels                         | version         |                                        |
+//! | listlabels                         | version + model |                                        |
 //! | listlockunspent                    | version + model |                                        |
 //! | listreceivedbyaddress              | version + model |                                        |
 //! | listreceivedbylabel                | version + model |                                        |
 //! | listsinceblock                     | version + model |                                        |
 //! | listtransactions                   | version + model |                                        |
 //! | listunspent                        | version + model |                                        |
-//! | listwalletdir                      | version         |                                        |
+//! | listwalletdir                      | version + model |                                        |
 //! | listwallets                        | version + model |                                        |
 //! | loadwallet                         | version + model |                                        |
 //! | lockunspent                        | version         |                                        |
@@ -201,7 +201,7 @@
 //! | sendtoaddress                      | version + model |                                        |
 //! | sethdseed                          | returns nothing |                                        |
 //! | setlabel                           | returns nothing |                                        |
-//! | settxfee                           | version         |                                        |
+//! | settxfee                           | returns boolean |                                        |
 //! | signmessage                        | version + model |                                        |
 //! | signrawtransactionwithwallet       | version + model |                                        |
 //! | unloadwallet                       | returns nothing |                                        |
@@ -218,7 +218,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -238,12 +238,12 @@ pub use self::{
         GetMempoolDescendantsVerbose, GetMempoolEntry, MempoolEntry,
     },
     control::{ActiveCommand, GetRpcInfo},
-    network::{GetNodeAddresses, GetPeerInfo, NodeAddress, PeerInfo},
+    network::{GetNodeAddresses, GetPeerInfo, NodeAddress, PeerInfo, PeerInfoError},
     raw_transactions::{
         AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
         AnalyzePsbtInputMissingError, JoinPsbts, UtxoUpdatePsbt,
     },
-    util::{DeriveAddresses, GetDescriptorInfo},
+    util::{DeriveAddresses, DeriveAddressesError, GetDescriptorInfo},
     wallet::{
         GetAddressInfo, GetAddressInfoEmbedded, GetAddressInfoError, GetReceivedByLabel,
         GetWalletInfo, ImportMulti, ImportMultiEntry, JsonRpcError, ListReceivedByAddress,
@@ -255,32 +255,35 @@ pub use self::{
 #[doc(inline)]
 pub use crate::v17::{
     AbortRescan, AddMultisigAddress, AddMultisigAddressError, AddedNode, AddedNodeAddress,
-    AddressInformation, Banned, Bip32Deriv, Bip9Softfork, Bip9SoftforkStatus, BumpFee,
-    BumpFeeError, ChainTips, ChainTipsError, ChainTipsStatus, CombinePsbt, CombineRawTransaction,
-    ConvertToPsbt, CreateMultisig, CreateMultisigError, CreatePsbt, CreateRawTransaction,
-    CreateWallet, DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript,
-    DecodeScriptError, DumpPrivKey, DumpWallet, EncryptWallet, EstimateSmartFee, FinalizePsbt,
-    FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress,
-    GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel,
-    GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
+    AddressInformation, AddressPurpose, Banned, Bip32Deriv, Bip9Softfork, Bip9SoftforkStatistics,
+    Bip9SoftforkStatus, BumpFee, BumpFeeError, ChainTips, ChainTipsError, ChainTipsStatus,
+    CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisig, CreateMultisigError,
+    CreatePsbt, CreateRawTransaction, CreateWallet, DecodePsbt, DecodePsbtError,
+    DecodeRawTransaction, DecodeScript, DecodeScriptError, DumpPrivKey, DumpWallet, EncryptWallet,
+    EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError,
+    Generate, GenerateToAddress, GetAddedNodeInfo, GetAddedNodeInfoError,
+    GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel, GetBalance,
+    GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
     GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
     GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
-    GetBlockVerboseZero, GetBlockchainInfo, GetBlockchainInfoError, GetChainTips, GetChainTxStats,
-    GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfo,
-    GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfo, GetNetworkInfoAddress,
-    GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress, GetRawMempool,
-    GetRawMempoolVerbose, GetRawTransaction, GetRawTransactionVerbose,
-    GetRawTransactionVerboseError, GetReceivedByAddress, GetTransaction, GetTransactionDetail,
-    GetTransactionDetailError, GetTransactionError, GetTxOut, GetTxOutError, GetTxOutSetInfo,
-    GetTxOutSetInfoError, GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings,
+    GetBlockVerboseTwo, GetBlockVerboseTwoError, GetBlockVerboseTwoInput,
+    GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetBlockchainInfo, GetBlockchainInfoError,
+    GetChainTips, GetChainTxStats, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
+    GetMemoryInfoStats, GetMempoolInfo, GetMempoolInfoError, GetMiningInfo, GetMiningInfoError,
+    GetNetTotals, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoError,
+    GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress, GetRawMempool, GetRawMempoolVerbose,
+    GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+    GetReceivedByAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailError,
+    GetTransactionError, GetTxOut, GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError,
+    GetUnconfirmedBalance, GetWalletInfoError, GetZmqNotificationsError, ListAddressGroupings,
     ListAddressGroupingsError, ListAddressGroupingsItem, ListBanned, ListLabels, ListLockUnspent,
     ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddressError, ListSinceBlock,
     ListSinceBlockError, ListTransactions, ListUnspentItemError, ListWallets, LoadWallet,
     LockUnspent, Locked, Logging, MapMempoolEntryError, MempoolAcceptance, MempoolEntryError,
     MempoolEntryFees, MempoolEntryFeesError, NumericError, PruneBlockchain, PsbtInput, PsbtOutput,
     PsbtScript, RawTransaction, RawTransactionError, RawTransactionInput, RawTransactionOutput,
-    RescanBlockchain, ScriptType, SendMany, SendRawTransaction, SendToAddress, SetNetworkActive,
-    SetTxFee, SignFail, SignFailError, SignMessage, SignMessageWithPrivKey, SignRawTransaction,
+    RescanBlockchain, ScriptType, SendMany, SendRawTransaction, SendToAddress, SignFail,
+    SignFailError, SignMessage, SignMessageWithPrivKey, SignRawTransaction,
     SignRawTransactionError, SignRawTransactionWithKey, SignRawTransactionWithWallet, Softfork,
     SoftforkReject, TestMempoolAccept, TransactionCategory, TransactionItem, TransactionItemError,
     UploadTarget, ValidateAddress, ValidateAddressError, VerifyChain, VerifyMessage,
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use core::{fmt, num};
+
+use bitcoin::amount::ParseAmountError;
+
+use crate::error::write_err;
+
+/// Error when converting a `PeerInfo` type into the model type.
+#[derive(Debug)]
+pub enum PeerInfoError {
+    /// Conversion of the `address` field failed.
+    Address(num::ParseIntError),
+    /// Conversion of the `address_bind` field failed.
+    AddressBind(num::ParseIntError),
+    /// Conversion of the `address_local` field failed.
+    AddressLocal(num::ParseIntError),
+    /// Conversion of the `services` field failed.
+    Services(num::ParseIntError),
+    /// Conversion of the `minimum_fee_filter` field failed.
+    MinimumFeeFilter(ParseAmountError),
+}
+
+impl fmt::Display for PeerInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PeerInfoError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::AddressBind(ref e) =>
+                write_err!(f, "conversion of the `address_bind` field failed"; e),
+            E::AddressLocal(ref e) =>
+                write_err!(f, "conversion of the `address_local` field failed"; e),
+            E::Services(ref e) => write_err!(f, "conversion of the `services` field failed"; e),
+            E::MinimumFeeFilter(ref e) =>
+                write_err!(f, "conversion of the `minimum_fee_filter` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PeerInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PeerInfoError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::AddressBind(ref e) => Some(e),
+            E::AddressLocal(ref e) => Some(e),
+            E::Services(ref e) => Some(e),
+            E::MinimumFeeFilter(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<crate::v17::PeerInfoError> for PeerInfoError {
+    fn from(e: crate::v17::PeerInfoError) -> Self {
+        use crate::v17::PeerInfoError as E;
+
+        match e {
+            E::Address(e) => Self::Address(e),
+            E::AddressBind(e) => Self::AddressBind(e),
+            E::AddressLocal(e) => Self::AddressLocal(e),
+            E::Services(e) => Self::Services(e),
+        }
+    }
+}
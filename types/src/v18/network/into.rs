@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use bitcoin::p2p::ServiceFlags;
+
+use super::{GetNodeAddresses, GetPeerInfo, NodeAddress, PeerInfo, PeerInfoError};
+use crate::{model, v17};
+
+impl GetNodeAddresses {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNodeAddresses {
+        model::GetNodeAddresses(self.0.into_iter().map(|a| a.into_model()).collect())
+    }
+}
+
+impl NodeAddress {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::NodeAddress {
+        model::NodeAddress {
+            time: self.time,
+            services: ServiceFlags::from(self.services),
+            address: crate::node_address_kind(self.address, self.port),
+            network: None, // v22 and later only.
+        }
+    }
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        let minimum_fee_filter =
+            crate::btc_per_kb(self.min_fee_filter).map_err(PeerInfoError::MinimumFeeFilter)?;
+
+        let peer = v17::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            services: self.services,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            add_node: self.add_node,
+            starting_height: self.starting_height,
+            ban_score: self.ban_score,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            whitelisted: self.whitelisted,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+        };
+
+        Ok(model::PeerInfo { minimum_fee_filter, ..peer.into_model()? })
+    }
+}
@@ -4,10 +4,15 @@
 //!
 //! Types for methods found under the `== Network ==` section of the API docs.
 
+mod error;
+mod into;
+
 use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+pub use self::error::PeerInfoError;
+
 /// Result of JSON-RPC method `getnodeaddresses`.
 ///
 /// > getnodeaddresses ( count )
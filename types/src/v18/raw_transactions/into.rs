@@ -34,7 +34,7 @@ impl AnalyzePsbt {
             estimated_vsize: self.estimated_vsize,
             estimated_fee_rate,
             fee,
-            next: self.next,
+            next: psbt_role(self.next),
         })
     }
 }
@@ -48,11 +48,25 @@ impl AnalyzePsbtInput {
             has_utxo: self.has_utxo,
             is_final: self.is_final,
             missing,
-            next: self.next,
+            next: self.next.map(psbt_role),
         })
     }
 }
 
+/// Converts a role name as returned by Core into the model type.
+fn psbt_role(role: String) -> model::PsbtRole {
+    use model::PsbtRole as R;
+
+    match role.as_str() {
+        "creator" => R::Creator,
+        "updater" => R::Updater,
+        "signer" => R::Signer,
+        "finalizer" => R::Finalizer,
+        "extractor" => R::Extractor,
+        _ => R::Unknown(role),
+    }
+}
+
 impl AnalyzePsbtInputMissing {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use core::fmt;
+
+use bitcoin::address;
+
+use crate::error::write_err;
+
+/// Error when converting a `DeriveAddresses` type into the model type.
+#[derive(Debug)]
+pub enum DeriveAddressesError {
+    /// Conversion of the address (with index) in the list failed.
+    Address(usize, address::ParseError),
+}
+
+impl fmt::Display for DeriveAddressesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DeriveAddressesError as E;
+
+        match *self {
+            E::Address(index, ref e) =>
+                write_err!(f, "conversion of the address at index {} failed", index; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeriveAddressesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DeriveAddressesError as E;
+
+        match *self {
+            E::Address(_, ref e) => Some(e),
+        }
+    }
+}
@@ -1,18 +1,30 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::address;
-
-use super::DeriveAddresses;
+use super::{DeriveAddresses, DeriveAddressesError, GetDescriptorInfo};
 use crate::model;
 
 impl DeriveAddresses {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> Result<model::DeriveAddresses, address::ParseError> {
+    pub fn into_model(self) -> Result<model::DeriveAddresses, DeriveAddressesError> {
         let mut addresses = Vec::with_capacity(self.0.len());
-        for addr_str in self.0 {
-            let addr = addr_str.parse()?;
+        for (index, addr_str) in self.0.into_iter().enumerate() {
+            let addr = addr_str.parse().map_err(|e| DeriveAddressesError::Address(index, e))?;
             addresses.push(addr);
         }
         Ok(model::DeriveAddresses { addresses })
     }
 }
+
+impl GetDescriptorInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetDescriptorInfo {
+        model::GetDescriptorInfo {
+            descriptor: self.descriptor,
+            multipath_expansion: None,
+            checksum: None,
+            is_range: self.is_range,
+            is_solvable: self.is_solvable,
+            has_private_keys: self.has_private_keys,
+        }
+    }
+}
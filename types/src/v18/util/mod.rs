@@ -4,16 +4,24 @@
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
 
+mod error;
 mod into;
 
 use serde::{Deserialize, Serialize};
 
+#[rustfmt::skip]                // Keep public re-exports separate.
+pub use self::error::DeriveAddressesError;
+
 /// Result of JSON-RPC method `deriveaddresses`.
 ///
 /// > deriveaddresses "descriptor" ( range )
 /// >
 /// > Derives one or more addresses corresponding to an output descriptor.
 /// > Returns an array of derived addresses.
+/// >
+/// > Arguments:
+/// > 1. descriptor    (string, required) The descriptor.
+/// > 2. range         (numeric or array, optional) If a ranged descriptor is used, this specifies the end or the range (in [begin,end] notation) to derive.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct DeriveAddresses(pub Vec<String>);
@@ -13,7 +13,7 @@ use super::{
     GetReceivedByLabel, GetWalletInfo, GetWalletInfoError, ListReceivedByAddress,
     ListReceivedByAddressError, ListReceivedByAddressItem, ListReceivedByLabel,
     ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent, ListUnspentItem,
-    ListUnspentItemError,
+    ListUnspentItemError, ListWalletDir,
 };
 use crate::model;
 
@@ -326,3 +326,10 @@ impl ListUnspentItem {
         })
     }
 }
+
+impl ListWalletDir {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListWalletDir {
+        model::ListWalletDir { wallets: self.wallets.into_iter().map(|w| w.name).collect() }
+    }
+}
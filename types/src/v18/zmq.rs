@@ -6,6 +6,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::GetZmqNotificationsError;
+use crate::model;
+
 /// Result of JSON-RPC method `getzmqnotifications`.
 ///
 ///> getzmqnotifications
@@ -22,3 +25,16 @@ pub struct GetZmqNotifications {
     /// Outbound message high water mark.
     pub hwm: u64,
 }
+
+impl GetZmqNotifications {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetZmqNotifications, GetZmqNotificationsError> {
+        crate::v17::zmq::zmq_address(&self.address)?;
+
+        Ok(model::GetZmqNotifications {
+            type_: self.type_,
+            address: self.address,
+            hwm: Some(self.hwm),
+        })
+    }
+}
@@ -128,6 +128,8 @@ pub enum MempoolEntryError {
     Wtxid(hex::HexToArrayError),
     /// Conversion of the `MempoolEntryFees` type failed.
     Fees(MempoolEntryFeesError),
+    /// Neither the `fees` object nor the deprecated top-level fee fields were present.
+    MissingFees,
     /// Conversion of the `depends` field failed.
     Depends(hex::HexToArrayError),
     /// Conversion of the `spent_by` field failed.
@@ -146,6 +148,7 @@ impl fmt::Display for MempoolEntryError {
             E::Numeric(ref e) => write_err!(f, "numeric"; e),
             E::Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
             E::Fees(ref e) => write_err!(f, "conversion of the `fees` field failed"; e),
+            E::MissingFees => write!(f, "no `fees` object and no deprecated fee fields"),
             E::Depends(ref e) => write_err!(f, "conversion of the `depends` field failed"; e),
             E::SpentBy(ref e) => write_err!(f, "conversion of the `spent_by` field failed"; e),
         }
@@ -161,6 +164,7 @@ impl std::error::Error for MempoolEntryError {
             E::Numeric(ref e) => Some(e),
             E::Wtxid(ref e) => Some(e),
             E::Fees(ref e) => Some(e),
+            E::MissingFees => None,
             E::Depends(ref e) => Some(e),
             E::SpentBy(ref e) => Some(e),
         }
@@ -206,3 +210,100 @@ impl std::error::Error for MempoolEntryFeesError {
         }
     }
 }
+
+/// Error when converting a `ScanTxOutSet` type into the model type.
+#[cfg(feature = "experimental-rpc")]
+#[derive(Debug)]
+pub enum ScanTxOutSetError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `bestblock` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the `total_amount` field failed.
+    TotalAmount(ParseAmountError),
+    /// Conversion of an item in the `unspents` list failed.
+    Unspents(ScanTxOutSetUnspentError),
+}
+
+#[cfg(feature = "experimental-rpc")]
+impl fmt::Display for ScanTxOutSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::BestBlock(ref e) => write_err!(f, "conversion of the `bestblock` field failed"; e),
+            E::TotalAmount(ref e) =>
+                write_err!(f, "conversion of the `total_amount` field failed"; e),
+            E::Unspents(ref e) =>
+                write_err!(f, "conversion of an item in the `unspents` list failed"; e),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "experimental-rpc"))]
+impl std::error::Error for ScanTxOutSetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ScanTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::BestBlock(ref e) => Some(e),
+            E::TotalAmount(ref e) => Some(e),
+            E::Unspents(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "experimental-rpc")]
+impl From<NumericError> for ScanTxOutSetError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `ScanTxOutSetUnspent` type into the model type.
+#[cfg(feature = "experimental-rpc")]
+#[derive(Debug)]
+pub enum ScanTxOutSetUnspentError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `scriptPubKey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+}
+
+#[cfg(feature = "experimental-rpc")]
+impl fmt::Display for ScanTxOutSetUnspentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanTxOutSetUnspentError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `scriptPubKey` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "experimental-rpc"))]
+impl std::error::Error for ScanTxOutSetUnspentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ScanTxOutSetUnspentError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "experimental-rpc")]
+impl From<NumericError> for ScanTxOutSetUnspentError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
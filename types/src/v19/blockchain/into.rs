@@ -3,17 +3,25 @@
 use std::collections::BTreeMap;
 
 use bitcoin::hex::{self, FromHex as _};
-use bitcoin::{bip158, Amount, BlockHash, Network, Txid, Work, Wtxid};
+use bitcoin::{bip158, Amount, BlockHash, Denomination, Network, Txid, Work, Wtxid};
+#[cfg(feature = "experimental-rpc")]
+use bitcoin::{OutPoint, ScriptBuf};
 
 use super::error::{
     GetBlockFilterError, GetBlockchainInfoError, MapMempoolEntryError, MempoolEntryError,
     MempoolEntryFeesError,
 };
+#[cfg(feature = "experimental-rpc")]
+use super::error::{ScanTxOutSetError, ScanTxOutSetUnspentError};
 use super::{
-    GetBlockFilter, GetBlockchainInfo, GetChainTxStats, GetChainTxStatsError, GetMempoolAncestors,
+    Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBlockFilter,
+    GetBlockchainInfo, GetChainTxStats, GetChainTxStatsError, GetMempoolAncestors,
     GetMempoolAncestorsVerbose, GetMempoolDescendants, GetMempoolDescendantsVerbose,
-    GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError, MempoolEntry, MempoolEntryFees,
+    GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError, MempoolEntry, MempoolEntryFees, Softfork,
+    SoftforkType,
 };
+#[cfg(feature = "experimental-rpc")]
+use super::{ScanTxOutSet, ScanTxOutSetUnspent};
 use crate::model;
 
 impl GetBlockchainInfo {
@@ -29,7 +37,7 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = Softfork::map_into_model(self.softforks)?;
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -56,13 +64,86 @@ impl GetBlockchainInfo {
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Softfork, crate::NumericError> {
+        Ok(model::Softfork {
+            type_: self.type_.into_model(),
+            bip9: self.bip9.map(|b| b.into_model()).transpose()?,
+            height: self.height.map(|h| crate::to_u32(h, "height")).transpose()?,
+            active: self.active,
+        })
+    }
+
+    /// Converts the `softforks` map of `getblockchaininfo` into the model type.
+    pub(crate) fn map_into_model(
+        softforks: BTreeMap<String, Self>,
+    ) -> Result<BTreeMap<String, model::Softfork>, crate::NumericError> {
+        softforks.into_iter().map(|(name, softfork)| Ok((name, softfork.into_model()?))).collect()
+    }
+}
+
+impl SoftforkType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::SoftforkType {
+        match self {
+            Self::Buried => model::SoftforkType::Buried,
+            Self::Bip9 => model::SoftforkType::Bip9,
+        }
+    }
+}
+
+impl Bip9SoftforkInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkInfo, crate::NumericError> {
+        Ok(model::Bip9SoftforkInfo {
+            status: self.status.into_model(),
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: crate::to_u32(self.since, "since")?,
+            min_activation_height: None, // v0.21 and later only.
+            statistics: self.statistics.map(|s| s.into_model()).transpose()?,
+        })
+    }
+}
+
+impl Bip9SoftforkStatus {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip9SoftforkStatus {
+        use model::Bip9SoftforkStatus::*;
+
+        match self {
+            Self::Defined => Defined,
+            Self::Started => Started,
+            Self::LockedIn => LockedIn,
+            Self::Active => Active,
+            Self::Failed => Failed,
+        }
+    }
+}
+
+impl Bip9SoftforkStatistics {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkStatistics, crate::NumericError> {
+        Ok(model::Bip9SoftforkStatistics {
+            period: crate::to_u32(self.period, "period")?,
+            threshold: self.threshold.map(|t| crate::to_u32(t, "threshold")).transpose()?,
+            elapsed: crate::to_u32(self.elapsed, "elapsed")?,
+            count: crate::to_u32(self.count, "count")?,
+            possible: self.possible,
+        })
+    }
+}
+
 impl GetBlockFilter {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockFilter, GetBlockFilterError> {
         use GetBlockFilterError as E;
 
         let filter = Vec::from_hex(&self.filter).map_err(E::Filter)?;
-        let header = self.header.parse::<bip158::FilterHash>().map_err(E::Header)?;
+        let filter = bip158::BlockFilter::new(&filter);
+        let header = self.header.parse::<bip158::FilterHeader>().map_err(E::Header)?;
         Ok(model::GetBlockFilter { filter, header })
     }
 }
@@ -80,7 +161,6 @@ impl GetChainTxStats {
             self.window_tx_count.map(|h| crate::to_u32(h, "window_tx_count")).transpose()?;
         let window_interval =
             self.window_interval.map(|h| crate::to_u32(h, "window_interval")).transpose()?;
-        let tx_rate = self.tx_rate.map(|h| crate::to_u32(h, "tx_rate")).transpose()?;
 
         Ok(model::GetChainTxStats {
             time: crate::to_u32(self.time, "time")?,
@@ -90,7 +170,7 @@ impl GetChainTxStats {
             window_block_count: crate::to_u32(self.window_block_count, "window_block_count")?,
             window_tx_count,
             window_interval,
-            tx_rate,
+            tx_rate: self.tx_rate,
         })
     }
 }
@@ -163,7 +243,16 @@ impl MempoolEntry {
         let ancestor_count = crate::to_u32(self.ancestor_count, "ancestor_count")?;
         let ancestor_size = crate::to_u32(self.ancestor_size, "ancestor_size")?;
         let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
-        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let fees = match self.fees {
+            Some(fees) => fees.into_model(),
+            None => legacy_fees(
+                self.fee.ok_or(E::MissingFees)?,
+                self.modified_fee.ok_or(E::MissingFees)?,
+                self.ancestor_fees.ok_or(E::MissingFees)?,
+                self.descendant_fees.ok_or(E::MissingFees)?,
+            ),
+        }
+        .map_err(E::Fees)?;
         let depends = self
             .depends
             .iter()
@@ -197,6 +286,27 @@ impl MempoolEntry {
     }
 }
 
+/// Builds the fee model from the deprecated top-level fields of `MempoolEntry`.
+///
+/// `fee` and `modifiedfee` are in BTC but `ancestorfees` and `descendantfees` are in satoshis.
+fn legacy_fees(
+    fee: f64,
+    modified_fee: f64,
+    ancestor_fees: f64,
+    descendant_fees: f64,
+) -> Result<model::MempoolEntryFees, MempoolEntryFeesError> {
+    use MempoolEntryFeesError as E;
+
+    Ok(model::MempoolEntryFees {
+        base: Amount::from_btc(fee).map_err(E::Base)?,
+        modified: Amount::from_btc(modified_fee).map_err(E::Modified)?,
+        ancestor: Amount::from_float_in(ancestor_fees, Denomination::Satoshi)
+            .map_err(E::MempoolEntry)?,
+        descendant: Amount::from_float_in(descendant_fees, Denomination::Satoshi)
+            .map_err(E::Descendant)?,
+    })
+}
+
 impl MempoolEntryFees {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::MempoolEntryFees, MempoolEntryFeesError> {
@@ -236,3 +346,87 @@ impl GetMempoolInfo {
         })
     }
 }
+
+#[cfg(feature = "experimental-rpc")]
+impl ScanTxOutSet {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSet, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let tx_outs = crate::to_u32(self.tx_outs, "txouts")?;
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let unspents = self
+            .unspents
+            .into_iter()
+            .map(|u| u.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Unspents)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSet {
+            success: self.success,
+            tx_outs,
+            height,
+            best_block,
+            unspents,
+            total_amount,
+        })
+    }
+}
+
+#[cfg(feature = "experimental-rpc")]
+impl ScanTxOutSetUnspent {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetUnspent, ScanTxOutSetUnspentError> {
+        use ScanTxOutSetUnspentError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let height = crate::to_u32(self.height, "height")?;
+
+        Ok(model::ScanTxOutSetUnspent {
+            outpoint: OutPoint { txid, vout: self.vout },
+            script_pubkey,
+            descriptor: self.descriptor,
+            amount,
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mempool_entry_legacy_fee_fields() {
+        // `getmempoolentry` result without the `fees` object.
+        let json = r#"{
+            "vsize": 141,
+            "weight": 561,
+            "fee": 0.00001410,
+            "modifiedfee": 0.00001410,
+            "time": 1700000000,
+            "height": 101,
+            "descendantcount": 1,
+            "descendantsize": 141,
+            "descendantfees": 1410,
+            "ancestorcount": 1,
+            "ancestorsize": 141,
+            "ancestorfees": 1410,
+            "wtxid": "f8b2b2eb7a1a4d8d8fd8f1a5bd4c7ff2f2d7e0e9a4a1b3b2c8a5b7f0e1d2c3b4",
+            "depends": [],
+            "spentby": [],
+            "bip125-replaceable": false
+        }"#;
+        let entry: MempoolEntry = serde_json::from_str(json).expect("deserialize");
+        let model = entry.into_model().expect("into_model");
+
+        assert_eq!(model.fees.base, Amount::from_sat(1410));
+        assert_eq!(model.fees.modified, Amount::from_sat(1410));
+        assert_eq!(model.fees.ancestor, Amount::from_sat(1410));
+        assert_eq!(model.fees.descendant, Amount::from_sat(1410));
+    }
+}
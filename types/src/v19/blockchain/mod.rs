@@ -186,7 +186,7 @@ pub struct GetChainTxStats {
     pub window_interval: Option<i64>,
     /// The average rate of transactions per second in the window. Only returned if "window_interval" is > 0.
     #[serde(rename = "txrate")]
-    pub tx_rate: Option<i64>,
+    pub tx_rate: Option<f64>,
 }
 
 /// Result of JSON-RPC method `getmempoolancestors` with verbose set to `false`.
@@ -253,10 +253,10 @@ pub struct MempoolEntry {
     /// Transaction weight as defined in BIP 141.
     pub weight: i64,
     /// DEPRECATED: Transaction fee in BTC.
-    pub fee: f64,
+    pub fee: Option<f64>,
     /// DEPRECATED: Transaction fee with fee deltas used for mining priority.
     #[serde(rename = "modifiedfee")]
-    pub modified_fee: f64,
+    pub modified_fee: Option<f64>,
     /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
     pub time: i64,
     /// Block height when transaction entered pool.
@@ -267,23 +267,27 @@ pub struct MempoolEntry {
     /// Virtual transaction size of in-mempool descendants (including this one).
     #[serde(rename = "descendantsize")]
     pub descendant_size: i64,
-    /// DEPRECATED: Modified fees (see above) of in-mempool descendants (including this one).
+    /// DEPRECATED: Modified fees (see above) of in-mempool descendants (including this one) in
+    /// satoshis.
     #[serde(rename = "descendantfees")]
-    pub descendant_fees: f64,
+    pub descendant_fees: Option<f64>,
     /// Number of in-mempool ancestor transactions (including this one).
     #[serde(rename = "ancestorcount")]
     pub ancestor_count: i64,
     /// Virtual transaction size of in-mempool ancestors (including this one).
     #[serde(rename = "ancestorsize")]
     pub ancestor_size: i64,
-    /// DEPRECATED: Modified fees (see above) of in-mempool ancestors (including this one).
+    /// DEPRECATED: Modified fees (see above) of in-mempool ancestors (including this one) in
+    /// satoshis.
     #[serde(rename = "ancestorfees")]
-    pub ancestor_fees: f64,
+    pub ancestor_fees: Option<f64>,
     /// Hash of serialized transaction, including witness data.
     pub wtxid: String,
     /// Fee object which contains the base fee, modified fee (with fee deltas), and
     /// ancestor/descendant fee totals all in BTC.
-    pub fees: MempoolEntryFees,
+    ///
+    /// If not present `into_model` falls back to the deprecated top-level fee fields.
+    pub fees: Option<MempoolEntryFees>,
     /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
     pub depends: Vec<String>,
     /// Unconfirmed transactions spending outputs from this transaction (child transaction id).
@@ -341,3 +345,61 @@ pub struct GetMempoolInfo {
     #[serde(rename = "minrelaytxfee")]
     pub min_relay_tx_fee: f64,
 }
+
+/// Result of JSON-RPC method `scantxoutset` with `action = start`.
+///
+/// Only available with the `experimental-rpc` feature, Bitcoin Core marks this API as
+/// experimental.
+///
+/// > scantxoutset "action" ( [scanobjects,...] )
+/// >
+/// > EXPERIMENTAL warning: this call may be removed or changed in future releases.
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. action         (string, required) The action to execute
+/// >                   "start" for starting a scan
+/// >                   "abort" for aborting the current scan (returns true when abort was successful)
+/// >                   "status" for progress report (in %) of the current scan
+/// > 2. scanobjects    (json array, required) Array of scan objects
+#[cfg(feature = "experimental-rpc")]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScanTxOutSet {
+    /// Whether the scan was completed.
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    #[serde(rename = "txouts")]
+    pub tx_outs: i64,
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The unspent transaction outputs that matched the scan objects.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+/// An unspent transaction output. Part of `scantxoutset`.
+#[cfg(feature = "experimental-rpc")]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: u32,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// A specialized descriptor for the matched scriptPubKey.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// The total amount in BTC of the unspent output.
+    pub amount: f64,
+    /// Height of the unspent transaction output.
+    pub height: i64,
+}
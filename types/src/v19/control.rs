@@ -7,6 +7,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::ActiveCommand;
+use crate::model;
 
 /// Result of JSON-RPC method `getrpcinfo`.
 ///
@@ -22,3 +23,11 @@ pub struct GetRpcInfo {
     #[serde(rename = "logpath")]
     pub log_path: String,
 }
+
+impl GetRpcInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetRpcInfo {
+        let active_commands = self.active_commands.into_iter().map(|c| c.into_model()).collect();
+        model::GetRpcInfo { active_commands, log_path: Some(self.log_path.into()) }
+    }
+}
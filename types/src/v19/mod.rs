@@ -46,9 +46,9 @@
 //! | gettxoutproof                      | returns string  |                                        |
 //! | gettxoutsetinfo                    | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
-//! | pruneblockchain                    | version         |                                        |
+//! | pruneblockchain                    | version + model |                                        |
 //! | savemempool                        | returns nothing |                                        |
-//! | scantxoutset                       | omitted         | API marked as experimental             |
+//! | scantxoutset                       | version + model | Requires `experimental-rpc` feature    |
 //! | verifychain                        | version         |                                        |
 //! | verifytxoutproof                   | version + model |                                        |
 //!
@@ -60,9 +60,9 @@
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getmemoryinfo                      | version         |                                        |
-//! | getrpcinfo                         | version         |                                        |
+//! | getrpcinfo                         | version + model |                                        |
 //! | help                               | returns string  |                                        |
-//! | logging                            | version         |                                        |
+//! | logging                            | version + model |                                        |
 //! | stop                               | returns string  |                                        |
 //! | uptime                             | returns numeric |                                        |
 //!
@@ -84,7 +84,7 @@
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
 //! | getblocktemplate                   | version + model |                                        |
 //! | getmininginfo                      | version + model |                                        |
-//! | getnetworkhashps                   | returns boolean |                                        |
+//! | getnetworkhashps                   | returns numeric |                                        |
 //! | prioritisetransaction              | returns boolean |                                        |
 //! | submitblock                        | returns nothing |                                        |
 //! | submitheader                       | returns nothing |                                        |
@@ -99,16 +99,16 @@
 //! | addnode                            | returns nothing |                                        |
 //! | clearbanned                        | returns nothing |                                        |
 //! | disconnectnode                     | returns nothing |                                        |
-//! | getaddednodeinfo                   | version         |                                        |
+//! | getaddednodeinfo                   | version + model |                                        |
 //! | getconnectioncount                 | version         |                                        |
-//! | getnettotals                       | version         |                                        |
+//! | getnettotals                       | version + model |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
-//! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
-//! | listbanned                         | version         |                                        |
+//! | getnodeaddresses                   | version + model |                                        |
+//! | getpeerinfo                        | version + model |                                        |
+//! | listbanned                         | version + model |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
-//! | setnetworkactive                   | version         |                                        |
+//! | setnetworkactive                   | returns boolean |                                        |
 //!
 //! </details>
 //!
@@ -145,7 +145,7 @@
 //! | createmultisig                     | version + model |                                        |
 //! | deriveaddresses                    | version + model |                                        |
 //! | estimatesmartfee                   | version + model |                                        |
-//! | getdescriptorinfo                  | version         |                                        |
+//! | getdescriptorinfo                  | version + model |                                        |
 //! | signmessagewithprivkey             | version + model |                                        |
 //! | validateaddress                    | version + model |                                        |
 //! | verifymessage                      | version         |                                        |
@@ -164,7 +164,7 @@
 //! | bumpfee                            | version + model |                                        |
 //! | createwallet                       | version + model |                                        |
 //! | dumpprivkey                        | version + model |                                        |
-//! | dumpwallet                         | version         |                                        |
+//! | dumpwallet                         | version + model |                                        |
 //! | encryptwallet                      | version         |                                        |
 //! | getaddressesbylabel                | version + model |                                        |
 //! | getaddressinfo                     | version + model |                                        |
@@ -185,14 +185,14 @@
 //! | importwallet                       | returns nothing |                                        |
 //! | keypoolrefill                      | returns nothing |                                        |
 //! | listaddressgroupings               | version + model |                                        |
-//! | listlabels                         | version         |                                        |
+//! | listlabels                         | version + model |                                        |
 //! | listlockunspent                    | version + model |                                        |
 //! | listreceivedbyaddress              | version + model |                                        |
 //! | listreceivedbylabel                | version + model |                                        |
 //! | listsinceblock                     | version + model |                                        |
 //! | listtransactions                   | version + model |                                        |
 //! | listunspent                        | version + model |                                        |
-//! | listwalletdir                      | version         |                                        |
+//! | listwalletdir                      | version + model |                                        |
 //! | listwallets                        | version + model |                                        |
 //! | loadwallet                         | version + model |                                        |
 //! | lockunspent                        | version         |                                        |
@@ -202,7 +202,7 @@
 //! | sendtoaddress                      | version + model |                                        |
 //! | sethdseed                          | returns nothing |                                        |
 //! | setlabel                           | returns nothing |                                        |
-//! | settxfee                           | version         |                                        |
+//! | settxfee                           | returns boolean |                                        |
 //! | setwalletflag                      | version         |                                        |
 //! | signmessage                        | version + model |                                        |
 //! | signrawtransactionwithwallet       | version + model |                                        |
@@ -220,7 +220,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -231,6 +231,11 @@ mod network;
 mod util;
 mod wallet;
 
+#[cfg(feature = "experimental-rpc")]
+#[doc(inline)]
+pub use self::blockchain::{
+    ScanTxOutSet, ScanTxOutSetError, ScanTxOutSetUnspent, ScanTxOutSetUnspentError,
+};
 #[doc(inline)]
 pub use self::{
     blockchain::{
@@ -243,7 +248,7 @@ pub use self::{
     },
     control::GetRpcInfo,
     network::{GetNetworkInfo, GetPeerInfo, PeerInfo},
-    util::GetDescriptorInfo,
+    util::{GetDescriptorInfo, GetDescriptorInfoError},
     wallet::{
         GetBalances, GetBalancesError, GetBalancesMine, GetBalancesWatchOnly, GetTransaction,
         GetWalletInfo, GetWalletInfoScanning, SetWalletFlag,
@@ -252,29 +257,30 @@ pub use self::{
 #[doc(inline)]
 pub use crate::v17::{
     AbortRescan, AddMultisigAddress, AddMultisigAddressError, AddedNode, AddedNodeAddress,
-    AddressInformation, Banned, Bip125Replaceable, BumpFee, BumpFeeError, ChainTips,
-    ChainTipsError, ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt,
+    AddressInformation, AddressPurpose, Banned, Bip125Replaceable, BumpFee, BumpFeeError,
+    ChainTips, ChainTipsError, ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt,
     CreateMultisig, CreateMultisigError, CreatePsbt, CreateRawTransaction, CreateWallet,
     DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript, DecodeScriptError,
     DumpPrivKey, DumpWallet, EncryptWallet, EstimateSmartFee, FinalizePsbt, FinalizePsbtError,
     FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
-    GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel, GetBalance,
-    GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
+    GetAddedNodeInfoError, GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel,
+    GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
     GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
     GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
-    GetBlockVerboseZero, GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
-    GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
-    GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress, GetRawMempool,
-    GetRawMempoolVerbose, GetRawTransaction, GetRawTransactionVerbose,
-    GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetail,
-    GetTransactionDetailError, GetTransactionError, GetTxOut, GetTxOutError, GetTxOutSetInfo,
-    GetTxOutSetInfoError, GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings,
-    ListAddressGroupingsError, ListAddressGroupingsItem, ListBanned, ListLabels, ListLockUnspent,
-    ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddressError, ListSinceBlock,
-    ListSinceBlockError, ListTransactions, ListUnspentItemError, ListWallets, LoadWallet,
-    LockUnspent, Locked, Logging, NumericError, PruneBlockchain, RawTransactionError,
-    RawTransactionInput, RawTransactionOutput, RescanBlockchain, ScriptType, SendMany,
-    SendRawTransaction, SendToAddress, SetNetworkActive, SetTxFee, SignMessage,
+    GetBlockVerboseTwo, GetBlockVerboseTwoError, GetBlockVerboseTwoInput,
+    GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips, GetChainTxStatsError,
+    GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo,
+    GetMiningInfoError, GetNetTotals, GetNetworkInfoAddress, GetNetworkInfoError,
+    GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress, GetRawMempool, GetRawMempoolVerbose,
+    GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+    GetReceivedByAddress, GetTransactionDetail, GetTransactionDetailError, GetTransactionError,
+    GetTxOut, GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError, GetUnconfirmedBalance,
+    GetWalletInfoError, GetZmqNotificationsError, ListAddressGroupings, ListAddressGroupingsError,
+    ListAddressGroupingsItem, ListBanned, ListLabels, ListLockUnspent, ListLockUnspentItem,
+    ListLockUnspentItemError, ListReceivedByAddressError, ListSinceBlock, ListSinceBlockError,
+    ListTransactions, ListUnspentItemError, ListWallets, LoadWallet, LockUnspent, Locked, Logging,
+    NumericError, PruneBlockchain, RawTransactionError, RawTransactionInput, RawTransactionOutput,
+    RescanBlockchain, ScriptType, SendMany, SendRawTransaction, SendToAddress, SignMessage,
     SignMessageWithPrivKey, SignRawTransaction, SignRawTransactionError, SignRawTransactionWithKey,
     SignRawTransactionWithWallet, SoftforkReject, TestMempoolAccept, TransactionCategory,
     TransactionItem, TransactionItemError, UploadTarget, ValidateAddress, ValidateAddressError,
@@ -284,9 +290,10 @@ pub use crate::v17::{
 #[doc(inline)]
 pub use crate::v18::{
     ActiveCommand, AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
-    AnalyzePsbtInputMissingError, DeriveAddresses, GetAddressInfo, GetAddressInfoEmbedded,
-    GetAddressInfoError, GetNodeAddresses, GetReceivedByLabel, GetZmqNotifications, ImportMulti,
-    ImportMultiEntry, JoinPsbts, JsonRpcError, ListReceivedByAddress, ListReceivedByAddressItem,
-    ListReceivedByLabel, ListReceivedByLabelError, ListUnspent, ListUnspentItem, ListWalletDir,
-    ListWalletDirWallet, NodeAddress, UtxoUpdatePsbt,
+    AnalyzePsbtInputMissingError, DeriveAddresses, DeriveAddressesError, GetAddressInfo,
+    GetAddressInfoEmbedded, GetAddressInfoError, GetNodeAddresses, GetReceivedByLabel,
+    GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
+    ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
+    ListReceivedByLabelError, ListUnspent, ListUnspentItem, ListWalletDir, ListWalletDirWallet,
+    NodeAddress, PeerInfoError, UtxoUpdatePsbt,
 };
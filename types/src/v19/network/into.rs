@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use super::{GetNetworkInfo, GetNetworkInfoError};
-use crate::model;
+use super::{GetNetworkInfo, GetNetworkInfoError, GetPeerInfo, PeerInfo, PeerInfoError};
+use crate::{model, v18};
 
 impl GetNetworkInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -31,3 +31,54 @@ impl GetNetworkInfo {
         })
     }
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        let peer = v18::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            services: self.services,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            add_node: self.add_node,
+            starting_height: self.starting_height,
+            ban_score: self.ban_score,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            whitelisted: self.whitelisted,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            min_fee_filter: self.min_fee_filter,
+        };
+
+        Ok(model::PeerInfo {
+            services_names: Some(self.services_names),
+            permissions: Some(self.permissions),
+            ..peer.into_model()?
+        })
+    }
+}
@@ -10,7 +10,7 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork};
+use super::{GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork, PeerInfoError};
 
 /// Result of the JSON-RPC method `getnetworkinfo`.
 ///
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use core::fmt;
+
+/// Error when converting a `GetDescriptorInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetDescriptorInfoError {
+    /// The `checksum` field is not a valid descriptor checksum.
+    Checksum(String),
+}
+
+impl fmt::Display for GetDescriptorInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetDescriptorInfoError as E;
+
+        match *self {
+            E::Checksum(ref s) => write!(f, "invalid descriptor checksum: {}", s),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetDescriptorInfoError {}
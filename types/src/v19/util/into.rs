@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use super::{GetDescriptorInfo, GetDescriptorInfoError};
+use crate::model;
+
+impl GetDescriptorInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetDescriptorInfo, GetDescriptorInfoError> {
+        if !crate::is_descriptor_checksum(&self.checksum) {
+            return Err(GetDescriptorInfoError::Checksum(self.checksum));
+        }
+
+        Ok(model::GetDescriptorInfo {
+            descriptor: self.descriptor,
+            multipath_expansion: None,
+            checksum: Some(self.checksum),
+            is_range: self.is_range,
+            is_solvable: self.is_solvable,
+            has_private_keys: self.has_private_keys,
+        })
+    }
+}
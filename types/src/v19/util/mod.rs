@@ -4,8 +4,14 @@
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
 
+mod error;
+mod into;
+
 use serde::{Deserialize, Serialize};
 
+#[rustfmt::skip]                // Keep public re-exports separate.
+pub use self::error::GetDescriptorInfoError;
+
 /// Result of JSON-RPC method `getdescriptorinfo`.
 ///
 /// > getdescriptorinfo "descriptor"
@@ -7,11 +7,11 @@
 mod error;
 mod into;
 
-use bitcoin::Transaction;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::GetBalancesError;
 use super::{Bip125Replaceable, GetTransactionDetail, GetTransactionError, GetWalletInfoError};
+use crate::psbt::RawTransaction;
 
 /// Result of the JSON-RPC method `getbalances`.
 ///
@@ -23,6 +23,7 @@ use super::{Bip125Replaceable, GetTransactionDetail, GetTransactionError, GetWal
 pub struct GetBalances {
     /// Balances from outputs that the wallet can sign.
     pub mine: GetBalancesMine,
+    /// Watchonly balances (not present if wallet does not watch anything).
     #[serde(rename = "watchonly")]
     pub watch_only: Option<GetBalancesWatchOnly>,
 }
@@ -43,7 +44,7 @@ pub struct GetBalancesMine {
     pub used: Option<f64>,
 }
 
-/// Hash and height of the block this information was generated on. Part of `getbalances`.
+/// Balances from outputs the wallet is watching but cannot sign. Part of `getbalances`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetBalancesWatchOnly {
@@ -104,7 +105,7 @@ pub struct GetTransaction {
     /// Raw data for transaction.
     pub hex: String,
     /// The decoded transaction (only present when `verbose` is passed). v19 and later only.
-    pub decoded: Option<Transaction>,
+    pub decoded: Option<RawTransaction>,
 }
 
 /// Result of the JSON-RPC method `getwalletinfo`.
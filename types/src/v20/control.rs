@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{model, v17};
+
 /// Result of JSON-RPC method `logging`.
 ///
 /// > logging ( `<include>` `<exclude>` )
@@ -37,3 +39,39 @@ pub struct Logging {
     pub walletdb: bool,
     pub zmq: bool,
 }
+
+impl Logging {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let mut logging = v17::Logging {
+            addrman: self.addrman,
+            bench: self.bench,
+            cmpctblock: self.cmpctblock,
+            coindb: self.coindb,
+            db: false,
+            estimatefee: self.estimatefee,
+            http: self.http,
+            leveldb: self.leveldb,
+            libevent: self.libevent,
+            mempool: self.mempool,
+            mempoolrej: self.mempoolrej,
+            net: self.net,
+            prune: self.prune,
+            proxy: self.proxy,
+            qt: self.qt,
+            rand: self.rand,
+            reindex: self.reindex,
+            rpc: self.rpc,
+            selectcoins: self.selectcoins,
+            tor: self.tor,
+            zmq: self.zmq,
+        }
+        .into_model();
+
+        // `db` was renamed to `walletdb` in v20.
+        logging.0.remove("db");
+        logging.0.insert("validation".to_string(), self.validation);
+        logging.0.insert("walletdb".to_string(), self.walletdb);
+        logging
+    }
+}
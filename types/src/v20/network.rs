@@ -4,8 +4,12 @@
 //!
 //! Types for methods found under the `== Network ==` section of the API docs.
 
+use core::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+
 /// Result of JSON-RPC method `listbanned`.
 ///
 /// > listbanned
@@ -26,3 +30,24 @@ pub struct Banned {
     /// The UNIX epoch time the ban was created.
     pub ban_created: u32,
 }
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            address: crate::banned_subnet(self.address),
+            banned_until: Duration::from_secs(self.banned_until.into()),
+            ban_created: Duration::from_secs(self.ban_created.into()),
+            ban_reason: None,
+            ban_duration: None,
+            time_remaining: None,
+        }
+    }
+}
@@ -5,11 +5,12 @@ use alloc::collections::BTreeMap;
 use bitcoin::{hex, BlockHash, Network, Txid, Work, Wtxid};
 
 use super::{
-    GetBlockchainInfo, GetBlockchainInfoError, GetMempoolAncestors, GetMempoolAncestorsVerbose,
-    GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo,
-    GetMempoolInfoError, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
+    Bip9SoftforkInfo, GetBlockchainInfo, GetBlockchainInfoError, GetMempoolAncestors,
+    GetMempoolAncestorsVerbose, GetMempoolDescendants, GetMempoolDescendantsVerbose,
+    GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError, MapMempoolEntryError, MempoolEntry,
+    MempoolEntryError, Softfork,
 };
-use crate::model;
+use crate::{model, v19};
 
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -24,7 +25,11 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = self
+            .softforks
+            .into_iter()
+            .map(|(name, softfork)| Ok((name, softfork.into_model()?)))
+            .collect::<Result<BTreeMap<_, _>, crate::NumericError>>()?;
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -51,6 +56,40 @@ impl GetBlockchainInfo {
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Softfork, crate::NumericError> {
+        let bip9 = self.bip9.map(|b| b.into_model()).transpose()?;
+        let softfork = v19::Softfork {
+            type_: self.type_,
+            bip9: None,
+            height: self.height,
+            active: self.active,
+        };
+
+        Ok(model::Softfork { bip9, ..softfork.into_model()? })
+    }
+}
+
+impl Bip9SoftforkInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkInfo, crate::NumericError> {
+        let info = v19::Bip9SoftforkInfo {
+            status: self.status,
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: self.since,
+            statistics: self.statistics,
+        };
+
+        Ok(model::Bip9SoftforkInfo {
+            min_activation_height: Some(self.min_activation_height),
+            ..info.into_model()?
+        })
+    }
+}
+
 impl GetMempoolAncestors {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetMempoolAncestors, hex::HexToArrayError> {
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 
 pub use super::{
     Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBlockchainInfoError, GetMempoolInfoError,
-    MapMempoolEntryError, MempoolEntryError, MempoolEntryFees,
+    MapMempoolEntryError, MempoolEntryError, MempoolEntryFees, SoftforkType,
 };
 
 /// Result of JSON-RPC method `getblockchaininfo`.
@@ -79,20 +79,6 @@ pub struct Softfork {
     pub active: bool,
 }
 
-/// The softfork type. Part of `getblockchaininfo`.
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum SoftforkType {
-    /// Softfork is "buried" (as defined in [BIP-90]).
-    ///
-    /// [BIP-90] <https://github.com/bitcoin/bips/blob/master/bip-0090.mediawiki>
-    Buried,
-    /// Softfork is "bip9" (see [BIP-9]).
-    ///
-    /// [BIP-9] <https://github.com/bitcoin/bips/blob/master/bip-0009.mediawiki>
-    Bip9,
-}
-
 /// BIP-9 softfork info. Part of `getblockchaininfo`.
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
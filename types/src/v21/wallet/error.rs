@@ -58,6 +58,8 @@ pub enum SendError {
     Psbt(PsbtParseError),
     /// Conversion of numeric type to expected type failed.
     Numeric(NumericError),
+    /// Transaction is complete but neither `txid` nor `hex` is present.
+    Incomplete,
 }
 
 impl fmt::Display for SendError {
@@ -69,6 +71,8 @@ impl fmt::Display for SendError {
             E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
             E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Incomplete =>
+                write!(f, "transaction is complete but neither `txid` nor `hex` is present"),
         }
     }
 }
@@ -83,6 +87,7 @@ impl std::error::Error for SendError {
             E::Hex(ref e) => Some(e),
             E::Psbt(ref e) => Some(e),
             E::Numeric(ref e) => Some(e),
+            E::Incomplete => None,
         }
     }
 }
@@ -90,3 +95,31 @@ impl std::error::Error for SendError {
 impl From<NumericError> for SendError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
+
+/// Error when converting a `UpgradeWallet` type into the model type.
+#[derive(Debug)]
+pub enum UpgradeWalletError {
+    /// The upgrade failed, holds the `error` message returned by Core.
+    Failed(String),
+}
+
+impl fmt::Display for UpgradeWalletError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use UpgradeWalletError as E;
+
+        match *self {
+            E::Failed(ref msg) => write!(f, "wallet upgrade failed: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UpgradeWalletError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use UpgradeWalletError as E;
+
+        match *self {
+            E::Failed(_) => None,
+        }
+    }
+}
@@ -3,15 +3,47 @@
 use bitcoin::{hex, Txid};
 
 use super::{
-    GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning, PsbtBumpFee, PsbtBumpFeeError, Send,
-    SendError, SendMany, SendManyVerbose, UnloadWallet,
+    GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning, ImportDescriptors,
+    ImportDescriptorsResult, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany,
+    SendManyVerbose, UnloadWallet, UpgradeWallet, UpgradeWalletError,
 };
 use crate::model;
 
 impl UnloadWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> model::UnloadWallet {
-        model::UnloadWallet { warnings: vec![self.warning] }
+        let warnings = crate::warning_to_option(self.warning).into_iter().collect();
+        model::UnloadWallet { warnings }
+    }
+}
+
+impl ImportDescriptors {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptors {
+        model::ImportDescriptors(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+impl ImportDescriptorsResult {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptorsResult {
+        use model::ImportDescriptorsFailure as F;
+
+        let error = self.error.map(|error| {
+            let code =
+                error.get("code").and_then(|c| c.as_i64()).and_then(|c| i32::try_from(c).ok());
+            let message = error.get("message").and_then(|m| m.as_str());
+            match (code, message) {
+                (Some(code), Some(message)) => F::Rpc { code, message: message.to_owned() },
+                _ => F::Other(error),
+            }
+        });
+
+        model::ImportDescriptorsResult {
+            success: self.success,
+            warnings: self.warnings.unwrap_or_default(),
+            error,
+        }
     }
 }
 
@@ -44,6 +76,10 @@ impl Send {
 
         let psbt = self.psbt.as_ref().map(|p| p.parse()).transpose().map_err(E::Psbt)?;
 
+        if self.complete && txid.is_none() && hex.is_none() {
+            return Err(E::Incomplete);
+        }
+
         Ok(model::Send { complete: self.complete, txid, hex, psbt })
     }
 }
@@ -76,7 +112,8 @@ impl GetWalletInfo {
         let immature_balance =
             bitcoin::Amount::from_btc(self.immature_balance).map_err(E::ImmatureBalance)?;
         let tx_count = crate::to_u32(self.tx_count, "tx_count")?;
-        let keypool_oldest = crate::to_u32(self.keypool_oldest, "keypool_oldest")?;
+        let keypool_oldest =
+            self.keypool_oldest.map(|v| crate::to_u32(v, "keypool_oldest")).transpose()?;
         let keypool_size = crate::to_u32(self.keypool_size, "keypool_size")?;
         let keypool_size_hd_internal =
             crate::to_u32(self.keypool_size_hd_internal, "keypool_size_hd_internal")?;
@@ -97,7 +134,7 @@ impl GetWalletInfo {
             unconfirmed_balance,
             immature_balance,
             tx_count,
-            keypool_oldest,
+            keypool_oldest: keypool_oldest.unwrap_or(0),
             keypool_size,
             keypool_size_hd_internal,
             unlocked_until: self.unlocked_until,
@@ -115,3 +152,19 @@ impl GetWalletInfo {
         })
     }
 }
+
+impl UpgradeWallet {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::UpgradeWallet, UpgradeWalletError> {
+        if let Some(error) = self.error {
+            return Err(UpgradeWalletError::Failed(error));
+        }
+
+        Ok(model::UpgradeWallet {
+            wallet_name: self.wallet_name,
+            previous_version: self.previous_version,
+            current_version: self.current_version,
+            result: self.result,
+        })
+    }
+}
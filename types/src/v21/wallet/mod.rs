@@ -9,7 +9,7 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
-pub use self::error::{PsbtBumpFeeError, SendError};
+pub use self::error::{PsbtBumpFeeError, SendError, UpgradeWalletError};
 pub use super::GetWalletInfoError;
 
 /// Result of the JSON-RPC method `getwalletinfo`.
@@ -38,7 +38,7 @@ pub struct GetWalletInfo {
     pub tx_count: i64,
     /// The UNIX epoch time of the oldest pre-generated key in the key pool. Legacy wallets only.
     #[serde(rename = "keypoololdest")]
-    pub keypool_oldest: i64,
+    pub keypool_oldest: Option<i64>,
     /// How many new keys are pre-generated (only counts external keys).
     #[serde(rename = "keypoolsize")]
     pub keypool_size: i64,
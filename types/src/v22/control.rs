@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{model, v20};
+
 /// Result of JSON-RPC method `logging`.
 ///
 /// > logging ( `<include>` `<exclude>` )
@@ -39,3 +41,38 @@ pub struct Logging {
     pub walletdb: bool,
     pub zmq: bool,
 }
+
+impl Logging {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let mut logging = v20::Logging {
+            addrman: self.addrman,
+            bench: self.bench,
+            cmpctblock: self.cmpctblock,
+            coindb: self.coindb,
+            estimatefee: self.estimatefee,
+            http: self.http,
+            leveldb: self.leveldb,
+            libevent: self.libevent,
+            mempool: self.mempool,
+            mempoolrej: self.mempoolrej,
+            net: self.net,
+            prune: self.prune,
+            proxy: self.proxy,
+            qt: self.qt,
+            rand: self.rand,
+            reindex: self.reindex,
+            rpc: self.rpc,
+            selectcoins: self.selectcoins,
+            tor: self.tor,
+            validation: self.validation,
+            walletdb: self.walletdb,
+            zmq: self.zmq,
+        }
+        .into_model();
+
+        logging.0.insert("i2p".to_string(), self.i2p);
+        logging.0.insert("ipc".to_string(), self.ipc);
+        logging
+    }
+}
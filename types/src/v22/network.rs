@@ -5,9 +5,14 @@
 //! Types for methods found under the `== Network ==` section of the API docs.
 
 use alloc::collections::BTreeMap;
+use core::time::Duration;
 
+use bitcoin::p2p::ServiceFlags;
 use serde::{Deserialize, Serialize};
 
+use super::PeerInfoError;
+use crate::{model, v21};
+
 /// Result of JSON-RPC method `getnodeaddresses`.
 ///
 /// > getnodeaddresses ( count "network" )
@@ -35,6 +40,25 @@ pub struct NodeAddress {
     pub network: String,
 }
 
+impl GetNodeAddresses {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNodeAddresses {
+        model::GetNodeAddresses(self.0.into_iter().map(|a| a.into_model()).collect())
+    }
+}
+
+impl NodeAddress {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::NodeAddress {
+        model::NodeAddress {
+            time: self.time,
+            services: ServiceFlags::from(self.services),
+            address: crate::node_address_kind(self.address, self.port),
+            network: Some(self.network),
+        }
+    }
+}
+
 /// Result of JSON-RPC method `getpeerinfo`.
 ///
 /// > getpeerinfo
@@ -171,3 +195,82 @@ pub struct Banned {
     /// The time remaining until the ban expires, in seconds.
     pub time_remaining: u32,
 }
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            address: crate::banned_subnet(self.address),
+            banned_until: Duration::from_secs(self.banned_until.into()),
+            ban_created: Duration::from_secs(self.ban_created.into()),
+            ban_reason: None,
+            ban_duration: Some(Duration::from_secs(self.ban_duration.into())),
+            time_remaining: Some(Duration::from_secs(self.time_remaining.into())),
+        }
+    }
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        let peer = v21::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: self.network,
+            services: self.services,
+            services_names: self.services_names,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: self.last_transaction,
+            last_block: self.last_block,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            add_node: self.add_node,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addresses_processed: self.addresses_processed,
+            addresses_rate_limited: self.addresses_rate_limited,
+            permissions: self.permissions,
+            whitelisted: self.whitelisted,
+            min_fee_filter: self.min_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type,
+        };
+
+        Ok(model::PeerInfo {
+            bip152_hb_to: Some(self.bip152_hb_to),
+            bip152_hb_from: Some(self.bip152_hb_from),
+            ..peer.into_model()?
+        })
+    }
+}
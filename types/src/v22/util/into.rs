@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: CC0-1.0
+
+use bitcoin::hex::FromHex as _;
+use bitcoin::{Address, ScriptBuf, WitnessProgram, WitnessVersion};
+
+use super::{ValidateAddress, ValidateAddressError};
+use crate::model;
+
+impl ValidateAddress {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ValidateAddress, ValidateAddressError> {
+        use ValidateAddressError as E;
+
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let script_pubkey = self
+            .script_pubkey
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::ScriptPubkey)?;
+        let (witness_version, witness_program) = match (self.witness_version, self.witness_program)
+        {
+            (Some(v), Some(hex)) => {
+                if v > u8::MAX as i64 || v < 0 {
+                    return Err(E::WitnessVersionValue(v));
+                }
+                let witness_version =
+                    WitnessVersion::try_from(v as u8).map_err(E::WitnessVersion)?;
+
+                let bytes = Vec::from_hex(&hex).map_err(E::WitnessProgramBytes)?;
+                let witness_program =
+                    WitnessProgram::new(witness_version, &bytes).map_err(E::WitnessProgram)?;
+
+                (Some(witness_version), Some(witness_program))
+            }
+            _ => (None, None),
+        };
+        let error_locations = self
+            .error_locations
+            .map(|locs| {
+                locs.into_iter()
+                    .map(|l| crate::to_u32(l, "error_locations"))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        Ok(model::ValidateAddress {
+            is_valid: self.is_valid,
+            address,
+            script_pubkey,
+            is_script: self.is_script,
+            is_witness: self.is_witness,
+            witness_version,
+            witness_program,
+            error: self.error,
+            error_locations,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core `v22` - util.
+//!
+//! Types for methods found under the `== Util ==` section of the API docs.
+
+mod into;
+
+use serde::{Deserialize, Serialize};
+
+pub use super::ValidateAddressError;
+
+/// Result of JSON-RPC method `validateaddress`.
+///
+/// > validateaddress "address"
+/// >
+/// > Return information about the given bitcoin address.
+/// >
+/// > Arguments:
+/// > 1. address    (string, required) The bitcoin address to validate
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidateAddress {
+    /// If the address is valid or not.
+    #[serde(rename = "isvalid")]
+    pub is_valid: bool,
+    /// The bitcoin address validated.
+    pub address: Option<String>,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: Option<String>,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: Option<bool>,
+    /// The version number of the witness program.
+    pub witness_version: Option<i64>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// Error message, if any.
+    pub error: Option<String>,
+    /// Indices of likely error locations in address, if known (e.g. Bech32 errors).
+    pub error_locations: Option<Vec<i64>>,
+}
@@ -8,7 +8,7 @@ use super::{
     Bip9Info, Bip9Statistics, DeploymentInfo, GetBlockchainInfo, GetBlockchainInfoError,
     GetDeploymentInfo, GetDeploymentInfoError, GetMempoolAncestors, GetMempoolAncestorsVerbose,
     GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, MapMempoolEntryError,
-    MempoolEntry, MempoolEntryError,
+    MempoolEntry, MempoolEntryError, Softfork,
 };
 use crate::model;
 
@@ -26,7 +26,7 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = Softfork::map_into_model(self.softforks)?;
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -6,8 +6,12 @@
 
 use alloc::collections::BTreeMap;
 
+use bitcoin::p2p::ServiceFlags;
 use serde::{Deserialize, Serialize};
 
+use super::PeerInfoError;
+use crate::model;
+
 /// Result of JSON-RPC method `getpeerinfo`.
 ///
 /// > getpeerinfo
@@ -122,3 +126,81 @@ pub struct PeerInfo {
     /// Type of connection.
     pub connection_type: Option<String>,
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        use PeerInfoError as E;
+
+        let address = crate::peer_address(&self.address).map_err(E::Address)?;
+        let address_bind = self
+            .address_bind
+            .map(|a| crate::peer_address(&a))
+            .transpose()
+            .map_err(E::AddressBind)?;
+        let address_local = self
+            .address_local
+            .map(|a| crate::peer_address(&a))
+            .transpose()
+            .map_err(E::AddressLocal)?;
+        let services = u64::from_str_radix(&self.services, 16).map_err(E::Services)?;
+        let services = ServiceFlags::from(services);
+        let minimum_fee_filter =
+            crate::btc_per_kb(self.minimum_fee_filter).map_err(E::MinimumFeeFilter)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            address,
+            address_bind,
+            address_local,
+            network: self.network,
+            mapped_as: None,
+            services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: Some(self.last_transaction),
+            last_block: Some(self.last_block),
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: Some(self.bip152_hb_to),
+            bip152_hb_from: Some(self.bip152_hb_from),
+            add_node: self.add_node,
+            starting_height: self.starting_height,
+            presynced_headers: None,
+            ban_score: self.ban_score,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addresses_relay_enabled: self.addresses_relay_enabled,
+            addresses_processed: self.addresses_processed,
+            addresses_rate_limited: self.addresses_rate_limited,
+            permissions: Some(self.permissions),
+            whitelisted: None,
+            minimum_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type,
+            transport_protocol_type: None,
+            session_id: None,
+        })
+    }
+}
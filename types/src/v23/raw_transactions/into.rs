@@ -48,15 +48,15 @@ impl DecodePsbt {
         let inputs = self
             .inputs
             .into_iter()
-            .map(|input| input.into_input())
-            .collect::<Result<_, _>>()
-            .map_err(E::Inputs)?;
+            .enumerate()
+            .map(|(i, input)| input.into_input().map_err(|e| E::Inputs(i, e)))
+            .collect::<Result<_, _>>()?;
         let outputs = self
             .outputs
             .into_iter()
-            .map(|output| output.into_output())
-            .collect::<Result<_, _>>()
-            .map_err(E::Outputs)?;
+            .enumerate()
+            .map(|(i, output)| output.into_output().map_err(|e| E::Outputs(i, e)))
+            .collect::<Result<_, _>>()?;
 
         let psbt = bitcoin::Psbt {
             unsigned_tx,
@@ -67,7 +67,7 @@ impl DecodePsbt {
             inputs,
             outputs,
         };
-        let fee = self.fee.map(Amount::from_sat);
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
 
         Ok(model::DecodePsbt { psbt, fee })
     }
@@ -8,6 +8,7 @@ use bitcoin::hex;
 
 use super::GetTransactionDetailError;
 use crate::error::write_err;
+use crate::psbt::RawTransactionError;
 use crate::NumericError;
 
 /// Error when converting a `GetTransaction` type into the model type.
@@ -33,6 +34,8 @@ pub enum GetTransactionError {
     Tx(encode::FromHexError),
     /// Conversion of the `details` field failed.
     Details(GetTransactionDetailError),
+    /// Conversion of the `decoded` field failed.
+    Decoded(RawTransactionError),
 }
 
 /// Error when converting a `ListSinceBlock` type into the model type.
@@ -153,6 +156,7 @@ impl fmt::Display for GetTransactionError {
                 write_err!(f, "conversion of the `replaces_txid` field failed"; e),
             E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Details(ref e) => write_err!(f, "conversion of the `details` field failed"; e),
+            E::Decoded(ref e) => write_err!(f, "conversion of the `decoded` field failed"; e),
         }
     }
 }
@@ -173,6 +177,7 @@ impl std::error::Error for GetTransactionError {
             E::ReplacesTxid(ref e) => Some(e),
             E::Tx(ref e) => Some(e),
             E::Details(ref e) => Some(e),
+            E::Decoded(ref e) => Some(e),
         }
     }
 }
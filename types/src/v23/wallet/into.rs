@@ -59,6 +59,8 @@ impl GetTransaction {
             .map(|d| d.into_model().map_err(E::Details))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let decoded = self.decoded.map(|tx| tx.to_transaction()).transpose().map_err(E::Decoded)?;
+
         Ok(model::GetTransaction {
             amount,
             fee, // Option in model
@@ -82,7 +84,7 @@ impl GetTransaction {
             bip125_replaceable: self.bip125_replaceable.into_model(),
             parent_descriptors: None, // v24 and later only.
             details,
-            decoded: self.decoded,
+            decoded,
             last_processed_block: None, // v26 and later only.
             tx,
         })
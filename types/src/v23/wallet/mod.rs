@@ -7,7 +7,6 @@
 mod error;
 mod into;
 
-use bitcoin::Transaction;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{GetTransactionError, ListSinceBlockError, TransactionItemError};
@@ -15,6 +14,7 @@ pub use super::{
     AddMultisigAddressError, Bip125Replaceable, GetTransactionDetail, GetTransactionDetailError,
     GetWalletInfoError,
 };
+use crate::psbt::RawTransaction;
 
 /// Result of the JSON-RPC method `addmultisigaddress`.
 ///
@@ -105,7 +105,7 @@ pub struct GetTransaction {
     /// Raw data for transaction.
     pub hex: String,
     /// The decoded transaction (only present when `verbose` is passed). v19 and later only.
-    pub decoded: Option<Transaction>,
+    pub decoded: Option<RawTransaction>,
 }
 
 /// Result of the JSON-RPC method `getwalletinfo`.
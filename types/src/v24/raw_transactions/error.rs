@@ -2,6 +2,7 @@
 
 use core::fmt;
 
+use bitcoin::amount::ParseAmountError;
 use bitcoin::taproot::{IncompleteBuilderError, TaprootBuilderError, TaprootError};
 use bitcoin::{bip32, hex, secp256k1, sighash};
 
@@ -19,10 +20,12 @@ pub enum DecodePsbtError {
     Proprietary(hex::HexToBytesError),
     /// Conversion of one the map items in the `unknown` field failed.
     Unknown(hex::HexToBytesError),
-    /// Conversion of one of the PSBT inputs failed.
-    Inputs(PsbtInputError),
-    /// Conversion of one of the PSBT outputs failed.
-    Outputs(PsbtOutputError),
+    /// Conversion of the PSBT input at the given index failed.
+    Inputs(usize, PsbtInputError),
+    /// Conversion of the PSBT output at the given index failed.
+    Outputs(usize, PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
 }
 
 impl fmt::Display for DecodePsbtError {
@@ -37,8 +40,11 @@ impl fmt::Display for DecodePsbtError {
                 write_err!(f, "conversion of one the map items in the `proprietray` field failed"; e),
             E::Unknown(ref e) =>
                 write_err!(f, "conversion of one the map items in the `unknown` field failed"; e),
-            E::Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
-            E::Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            E::Inputs(index, ref e) =>
+                write_err!(f, "conversion of the PSBT input at index {} failed", index; e),
+            E::Outputs(index, ref e) =>
+                write_err!(f, "conversion of the PSBT output at index {} failed", index; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
         }
     }
 }
@@ -53,8 +59,9 @@ impl std::error::Error for DecodePsbtError {
             E::GlobalXpubs(ref e) => Some(e),
             E::Proprietary(ref e) => Some(e),
             E::Unknown(ref e) => Some(e),
-            E::Inputs(ref e) => Some(e),
-            E::Outputs(ref e) => Some(e),
+            E::Inputs(_, ref e) => Some(e),
+            E::Outputs(_, ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
         }
     }
 }
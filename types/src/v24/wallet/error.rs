@@ -9,6 +9,7 @@ use bitcoin::psbt::PsbtParseError;
 
 use super::GetTransactionDetailError;
 use crate::error::write_err;
+use crate::psbt::RawTransactionError;
 use crate::NumericError;
 
 /// Error when converting a `GetTransaction` type into the model type.
@@ -38,6 +39,8 @@ pub enum GetTransactionError {
     Tx(encode::FromHexError),
     /// Conversion of the `details` field failed.
     Details(GetTransactionDetailError),
+    /// Conversion of the `decoded` field failed.
+    Decoded(RawTransactionError),
 }
 
 impl fmt::Display for GetTransactionError {
@@ -61,6 +64,7 @@ impl fmt::Display for GetTransactionError {
                 write_err!(f, "conversion of the `mempool_conflicts` field failed"; e),
             E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Details(ref e) => write_err!(f, "conversion of the `details` field failed"; e),
+            E::Decoded(ref e) => write_err!(f, "conversion of the `decoded` field failed"; e),
         }
     }
 }
@@ -83,6 +87,7 @@ impl std::error::Error for GetTransactionError {
             E::MempoolConflicts(ref e) => Some(e),
             E::Tx(ref e) => Some(e),
             E::Details(ref e) => Some(e),
+            E::Decoded(ref e) => Some(e),
         }
     }
 }
@@ -7,7 +7,6 @@
 mod error;
 mod into;
 
-use bitcoin::Transaction;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{
@@ -16,6 +15,7 @@ pub use self::error::{
 pub use super::{
     Bip125Replaceable, GetTransactionDetailError, ListUnspentItemError, TransactionCategory,
 };
+use crate::psbt::RawTransaction;
 
 /// Result of the JSON-RPC method `gettransaction`.
 ///
@@ -85,7 +85,7 @@ pub struct GetTransaction {
     /// Raw data for transaction.
     pub hex: String,
     /// The decoded transaction (only present when `verbose` is passed). v19 and later only.
-    pub decoded: Option<Transaction>,
+    pub decoded: Option<RawTransaction>,
 }
 
 /// Transaction detail. Part of the `gettransaction`.
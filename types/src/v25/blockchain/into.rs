@@ -11,49 +11,62 @@ impl GetBlockStats {
     pub fn into_model(self) -> Result<model::GetBlockStats, GetBlockStatsError> {
         use GetBlockStatsError as E;
 
-        // `FeeRate::sat_per_vb` returns an option if value overflows.
-        let average_fee_rate = FeeRate::from_sat_per_vb(self.average_fee_rate);
-        let block_hash = self.block_hash.parse::<BlockHash>().map_err(E::BlockHash)?;
+        let to_u32 = |value: Option<i64>, field| value.map(|v| crate::to_u32(v, field)).transpose();
+
+        // `FeeRate::from_sat_per_vb` returns `None` if the value overflows.
+        let fee_rate = |value: u64, field: &str| {
+            FeeRate::from_sat_per_vb(value)
+                .ok_or_else(|| E::FeeRateOverflow { field: field.to_owned(), value })
+        };
+        let average_fee_rate =
+            self.average_fee_rate.map(|v| fee_rate(v, "average_fee_rate")).transpose()?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
         let fee_rate_percentiles = self
             .fee_rate_percentiles
-            .iter()
-            .map(|vb| FeeRate::from_sat_per_vb(*vb))
-            .collect::<Vec<Option<FeeRate>>>();
-        let max_fee_rate = FeeRate::from_sat_per_vb(self.max_fee_rate);
-        let minimum_fee_rate = FeeRate::from_sat_per_vb(self.minimum_fee_rate);
+            .map(|percentiles| {
+                percentiles
+                    .iter()
+                    .map(|vb| fee_rate(*vb, "fee_rate_percentiles"))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let max_fee_rate = self.max_fee_rate.map(|v| fee_rate(v, "max_fee_rate")).transpose()?;
+        let minimum_fee_rate =
+            self.minimum_fee_rate.map(|v| fee_rate(v, "minimum_fee_rate")).transpose()?;
 
         // FIXME: Double check that these values are virtual bytes and not weight units.
-        let segwit_total_weight = Weight::from_vb(self.segwit_total_weight);
-        let total_weight = Weight::from_vb(self.total_weight);
+        let segwit_total_weight = self.segwit_total_weight.and_then(Weight::from_vb);
+        let total_weight = self.total_weight.and_then(Weight::from_vb);
 
         Ok(model::GetBlockStats {
-            average_fee: Amount::from_sat(self.average_fee),
+            average_fee: self.average_fee.map(Amount::from_sat),
             average_fee_rate,
-            average_tx_size: crate::to_u32(self.average_tx_size, "average_tx_size")?,
+            average_tx_size: to_u32(self.average_tx_size, "average_tx_size")?,
             block_hash,
             fee_rate_percentiles,
-            height: crate::to_u32(self.height, "height")?,
-            inputs: crate::to_u32(self.inputs, "inputs")?,
-            max_fee: Amount::from_sat(self.max_fee),
+            height: to_u32(self.height, "height")?,
+            inputs: to_u32(self.inputs, "inputs")?,
+            max_fee: self.max_fee.map(Amount::from_sat),
             max_fee_rate,
-            max_tx_size: crate::to_u32(self.max_tx_size, "max_tx_size")?,
-            median_fee: Amount::from_sat(self.median_fee),
-            median_time: crate::to_u32(self.median_time, "median_time")?,
-            median_tx_size: crate::to_u32(self.median_tx_size, "median_tx_size")?,
-            minimum_fee: Amount::from_sat(self.minimum_fee),
+            max_tx_size: to_u32(self.max_tx_size, "max_tx_size")?,
+            median_fee: self.median_fee.map(Amount::from_sat),
+            median_time: to_u32(self.median_time, "median_time")?,
+            median_tx_size: to_u32(self.median_tx_size, "median_tx_size")?,
+            minimum_fee: self.minimum_fee.map(Amount::from_sat),
             minimum_fee_rate,
-            minimum_tx_size: crate::to_u32(self.minimum_tx_size, "minimum_tx_size")?,
-            outputs: crate::to_u32(self.outputs, "outputs")?,
-            subsidy: Amount::from_sat(self.subsidy),
-            segwit_total_size: crate::to_u32(self.segwit_total_size, "segwit_total_size")?,
+            minimum_tx_size: to_u32(self.minimum_tx_size, "minimum_tx_size")?,
+            outputs: to_u32(self.outputs, "outputs")?,
+            subsidy: self.subsidy.map(Amount::from_sat),
+            segwit_total_size: to_u32(self.segwit_total_size, "segwit_total_size")?,
             segwit_total_weight,
-            segwit_txs: crate::to_u32(self.segwit_txs, "segwit_txs")?,
-            time: crate::to_u32(self.time, "time")?,
-            total_out: Amount::from_sat(self.total_out),
-            total_size: crate::to_u32(self.total_size, "total_size")?,
+            segwit_txs: to_u32(self.segwit_txs, "segwit_txs")?,
+            time: to_u32(self.time, "time")?,
+            total_out: self.total_out.map(Amount::from_sat),
+            total_size: to_u32(self.total_size, "total_size")?,
             total_weight,
-            total_fee: Amount::from_sat(self.total_fee),
-            txs: crate::to_u32(self.txs, "txs")?,
+            total_fee: self.total_fee.map(Amount::from_sat),
+            txs: to_u32(self.txs, "txs")?,
             utxo_increase: self.utxo_increase,
             utxo_size_increase: self.utxo_size_increase,
             utxo_increase_actual: self.utxo_increase_actual,
@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{model, v23};
+
 /// Result of JSON-RPC method `logging`.
 ///
 /// > logging ( `<include>` `<exclude>` )
@@ -43,3 +45,42 @@ pub struct Logging {
     pub walletdb: bool,         // v23 and later only
     pub zmq: bool,
 }
+
+impl Logging {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let mut logging = v23::Logging {
+            addrman: self.addrman,
+            bench: self.bench,
+            blockstorage: self.blockstorage,
+            cmpctblock: self.cmpctblock,
+            coindb: self.coindb,
+            estimatefee: self.estimatefee,
+            http: self.http,
+            i2p: self.i2p,
+            ipc: self.ipc,
+            leveldb: self.leveldb,
+            libevent: self.libevent,
+            mempool: self.mempool,
+            mempoolrej: self.mempoolrej,
+            net: self.net,
+            prune: self.prune,
+            proxy: self.proxy,
+            qt: self.qt,
+            rand: self.rand,
+            reindex: self.reindex,
+            rpc: self.rpc,
+            selectcoins: self.selectcoins,
+            tor: self.tor,
+            util: self.util,
+            validation: self.validation,
+            walletdb: self.walletdb,
+            zmq: self.zmq,
+        }
+        .into_model();
+
+        logging.0.insert("scan".to_string(), self.scan);
+        logging.0.insert("txreconciliation".to_string(), self.txreconciliation);
+        logging
+    }
+}
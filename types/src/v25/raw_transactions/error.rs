@@ -3,11 +3,66 @@
 use core::fmt;
 
 use bitcoin::amount::ParseAmountError;
+use bitcoin::consensus::encode;
 use bitcoin::hex;
 
 use crate::error::write_err;
 use crate::NumericError;
 
+/// Error when converting a `GetRawTransactionVerboseTwo` type into the model type.
+#[derive(Debug)]
+pub enum GetRawTransactionVerboseTwoError {
+    /// Conversion of a numeric field failed.
+    Numeric(NumericError),
+    /// Conversion of the transaction `hex` field failed.
+    Hex(encode::FromHexError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+    /// Conversion of a prevout `value` field failed.
+    PrevoutValue(ParseAmountError),
+    /// Conversion of a prevout `scriptPubKey` field failed.
+    PrevoutScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetRawTransactionVerboseTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetRawTransactionVerboseTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "conversion of a numeric field failed"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the transaction `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            E::PrevoutValue(ref e) =>
+                write_err!(f, "conversion of a prevout `value` field failed"; e),
+            E::PrevoutScriptPubkey(ref e) =>
+                write_err!(f, "conversion of a prevout `scriptPubKey` field failed"; e),
+            E::BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetRawTransactionVerboseTwoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetRawTransactionVerboseTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::PrevoutValue(ref e) => Some(e),
+            E::PrevoutScriptPubkey(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetRawTransactionVerboseTwoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Error when converting a `TestMempoolAccept` type into the model type.
 #[derive(Debug)]
 pub enum TestMempoolAcceptError {
@@ -1,10 +1,64 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::{Amount, Txid, Wtxid};
+use bitcoin::consensus::encode;
+use bitcoin::{Amount, BlockHash, Txid, Wtxid};
 
-use super::{MempoolAcceptance, MempoolAcceptanceError, TestMempoolAccept, TestMempoolAcceptError};
+use super::{
+    GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError,
+    GetRawTransactionVerboseTwoPrevout, MempoolAcceptance, MempoolAcceptanceError,
+    TestMempoolAccept, TestMempoolAcceptError,
+};
 use crate::model;
 
+impl GetRawTransactionVerboseTwo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError> {
+        use GetRawTransactionVerboseTwoError as E;
+
+        let transaction = encode::deserialize_hex(&self.hex).map_err(E::Hex)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+        let prevouts = self
+            .inputs
+            .into_iter()
+            .map(|input| input.prevout.map(|prevout| prevout.into_model()).transpose())
+            .collect::<Result<_, _>>()?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+
+        Ok(model::GetRawTransactionVerboseTwo {
+            in_active_chain: self.in_active_chain,
+            transaction,
+            fee,
+            prevouts,
+            block_hash,
+            confirmations: self.confirmations,
+            transaction_time: self.transaction_time,
+            block_time: self.block_time,
+        })
+    }
+}
+
+impl GetRawTransactionVerboseTwoPrevout {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerboseTwoPrevout, GetRawTransactionVerboseTwoError> {
+        use GetRawTransactionVerboseTwoError as E;
+
+        let value = Amount::from_btc(self.value).map_err(E::PrevoutValue)?;
+        let script_pubkey = self.script_pubkey.script_buf().map_err(E::PrevoutScriptPubkey)?;
+
+        Ok(model::GetRawTransactionVerboseTwoPrevout {
+            generated: self.generated,
+            height: crate::to_u32(self.height, "height")?,
+            value,
+            script_pubkey,
+        })
+    }
+}
+
 impl TestMempoolAccept {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
@@ -9,7 +9,106 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
-pub use self::error::{MempoolAcceptanceError, TestMempoolAcceptError};
+pub use self::error::{
+    GetRawTransactionVerboseTwoError, MempoolAcceptanceError, TestMempoolAcceptError,
+};
+use crate::psbt::RawTransactionOutput;
+use crate::{ScriptPubkey, ScriptSig};
+
+/// Result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+///
+/// > getrawtransaction "txid" ( verbosity "blockhash" )
+/// >
+/// > Return the raw transaction data.
+/// >
+/// > If verbosity is 0, returns a string that is serialized, hex-encoded data for 'txid'.
+/// > If verbosity is 1, returns a JSON Object with information about 'txid'.
+/// > If verbosity is 2, returns a JSON Object with information about 'txid', including fee and prevout information.
+/// >
+/// > Arguments:
+/// > 1. txid         (string, required) The transaction id
+/// > 2. verbosity    (numeric, optional, default=0) 0 for hex-encoded data, 1 for a JSON object, and 2 for JSON object with fee and prevout
+/// > 3. blockhash    (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRawTransactionVerboseTwo {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The serialized, hex-encoded data for 'txid'.
+    pub hex: String,
+    /// The transaction id (same as provided).
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The serialized transaction size.
+    pub size: u64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: u64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: u64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: u32,
+    /// Array of transaction inputs.
+    #[serde(rename = "vin")]
+    pub inputs: Vec<GetRawTransactionVerboseTwoInput>,
+    /// Array of transaction outputs.
+    #[serde(rename = "vout")]
+    pub outputs: Vec<RawTransactionOutput>,
+    /// Transaction fee in BTC, omitted if block undo data is not available.
+    pub fee: Option<f64>,
+    // The following fields are all `None` if the transaction is in the mempool.
+    /// The block hash.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The confirmations.
+    pub confirmations: Option<u64>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "time")]
+    pub transaction_time: Option<u64>,
+    /// The block time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+}
+
+/// A transaction input. Part of `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRawTransactionVerboseTwoInput {
+    /// The transaction id, omitted for the coinbase input.
+    pub txid: Option<String>,
+    /// The output number, omitted for the coinbase input.
+    pub vout: Option<u32>,
+    /// The script, omitted for the coinbase input.
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<ScriptSig>,
+    /// The hex-encoded coinbase script, only present for the coinbase input.
+    pub coinbase: Option<String>,
+    /// Hex-encoded witness data (if any).
+    #[serde(rename = "txinwitness")]
+    pub txin_witness: Option<Vec<String>>,
+    /// The previous output, omitted if block undo data is not available.
+    pub prevout: Option<GetRawTransactionVerboseTwoPrevout>,
+    /// The script sequence number.
+    pub sequence: u32,
+}
+
+/// The output spent by a transaction input. Part of `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetRawTransactionVerboseTwoPrevout {
+    /// Coinbase or not.
+    pub generated: bool,
+    /// The height of the block containing the previous output.
+    pub height: i64,
+    /// The value in BTC.
+    pub value: f64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: ScriptPubkey,
+}
 
 /// Result of JSON-RPC method `testmempoolaccept`.
 ///
@@ -8,6 +8,9 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::PeerInfoError;
+use crate::{model, v24};
+
 /// Result of JSON-RPC method `getaddrmaninfo`.
 ///
 /// > getaddrmaninfo
@@ -158,3 +161,67 @@ pub struct PeerInfo {
     /// v26 and later only.
     pub session_id: String,
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        Ok(model::GetPeerInfo(
+            self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        let peer = v24::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: Some(self.network),
+            services: self.services,
+            services_names: self.services_names,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: self.last_transaction,
+            last_block: self.last_block,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: self.bip152_hb_to,
+            bip152_hb_from: self.bip152_hb_from,
+            add_node: self.add_node,
+            starting_height: self.starting_height,
+            presynced_headers: self.presynced_headers,
+            ban_score: self.ban_score,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addresses_relay_enabled: self.addresses_relay_enabled,
+            addresses_processed: self.addresses_processed,
+            addresses_rate_limited: self.addresses_rate_limited,
+            permissions: self.permissions,
+            minimum_fee_filter: self.minimum_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type,
+        };
+
+        Ok(model::PeerInfo {
+            mapped_as: self.mapped_as,
+            whitelisted: self.whitelisted,
+            transport_protocol_type: Some(self.transport_protocol_type),
+            session_id: Some(self.session_id),
+            ..peer.into_model()?
+        })
+    }
+}
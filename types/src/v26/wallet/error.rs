@@ -8,6 +8,7 @@ use bitcoin::{hex, psbt};
 
 use super::GetTransactionDetailError;
 use crate::error::write_err;
+use crate::psbt::RawTransactionError;
 use crate::NumericError;
 
 /// Error when converting a `GetBalances` type into the model type.
@@ -74,6 +75,8 @@ pub enum GetTransactionError {
     Tx(encode::FromHexError),
     /// Conversion of the `details` field failed.
     Details(GetTransactionDetailError),
+    /// Conversion of the `decoded` field failed.
+    Decoded(RawTransactionError),
     /// Conversion of the `last_processed_block` field failed.
     LastProcessedBlock(LastProcessedBlockError),
 }
@@ -99,6 +102,7 @@ impl fmt::Display for GetTransactionError {
                 write_err!(f, "conversion of the `mempool_conflicts` field failed"; e),
             E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
             E::Details(ref e) => write_err!(f, "conversion of the `details` field failed"; e),
+            E::Decoded(ref e) => write_err!(f, "conversion of the `decoded` field failed"; e),
             E::LastProcessedBlock(ref e) =>
                 write_err!(f, "conversion of the `last_processed_block` field failed"; e),
         }
@@ -123,6 +127,7 @@ impl std::error::Error for GetTransactionError {
             E::MempoolConflicts(ref e) => Some(e),
             E::Tx(ref e) => Some(e),
             E::Details(ref e) => Some(e),
+            E::Decoded(ref e) => Some(e),
             E::LastProcessedBlock(ref e) => Some(e),
         }
     }
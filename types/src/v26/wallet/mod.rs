@@ -7,7 +7,6 @@
 mod error;
 mod into;
 
-use bitcoin::Transaction;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{
@@ -18,6 +17,7 @@ pub use super::{
     Bip125Replaceable, GetBalancesMine, GetBalancesWatchOnly, GetTransactionDetail,
     GetTransactionDetailError,
 };
+use crate::psbt::RawTransaction;
 
 /// Result of the JSON-RPC method `createwallet`.
 ///
@@ -55,6 +55,7 @@ pub struct CreateWallet {
 pub struct GetBalances {
     /// Balances from outputs that the wallet can sign.
     pub mine: GetBalancesMine,
+    /// Watchonly balances (not present if wallet does not watch anything).
     #[serde(rename = "watchonly")]
     pub watch_only: Option<GetBalancesWatchOnly>,
     /// Hash and height of the block this information was generated on. v26 and later only.
@@ -130,7 +131,7 @@ pub struct GetTransaction {
     /// Raw data for transaction.
     pub hex: String,
     /// The decoded transaction (only present when `verbose` is passed). v19 and later only.
-    pub decoded: Option<Transaction>,
+    pub decoded: Option<RawTransaction>,
     /// Hash and height of the block this information was generated on. v26 and later only.
     #[serde(rename = "lastprocessedblock")]
     pub last_processed_block: Option<LastProcessedBlock>,
@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{model, v26};
+
 /// Result of JSON-RPC method `logging`.
 ///
 /// > logging ( `<include>` `<exclude>` )
@@ -43,3 +45,45 @@ pub struct Logging {
     pub walletdb: bool,         // v23 and later only
     pub zmq: bool,
 }
+
+impl Logging {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let mut logging = v26::Logging {
+            addrman: self.addrman,
+            bench: self.bench,
+            blockstorage: self.blockstorage,
+            cmpctblock: self.cmpctblock,
+            coindb: self.coindb,
+            estimatefee: self.estimatefee,
+            http: self.http,
+            i2p: self.i2p,
+            ipc: self.ipc,
+            leveldb: self.leveldb,
+            libevent: self.libevent,
+            mempool: self.mempool,
+            mempoolrej: self.mempoolrej,
+            net: self.net,
+            prune: self.prune,
+            proxy: self.proxy,
+            qt: self.qt,
+            rand: self.rand,
+            reindex: self.reindex,
+            rpc: self.rpc,
+            scan: self.scan,
+            selectcoins: self.selectcoins,
+            tor: self.tor,
+            txpackages: self.txpackages,
+            txreconciliation: self.txreconciliation,
+            util: false,
+            validation: self.validation,
+            walletdb: self.walletdb,
+            zmq: self.zmq,
+        }
+        .into_model();
+
+        // `util` was removed in v28.
+        logging.0.remove("util");
+        logging
+    }
+}
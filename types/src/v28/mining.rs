@@ -4,9 +4,10 @@
 //!
 //! Types for methods found under the `== Mining ==` section of the API docs.
 
-use bitcoin::Weight;
+use bitcoin::{Network, Weight};
 use serde::{Deserialize, Serialize};
 
+use super::GetMiningInfoError;
 use crate::model;
 
 /// Result of the JSON-RPC method `getmininginfo`.
@@ -29,7 +30,7 @@ pub struct GetMiningInfo {
     pub difficulty: f64,
     /// The network hashes per second.
     #[serde(rename = "networkhashps")]
-    pub network_hash_ps: i64,
+    pub network_hash_ps: f64,
     /// The size of the mempool.
     #[serde(rename = "pooledtx")]
     pub pooled_tx: i64,
@@ -41,10 +42,14 @@ pub struct GetMiningInfo {
 
 impl GetMiningInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> model::GetMiningInfo {
+    pub fn into_model(self) -> Result<model::GetMiningInfo, GetMiningInfoError> {
+        use GetMiningInfoError as E;
+
         let current_block_weight = self.current_block_weight.map(Weight::from_wu);
+        let pooled_tx = crate::to_u64(self.pooled_tx, "pooled_tx")?;
+        let chain = Network::from_core_arg(&self.chain).map_err(E::UnknownChain)?;
 
-        model::GetMiningInfo {
+        Ok(model::GetMiningInfo {
             blocks: self.blocks,
             current_block_weight,
             current_block_tx: self.current_block_tx,
@@ -52,11 +57,11 @@ impl GetMiningInfo {
             difficulty: self.difficulty,
             target: None,
             network_hash_ps: self.network_hash_ps,
-            pooled_tx: self.pooled_tx,
-            chain: self.chain,
+            pooled_tx,
+            chain,
             signet_challenge: None,
             next: None,
             warnings: self.warnings,
-        }
+        })
     }
 }
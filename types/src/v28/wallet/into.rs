@@ -221,6 +221,8 @@ impl GetTransaction {
             .transpose()
             .map_err(E::LastProcessedBlock)?;
 
+        let decoded = self.decoded.map(|tx| tx.to_transaction()).transpose().map_err(E::Decoded)?;
+
         Ok(model::GetTransaction {
             amount,
             fee,
@@ -244,7 +246,7 @@ impl GetTransaction {
             bip125_replaceable: self.bip125_replaceable.into_model(),
             parent_descriptors: self.parent_descriptors,
             details,
-            decoded: self.decoded,
+            decoded,
             last_processed_block,
             tx,
         })
@@ -7,7 +7,6 @@
 mod error;
 mod into;
 
-use bitcoin::Transaction;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{GetHdKeysError, ListSinceBlockError, TransactionItemError};
@@ -15,6 +14,7 @@ pub use super::{
     Bip125Replaceable, GetAddressInfoEmbeddedError, GetAddressInfoError, GetTransactionDetail,
     GetTransactionError, LastProcessedBlock, ScriptType,
 };
+use crate::psbt::RawTransaction;
 
 /// Result of the JSON-RPC method `createwalletdescriptor`.
 ///
@@ -270,7 +270,7 @@ pub struct GetTransaction {
     /// Raw data for transaction.
     pub hex: String,
     /// The decoded transaction (only present when `verbose` is passed). v19 and later only.
-    pub decoded: Option<Transaction>,
+    pub decoded: Option<RawTransaction>,
     /// Hash and height of the block this information was generated on. v26 and later only.
     #[serde(rename = "lastprocessedblock")]
     pub last_processed_block: Option<LastProcessedBlock>,
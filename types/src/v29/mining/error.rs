@@ -3,16 +3,22 @@
 use core::fmt;
 
 use bitcoin::error::UnprefixedHexError;
+use bitcoin::network;
 
 use crate::error::write_err;
+use crate::NumericError;
 
 /// Error when converting a `GetMiningInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetMiningInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
     /// Conversion of the `bits` field failed.
     Bits(UnprefixedHexError),
     /// Conversion of the `target` field failed.
     Target(UnprefixedHexError),
+    /// Conversion of the `chain` field failed.
+    UnknownChain(network::ParseNetworkError),
     /// Conversion of one of the items in field `next` failed.
     Next(NextBlockInfoError),
 }
@@ -22,8 +28,10 @@ impl fmt::Display for GetMiningInfoError {
         use GetMiningInfoError as E;
 
         match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
             E::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
             E::Target(ref e) => write_err!(f, "conversion of the `target` field failed"; e),
+            E::UnknownChain(ref e) => write_err!(f, "conversion of the `chain` field failed"; e),
             E::Next(ref e) =>
                 write_err!(f, "conversion of one of the items in field `next` failed"; e),
         }
@@ -36,13 +44,19 @@ impl std::error::Error for GetMiningInfoError {
         use GetMiningInfoError as E;
 
         match *self {
+            E::Numeric(ref e) => Some(e),
             E::Bits(ref e) => Some(e),
             E::Target(ref e) => Some(e),
+            E::UnknownChain(ref e) => Some(e),
             E::Next(ref e) => Some(e),
         }
     }
 }
 
+impl From<NumericError> for GetMiningInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Error when converting a `NextBlockInfo` type into the model type.
 #[derive(Debug)]
 pub enum NextBlockInfoError {
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::{CompactTarget, Target, Weight};
+use bitcoin::{CompactTarget, Network, Target, Weight};
 
 use super::{GetMiningInfo, GetMiningInfoError, NextBlockInfo, NextBlockInfoError};
 use crate::model;
@@ -13,6 +13,8 @@ impl GetMiningInfo {
         let current_block_weight = self.current_block_weight.map(Weight::from_wu);
         let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
         let target = Target::from_unprefixed_hex(self.target.as_ref()).map_err(E::Target)?;
+        let pooled_tx = crate::to_u64(self.pooled_tx, "pooled_tx")?;
+        let chain = Network::from_core_arg(&self.chain).map_err(E::UnknownChain)?;
 
         let next = self.next.into_model().map_err(E::Next)?;
 
@@ -24,8 +26,8 @@ impl GetMiningInfo {
             difficulty: self.difficulty,
             target: Some(target),
             network_hash_ps: self.network_hash_ps,
-            pooled_tx: self.pooled_tx,
-            chain: self.chain,
+            pooled_tx,
+            chain,
             signet_challenge: self.signet_challenge,
             next: Some(next),
             warnings: self.warnings,
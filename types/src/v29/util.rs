@@ -4,10 +4,9 @@
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
 
-use bitcoin::address;
 use serde::{Deserialize, Serialize};
 
-use super::DeriveAddresses;
+use super::{DeriveAddresses, DeriveAddressesError, GetDescriptorInfoError};
 use crate::model;
 
 /// Result of JSON-RPC method `deriveaddresses` for multipath descriptors.
@@ -22,7 +21,7 @@ pub struct DeriveAddressesMultipath(pub Vec<DeriveAddresses>);
 
 impl DeriveAddressesMultipath {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
-    pub fn into_model(self) -> Result<model::DeriveAddressesMultipath, address::ParseError> {
+    pub fn into_model(self) -> Result<model::DeriveAddressesMultipath, DeriveAddressesError> {
         let derive_addresses = self
             .0
             .into_iter()
@@ -59,3 +58,21 @@ pub struct GetDescriptorInfo {
     #[serde(rename = "hasprivatekeys")]
     pub has_private_keys: bool,
 }
+
+impl GetDescriptorInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetDescriptorInfo, GetDescriptorInfoError> {
+        if !crate::is_descriptor_checksum(&self.checksum) {
+            return Err(GetDescriptorInfoError::Checksum(self.checksum));
+        }
+
+        Ok(model::GetDescriptorInfo {
+            descriptor: self.descriptor,
+            multipath_expansion: self.multipath_expansion,
+            checksum: Some(self.checksum),
+            is_range: self.is_range,
+            is_solvable: self.is_solvable,
+            has_private_keys: self.has_private_keys,
+        })
+    }
+}
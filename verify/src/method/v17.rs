@@ -30,7 +30,7 @@ pub const METHODS: &[Method] = &[
     Method::new_string("gettxoutproof", "get_tx_out_proof"),
     Method::new_modelled("gettxoutsetinfo", "GetTxOutSetInfo", "get_tx_out_set_info"),
     Method::new_nothing("preciousblock", "precious_block"),
-    Method::new_no_model("pruneblockchain", "PruneBlockchain", "prune_blockchain"),
+    Method::new_modelled("pruneblockchain", "PruneBlockchain", "prune_blockchain"),
     Method::new_nothing("savemempool", "save_mempool"),
     Method::new_modelled("scantxoutset", "ScanTxOutSet", "scan_tx_out_set"),
     Method::new_no_model("verifychain", "VerifyChain", "verify_chain"),
@@ -38,7 +38,7 @@ pub const METHODS: &[Method] = &[
     // control
     Method::new_no_model("getmemoryinfo", "GetMemoryInfoStats", "get_memory_info"),
     Method::new_string("help", "help"),
-    Method::new_no_model("logging", "Logging", "logging"),
+    Method::new_modelled("logging", "Logging", "logging"),
     Method::new_string("stop", "stop"),
     Method::new_numeric("uptime", "uptime"),
     // generating
@@ -54,15 +54,15 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("addnode", "add_node"),
     Method::new_nothing("clearbanned", "clear_banned"),
     Method::new_nothing("disconnectnode", "disconnect_node"),
-    Method::new_no_model("getaddednodeinfo", "GetAddedNodeInfo", "get_added_node_info"),
+    Method::new_modelled("getaddednodeinfo", "GetAddedNodeInfo", "get_added_node_info"),
     Method::new_no_model("getconnectioncount", "GetConnectionCount", "get_connection_count"),
-    Method::new_no_model("getnettotals", "GetNetTotals", "get_net_totals"),
+    Method::new_modelled("getnettotals", "GetNetTotals", "get_net_totals"),
     Method::new_modelled("getnetworkinfo", "GetNetworkInfo", "get_network_info"),
-    Method::new_no_model("getpeerinfo", "GetPeerInfo", "get_peer_info"),
-    Method::new_no_model("listbanned", "ListBanned", "list_banned"),
+    Method::new_modelled("getpeerinfo", "GetPeerInfo", "get_peer_info"),
+    Method::new_modelled("listbanned", "ListBanned", "list_banned"),
     Method::new_nothing("ping", "ping"),
     Method::new_nothing("setban", "set_ban"),
-    Method::new_no_model("setnetworkactive", "SetNetworkActive", "set_network_active"),
+    Method::new_bool("setnetworkactive", "set_network_active"),
     // raw transactions
     Method::new_modelled("combinepsbt", "CombinePsbt", "combine_psbt"),
     Method::new_modelled(
@@ -105,7 +105,7 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("bumpfee", "BumpFee", "bump_fee"),
     Method::new_modelled("createwallet", "CreateWallet", "create_wallet"),
     Method::new_modelled("dumpprivkey", "DumpPrivKey", "dump_priv_key"),
-    Method::new_no_model("dumpwallet", "DumpWallet", "dump_wallet"),
+    Method::new_modelled("dumpwallet", "DumpWallet", "dump_wallet"),
     Method::new_no_model("encryptwallet", "EncryptWallet", "encrypt_wallet"),
     Method::new_nothing("getaccount", "get_account"), // Deprecated
     Method::new_nothing("getaccountaddress", "get_account_address"), // Deprecated
@@ -133,7 +133,7 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("keypoolrefill", "keypool_refill"),
     Method::new_nothing("listaccounts", "list_accounts"), // Deprecated
     Method::new_modelled("listaddressgroupings", "ListAddressGroupings", "list_address_groupings"),
-    Method::new_no_model("listlabels", "ListLabels", "list_labels"),
+    Method::new_modelled("listlabels", "ListLabels", "list_labels"),
     Method::new_modelled("listlockunspent", "ListLockUnspent", "list_lock_unspent"),
     Method::new_nothing("listreceivedbyaccount", "list_received_by_account"), // Deprecated
     Method::new_modelled(
@@ -155,7 +155,7 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("sendtoaddress", "SendToAddress", "send_to_address"),
     Method::new_nothing("setaccount", "set_account"), // Deprecated
     Method::new_nothing("sethdseed", "set_hd_seed"),
-    Method::new_no_model("settxfee", "SetTxFee", "set_tx_fee"),
+    Method::new_bool("settxfee", "set_tx_fee"),
     Method::new_modelled("signmessage", "SignMessage", "sign_message"),
     Method::new_modelled(
         "signrawtransactionwithwallet",
@@ -173,5 +173,5 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("walletpassphrasechange", "wallet_passphrase_change"),
     Method::new_modelled("walletprocesspsbt", "WalletProcessPsbt", "wallet_process_psbt"),
     // zmq
-    Method::new_no_model("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
+    Method::new_modelled("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
 ];
@@ -31,16 +31,16 @@ pub const METHODS: &[Method] = &[
     Method::new_string("gettxoutproof", "get_tx_out_proof"),
     Method::new_modelled("gettxoutsetinfo", "GetTxOutSetInfo", "get_tx_out_set_info"),
     Method::new_nothing("preciousblock", "precious_block"),
-    Method::new_no_model("pruneblockchain", "PruneBlockchain", "prune_blockchain"),
+    Method::new_modelled("pruneblockchain", "PruneBlockchain", "prune_blockchain"),
     Method::new_nothing("savemempool", "save_mempool"),
     Method::new_modelled("scantxoutset", "ScanTxOutSet", "scan_tx_out_set"),
     Method::new_no_model("verifychain", "VerifyChain", "verify_chain"),
     Method::new_modelled("verifytxoutproof", "VerifyTxOutProof", "verify_tx_out_proof"),
-    Method::new_no_model("getrpcinfo", "GetRpcInfo", "get_rpc_info"),
+    Method::new_modelled("getrpcinfo", "GetRpcInfo", "get_rpc_info"),
     // control
     Method::new_no_model("getmemoryinfo", "GetMemoryInfoStats", "get_memory_info"),
     Method::new_string("help", "help"),
-    Method::new_no_model("logging", "Logging", "logging"),
+    Method::new_modelled("logging", "Logging", "logging"),
     Method::new_nothing("stop", "stop"),
     Method::new_numeric("uptime", "uptime"),
     // generating
@@ -48,7 +48,7 @@ pub const METHODS: &[Method] = &[
     // mining
     Method::new_modelled("getblocktemplate", "GetBlockTemplate", "get_block_template"),
     Method::new_modelled("getmininginfo", "GetMiningInfo", "get_mining_info"),
-    Method::new_nothing("getnetworkhashps", "get_network_hashes_per_second"),
+    Method::new_numeric("getnetworkhashps", "get_network_hash_ps"),
     Method::new_bool("prioritisetransaction", "prioritise_transaction"),
     Method::new_nothing("submitblock", "submit_block"),
     Method::new_nothing("submitheader", "submit_header"),
@@ -56,16 +56,16 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("addnode", "add_node"),
     Method::new_nothing("clearbanned", "clear_banned"),
     Method::new_nothing("disconnectnode", "disconnect_node"),
-    Method::new_no_model("getaddednodeinfo", "GetAddedNodeInfo", "get_added_node_info"),
+    Method::new_modelled("getaddednodeinfo", "GetAddedNodeInfo", "get_added_node_info"),
     Method::new_no_model("getconnectioncount", "GetConnectionCount", "get_connection_count"),
-    Method::new_no_model("getnettotals", "GetNetTotals", "get_net_totals"),
+    Method::new_modelled("getnettotals", "GetNetTotals", "get_net_totals"),
     Method::new_modelled("getnetworkinfo", "GetNetworkInfo", "get_network_info"),
-    Method::new_no_model("getnodeaddresses", "GetNodeAddresses", "get_node_addresses"),
-    Method::new_no_model("getpeerinfo", "GetPeerInfo", "get_peer_info"),
-    Method::new_no_model("listbanned", "ListBanned", "list_banned"),
+    Method::new_modelled("getnodeaddresses", "GetNodeAddresses", "get_node_addresses"),
+    Method::new_modelled("getpeerinfo", "GetPeerInfo", "get_peer_info"),
+    Method::new_modelled("listbanned", "ListBanned", "list_banned"),
     Method::new_nothing("ping", "ping"),
     Method::new_nothing("setban", "set_ban"),
-    Method::new_no_model("setnetworkactive", "SetNetworkActive", "set_network_active"),
+    Method::new_bool("setnetworkactive", "set_network_active"),
     // raw transactions
     Method::new_modelled("analyzepsbt", "AnalyzePsbt", "analyze_psbt"),
     Method::new_modelled("combinepsbt", "CombinePsbt", "combine_psbt"),
@@ -96,14 +96,14 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("createmultisig", "CreateMultisig", "create_multisig"),
     Method::new_modelled("deriveaddresses", "DeriveAddresses", "derive_addresses"),
     Method::new_modelled("estimatesmartfee", "EstimateSmartFee", "estimate_smart_fee"),
-    Method::new_no_model("getdescriptorinfo", "GetDescriptorInfo", "get_descriptor_info"),
+    Method::new_modelled("getdescriptorinfo", "GetDescriptorInfo", "get_descriptor_info"),
     Method::new_modelled(
         "signmessagewithprivkey",
         "SignMessageWithPrivKey",
         "sign_message_with_priv_key",
     ),
     Method::new_modelled("validateaddress", "ValidateAddress", "validate_address"),
-    Method::new_bool("verifymessage", "verify_message"),
+    Method::new_no_model("verifymessage", "VerifyMessage", "verify_message"),
     // wallet
     Method::new_nothing("abandontransaction", "abandon_transaction"),
     Method::new_no_model("abortrescan", "AbortRescan", "abort_rescan"),
@@ -112,7 +112,7 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("bumpfee", "BumpFee", "bump_fee"),
     Method::new_modelled("createwallet", "CreateWallet", "create_wallet"),
     Method::new_modelled("dumpprivkey", "DumpPrivKey", "dump_priv_key"),
-    Method::new_no_model("dumpwallet", "DumpWallet", "dump_wallet"),
+    Method::new_modelled("dumpwallet", "DumpWallet", "dump_wallet"),
     Method::new_no_model("encryptwallet", "EncryptWallet", "encrypt_wallet"),
     Method::new_modelled("getaddressesbylabel", "GetAddressesByLabel", "get_addresses_by_label"),
     Method::new_modelled("getaddressinfo", "GetAddressInfo", "get_address_info"),
@@ -137,7 +137,7 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("importwallet", "import_walet"),
     Method::new_nothing("keypoolrefill", "keypool_refill"),
     Method::new_modelled("listaddressgroupings", "ListAddressGroupings", "list_address_groupings"),
-    Method::new_no_model("listlabels", "ListLabels", "list_labels"),
+    Method::new_modelled("listlabels", "ListLabels", "list_labels"),
     Method::new_modelled("listlockunspent", "ListLockUnspent", "list_lock_unspent"),
     Method::new_modelled(
         "listreceivedbyaddress",
@@ -148,7 +148,7 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("listsinceblock", "ListSinceBlock", "list_since_block"),
     Method::new_modelled("listtransactions", "ListTransactions", "list_transactions"),
     Method::new_modelled("listunspent", "ListUnspent", "list_unspent"),
-    Method::new_no_model("listwalletdir", "ListWalletDir", "list_wallet_dir"),
+    Method::new_modelled("listwalletdir", "ListWalletDir", "list_wallet_dir"),
     Method::new_modelled("listwallets", "ListWallets", "list_wallets"),
     Method::new_modelled("loadwallet", "LoadWallet", "load_wallet"),
     Method::new_no_model("lockunspent", "LockUnspent", "lock_unspent"),
@@ -158,7 +158,7 @@ pub const METHODS: &[Method] = &[
     Method::new_modelled("sendtoaddress", "SendToAddress", "send_to_address"),
     Method::new_nothing("sethdseed", "set_hd_seed"),
     Method::new_nothing("setlabel", "set_label"),
-    Method::new_no_model("settxfee", "SetTxFee", "set_tx_fee"),
+    Method::new_bool("settxfee", "set_tx_fee"),
     Method::new_no_model("setwalletflag", "SetWalletFlag", "set_wallet_flag"),
     Method::new_modelled("signmessage", "SignMessage", "sign_message"),
     Method::new_modelled(
@@ -177,5 +177,5 @@ pub const METHODS: &[Method] = &[
     Method::new_nothing("walletpassphrasechange", "wallet_passphrase_change"),
     Method::new_modelled("walletprocesspsbt", "WalletProcessPsbt", "wallet_process_psbt"),
     // zmq
-    Method::new_no_model("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
+    Method::new_modelled("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
 ];